@@ -0,0 +1,78 @@
+//! Local-analysis throughput benchmark.
+//!
+//! Times `worker::scan_file` (tokenize, parse, every check) over a source
+//! tree, bypassing the scan cache. Pass a directory to scan, otherwise
+//! this crate's `src` is used:
+//!
+//! ```text
+//! cargo bench --bench scan -- /path/to/large/tree
+//! ```
+
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use rayon::prelude::{IntoParallelRefIterator, ParallelIterator};
+use slopchop_core::analysis::worker;
+use slopchop_core::config::Config;
+use slopchop_core::lang::Lang;
+use walkdir::WalkDir;
+
+const ITERATIONS: usize = 3;
+
+fn main() {
+    let root = std::env::args()
+        .skip(1)
+        .find(|a| !a.starts_with('-'))
+        .map_or_else(
+            || Path::new(env!("CARGO_MANIFEST_DIR")).join("src"),
+            PathBuf::from,
+        );
+    let files = source_files(&root);
+    let config = Config::default();
+    println!("scan bench: {} files under {}", files.len(), root.display());
+
+    let sequential = best_of(|| {
+        files
+            .iter()
+            .for_each(|f| drop(worker::scan_file(f, &config)))
+    });
+    let parallel = best_of(|| {
+        files
+            .par_iter()
+            .for_each(|f| drop(worker::scan_file(f, &config)))
+    });
+    report("sequential", sequential, files.len());
+    report("parallel", parallel, files.len());
+}
+
+fn source_files(root: &Path) -> Vec<PathBuf> {
+    WalkDir::new(root)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|e| e.file_type().is_file())
+        .map(walkdir::DirEntry::into_path)
+        .filter(|p| {
+            p.extension()
+                .and_then(|e| e.to_str())
+                .and_then(Lang::from_ext)
+                .is_some()
+        })
+        .collect()
+}
+
+fn best_of(mut run: impl FnMut()) -> Duration {
+    (0..ITERATIONS)
+        .map(|_| {
+            let start = Instant::now();
+            run();
+            start.elapsed()
+        })
+        .min()
+        .unwrap_or_default()
+}
+
+fn report(label: &str, elapsed: Duration, files: usize) {
+    #[allow(clippy::cast_precision_loss)]
+    let per_file = elapsed.as_secs_f64() * 1000.0 / files.max(1) as f64;
+    println!("  {label:<10} {elapsed:>10.2?}  ({per_file:.3} ms/file)");
+}
@@ -1,19 +1,25 @@
 //! Aggregation logic for analysis results.
 //! Pure data container to decouple data collection from analysis.
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
 use super::scope::Scope;
+use crate::graph::locality::TypeCounts;
 use crate::types::Violation;
 
 /// Results extracted from a single file.
-#[derive(Debug, Clone, Default, Serialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct FileAnalysis {
     pub path_str: String,
     pub scopes: HashMap<String, Scope>,
     pub violations: Vec<Violation>,
+    /// Raw imports with their 1-based lines, kept for the graph checks so
+    /// they never parse the file again.
+    pub imports: Vec<(String, usize)>,
+    /// Abstract and concrete type definitions, for module abstractness.
+    pub types: TypeCounts,
 }
 
 pub struct Aggregator {
@@ -0,0 +1,202 @@
+//! Incremental scan cache.
+//!
+//! Local analysis (tokenizing, parsing, pattern checks, scope extraction)
+//! depends only on a file's content and the rules that judge it, so its
+//! result is stored in `.slopchop/cache` keyed by content hash. The whole
+//! cache is dropped when the tool version or the rule configuration
+//! changes. Deep analysis is cross-file and always recomputed.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::Config;
+use crate::types::FileReport;
+use crate::utils::compute_sha256;
+
+use super::worker;
+
+/// Cache directory relative to the project root.
+pub const CACHE_DIR: &str = ".slopchop/cache";
+
+const CACHE_FILE: &str = "scan.json";
+const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Local reports from previous scans.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ScanCache {
+    version: String,
+    config: String,
+    entries: HashMap<PathBuf, CacheEntry>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CacheEntry {
+    hash: String,
+    report: FileReport,
+}
+
+impl ScanCache {
+    /// Returns the cached report for `path` if its content is unchanged.
+    #[must_use]
+    pub fn get(&self, path: &Path, hash: &str) -> Option<FileReport> {
+        self.entries
+            .get(path)
+            .filter(|entry| entry.hash == hash)
+            .map(|entry| entry.report.clone())
+    }
+}
+
+/// Loads the cache in `dir`. A missing, unreadable or stale cache loads
+/// as empty.
+#[must_use]
+pub fn load(dir: &Path, config: &Config) -> ScanCache {
+    let fresh = ScanCache {
+        version: VERSION.to_string(),
+        config: config_hash(config),
+        entries: HashMap::new(),
+    };
+    let Ok(content) = fs::read_to_string(dir.join(CACHE_FILE)) else {
+        return fresh;
+    };
+    match serde_json::from_str::<ScanCache>(&content) {
+        Ok(cache) if cache.version == fresh.version && cache.config == fresh.config => cache,
+        _ => fresh,
+    }
+}
+
+/// Records freshly scanned reports, drops entries for files deleted from
+/// under `root` and hands the reports back.
+pub fn update(
+    cache: &mut ScanCache,
+    root: &Path,
+    scanned: Vec<(FileReport, Option<String>)>,
+) -> Vec<FileReport> {
+    cache.entries.retain(|path, _| root.join(path).exists());
+    scanned
+        .into_iter()
+        .map(|(report, hash)| {
+            if let Some(hash) = hash {
+                let entry = CacheEntry {
+                    hash,
+                    report: report.clone(),
+                };
+                cache.entries.insert(report.path.clone(), entry);
+            }
+            report
+        })
+        .collect()
+}
+
+/// Writes the cache to `dir`. The directory gets its own `.gitignore` so
+/// the cache is never committed.
+///
+/// # Errors
+/// Returns error if the directory or files cannot be written.
+pub fn save(cache: &ScanCache, dir: &Path) -> anyhow::Result<()> {
+    fs::create_dir_all(dir)?;
+    fs::write(dir.join(".gitignore"), "*\n")?;
+    fs::write(dir.join(CACHE_FILE), serde_json::to_string(cache)?)?;
+    Ok(())
+}
+
+/// Scans `path`, relative to the project root, reusing the cached report
+/// when the content is unchanged. Returns the report with the content hash
+/// to cache it under, or `None` when the file could not be read.
+#[must_use]
+pub fn scan_file(path: &Path, config: &Config, cache: &ScanCache) -> (FileReport, Option<String>) {
+    let Ok(source) = fs::read_to_string(config.root.join(path)) else {
+        return (worker::scan_file(path, config), None);
+    };
+    let hash = compute_sha256(&source);
+    let report = cache
+        .get(path, &hash)
+        .unwrap_or_else(|| worker::scan_source(path, &source, config));
+    (report, Some(hash))
+}
+
+/// Hash of everything in the config that affects local analysis.
+fn config_hash(config: &Config) -> String {
+    let settings = &config.profile_settings;
+    let profiles: BTreeMap<_, _> = settings.profiles().iter().collect();
+    let key = serde_json::json!({
+        "rules": config.rules,
+        "profile": settings.default_profile(),
+        "profiles": profiles,
+        "path_profiles": settings.path_profiles(),
+    });
+    compute_sha256(&key.to_string())
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn scan(dir: &Path, file: &Path, config: &Config) -> (FileReport, bool) {
+        let mut cache = load(dir, config);
+        let (report, hash) = scan_file(file, config, &cache);
+        let hit = cache.get(file, hash.as_deref().unwrap()).is_some();
+        update(&mut cache, &config.root, vec![(report.clone(), hash)]);
+        save(&cache, dir).unwrap();
+        (report, hit)
+    }
+
+    #[test]
+    fn test_unchanged_files_hit_the_cache() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path().join("cache");
+        let file = tmp.path().join("lib.rs");
+        fs::write(&file, "fn f( {\n").unwrap();
+        let config = Config::default();
+
+        let (first, hit) = scan(&dir, &file, &config);
+        assert!(!hit);
+        let (second, hit) = scan(&dir, &file, &config);
+        assert!(hit);
+        assert_eq!(first.violations.len(), second.violations.len());
+        assert_eq!(
+            second.violations.first().map(|v| v.law),
+            Some("LAW OF INTEGRITY")
+        );
+
+        fs::write(&file, "fn f() {}\n").unwrap();
+        let (third, hit) = scan(&dir, &file, &config);
+        assert!(!hit);
+        assert!(third.violations.is_empty());
+    }
+
+    #[test]
+    fn test_config_change_invalidates_cache() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path().join("cache");
+        let file = tmp.path().join("lib.rs");
+        fs::write(&file, "fn f() {}\n").unwrap();
+
+        let config = Config::default();
+        scan(&dir, &file, &config);
+        assert!(scan(&dir, &file, &config).1);
+
+        let mut stricter = Config::default();
+        stricter.rules.max_file_tokens = 1;
+        assert!(!scan(&dir, &file, &stricter).1);
+    }
+
+    #[test]
+    fn test_paths_resolve_against_the_project_root() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path().join(CACHE_DIR);
+        fs::write(tmp.path().join("lib.rs"), "fn f() {}\n").unwrap();
+        let config = Config {
+            root: tmp.path().to_path_buf(),
+            ..Config::default()
+        };
+
+        let file = Path::new("lib.rs");
+        assert!(!scan(&dir, file, &config).1);
+        assert!(scan(&dir, file, &config).1);
+        assert!(load(&dir, &config).entries.contains_key(file));
+    }
+}
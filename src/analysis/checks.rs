@@ -4,21 +4,78 @@
 mod banned;
 mod complexity;
 mod naming;
+mod nim_checks;
 mod syntax;
 
-use tree_sitter::Node;
+use std::sync::Arc;
+
+use tree_sitter::{Node, Query};
 
 use crate::config::RuleConfig;
+use crate::lang::Lang;
+
+use super::queries::QuerySet;
 
 pub use banned::check_banned;
-pub use complexity::check_metrics;
+pub use complexity::{check_metrics, collect_functions, function_name};
 pub use naming::check_naming;
+pub use nim_checks::check_nim_specifics;
 pub use syntax::check_syntax;
 
-/// Context for running checks on a single file.
+/// Context for running checks on a single file: the tree parsed once by
+/// the worker and the language's shared [`QuerySet`].
 pub struct CheckContext<'a> {
+    pub lang: Lang,
     pub root: Node<'a>,
     pub source: &'a str,
     pub filename: &'a str,
     pub config: &'a RuleConfig,
+    pub queries: &'static QuerySet,
+}
+
+impl<'a> CheckContext<'a> {
+    #[must_use]
+    pub fn new(
+        lang: Lang,
+        root: Node<'a>,
+        source: &'a str,
+        filename: &'a str,
+        config: &'a RuleConfig,
+    ) -> Self {
+        Self {
+            lang,
+            root,
+            source,
+            filename,
+            config,
+            queries: QuerySet::for_lang(lang),
+        }
+    }
+
+    /// The shared compiled query for `pattern`; see [`QuerySet::get`].
+    #[must_use]
+    pub fn query(&self, pattern: &'static str) -> Option<Arc<Query>> {
+        self.queries.get(pattern)
+    }
+}
+
+/// Parses `source` and runs `check` against a default-config context.
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+pub(crate) fn with_context<R>(
+    lang: Lang,
+    source: &str,
+    check: impl FnOnce(&CheckContext) -> R,
+) -> R {
+    let mut parser = tree_sitter::Parser::new();
+    parser.set_language(lang.grammar()).unwrap();
+    let tree = parser.parse(source, None).unwrap();
+    let config = RuleConfig::default();
+    check(&CheckContext::new(
+        lang,
+        tree.root_node(),
+        source,
+        "src/lib.rs",
+        &config,
+    ))
 }
@@ -1,21 +1,35 @@
 // src/analysis/checks/banned.rs
-//! Banned construct checks (Law of Paranoia).
+//! Banned construct checks (L04).
+//!
+//! Reported at `warn` by default: the check never fired in earlier
+//! releases, so existing code may hold many of these calls.
 
 use std::path::Path;
-use tree_sitter::{Query, QueryCursor, QueryMatch};
+use tree_sitter::{Node, QueryCursor, QueryMatch};
 
+use crate::analysis::fixes;
 use crate::types::{Violation, ViolationDetails};
 
 use super::CheckContext;
 
+const BANNED_QUERY: &str = r#"
+    (call_expression
+        function: (field_expression field: (field_identifier) @method)
+        (#match? @method "^(unwrap|expect)$"))
+"#;
+
 /// Checks for banned constructs (`.unwrap()` and `.expect()` calls).
-pub fn check_banned(ctx: &CheckContext, banned_query: &Query, out: &mut Vec<Violation>) {
+/// Test files, `#[cfg(test)]` modules and `#[test]` functions are exempt.
+pub fn check_banned(ctx: &CheckContext, out: &mut Vec<Violation>) {
     if is_test_file(ctx.filename) {
         return;
     }
 
+    let Some(query) = ctx.query(BANNED_QUERY) else {
+        return;
+    };
     let mut cursor = QueryCursor::new();
-    let matches = cursor.matches(banned_query, ctx.root, ctx.source.as_bytes());
+    let matches = cursor.matches(&query, ctx.root, ctx.source.as_bytes());
 
     for m in matches {
         process_match(&m, ctx, out);
@@ -32,20 +46,62 @@ fn is_test_file(filename: &str) -> bool {
 fn process_match(m: &QueryMatch, ctx: &CheckContext, out: &mut Vec<Violation>) {
     for capture in m.captures {
         if let Ok(text) = capture.node.utf8_text(ctx.source.as_bytes()) {
-            let row = capture.node.start_position().row + 1;
             let kind = capture.node.kind();
-            if is_banned_call(kind, text) {
-                add_violation(text, row, out);
+            if is_banned_call(kind, text) && !is_test_code(capture.node, ctx.source) {
+                add_violation(text, capture.node, ctx.source, out);
             }
         }
     }
 }
 
 fn is_banned_call(kind: &str, text: &str) -> bool {
-    kind == "identifier" && (text == "unwrap" || text == "expect")
+    kind == "field_identifier" && (text == "unwrap" || text == "expect")
+}
+
+/// True if `node` sits inside a `#[cfg(test)]` item or a test function
+/// (`#[test]`, `#[tokio::test]`, `#[rstest]`, `#[test_case(..)]`, ...).
+fn is_test_code(node: Node, source: &str) -> bool {
+    let mut current = node.parent();
+    while let Some(n) = current {
+        if matches!(n.kind(), "mod_item" | "function_item") && has_test_attribute(n, source) {
+            return true;
+        }
+        current = n.parent();
+    }
+    false
 }
 
-fn add_violation(text: &str, row: usize, out: &mut Vec<Violation>) {
+fn has_test_attribute(item: Node, source: &str) -> bool {
+    let mut prev = item.prev_sibling();
+    while let Some(attr) = prev.filter(|p| p.kind() == "attribute_item") {
+        let text = attr.utf8_text(source.as_bytes()).unwrap_or("");
+        if is_test_attribute(text) {
+            return true;
+        }
+        prev = attr.prev_sibling();
+    }
+    false
+}
+
+/// Matches on the attribute path: a last segment ending in `test` or
+/// starting with `test_`, or `cfg` with a `test` predicate that is not
+/// negated.
+fn is_test_attribute(text: &str) -> bool {
+    let inner = text.trim_start_matches("#[").trim_end_matches(']');
+    let (path, args) = inner.split_once('(').unwrap_or((inner, ""));
+    let path: String = path.split_whitespace().collect();
+    if path == "cfg" {
+        let args: String = args.split_whitespace().collect();
+        return !args.contains("not(test")
+            && args
+                .split(|c: char| !c.is_alphanumeric() && c != '_')
+                .any(|word| word == "test");
+    }
+    let last = path.rsplit("::").next().unwrap_or_default();
+    last.ends_with("test") || last.starts_with("test_")
+}
+
+fn add_violation(text: &str, method: Node, source: &str, out: &mut Vec<Violation>) {
     let suggestion = if text == "unwrap" {
         "Use `?` operator or `ok_or_else()` to propagate errors"
     } else {
@@ -58,10 +114,67 @@ fn add_violation(text: &str, row: usize, out: &mut Vec<Violation>) {
         suggestion: Some(suggestion.to_string()),
     };
 
-    out.push(Violation::with_details(
-        row,
+    let mut violation = Violation::with_details(
+        method.start_position().row + 1,
         format!("Banned: '.{text}()' found. Use ? or proper error handling."),
-        "LAW OF PARANOIA",
+        "L04",
         details,
-    ));
+    );
+    let call = method.parent().and_then(|f| f.parent());
+    if let Some(fix) = call.filter(|_| text == "unwrap").and_then(|c| fixes::unwrap_to_try(c, source)) {
+        violation = violation.with_fix(fix);
+    }
+    out.push(violation);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::checks::with_context;
+    use crate::lang::Lang;
+
+    #[test]
+    fn test_flags_unwrap_outside_tests_with_fix() {
+        let source = "
+fn a() -> io::Result<u8> { Ok(fs::read(p).unwrap()[0]) }
+fn b() -> u8 { g().expect(\"x\") }
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn t() { g().unwrap(); }
+}
+";
+        let mut out = Vec::new();
+        with_context(Lang::Rust, source, |ctx| check_banned(ctx, &mut out));
+
+        let rows: Vec<usize> = out.iter().map(|v| v.row).collect();
+        assert_eq!(rows, vec![2, 3]);
+        assert!(out[0].fix.is_some());
+        assert!(out[1].fix.is_none(), "expect is reported but not rewritten");
+    }
+
+    #[test]
+    fn test_test_attributes_match_on_path() {
+        for attr in [
+            "#[test]",
+            "#[ test ]",
+            "#[tokio::test]",
+            "#[tokio::test(flavor = \"multi_thread\")]",
+            "#[rstest]",
+            "#[test_case(1, 2)]",
+            "#[cfg(test)]",
+            "#[cfg(all(test, feature = \"x\"))]",
+        ] {
+            assert!(is_test_attribute(attr), "{attr}");
+        }
+        for attr in [
+            "#[inline]",
+            "#[derive(Debug)]",
+            "#[cfg(feature = \"testing\")]",
+            "#[cfg(not(test))]",
+            "#[contest_entry]",
+        ] {
+            assert!(!is_test_attribute(attr), "{attr}");
+        }
+    }
 }
\ No newline at end of file
@@ -1,162 +1,211 @@
 //! Complexity metrics checks (Law of Complexity).
 
-use tree_sitter::{Node, Query, QueryCursor, QueryMatch};
+use tree_sitter::Node;
 
-use crate::config::RuleConfig;
+use crate::analysis::metrics;
 use crate::types::{Violation, ViolationDetails};
 
 use super::CheckContext;
 
-/// Checks for complexity metrics (arity, depth).
+/// Checks per-function metrics (arity, nesting depth).
 /// Note: Cognitive Complexity is calculated in `ast.rs` using the `cognitive` module.
 /// This module focuses on Arity and Nesting Depth.
-pub fn check_metrics(
-    ctx: &CheckContext,
-    func_query: &Query,
-    _complexity_query: &Query, // Unused now
-    out: &mut Vec<Violation>,
-) -> usize {
-    let mut cursor = QueryCursor::new();
-    let matches = cursor.matches(func_query, ctx.root, ctx.source.as_bytes());
-
-    for m in matches {
-        process_match(&m, ctx, out);
-    }
-    0 // Return 0 as max complexity is tracked via cognitive score in AST analyzer
+pub fn check_metrics(node: Node, ctx: &CheckContext, out: &mut Vec<Violation>) {
+    check_arity(node, ctx, out);
+    check_nesting(node, ctx, out);
 }
 
-fn process_match(
-    m: &QueryMatch,
-    ctx: &CheckContext,
-    out: &mut Vec<Violation>,
-) {
-    for capture in m.captures {
-        let node = capture.node;
-        if is_function_kind(node.kind()) {
-            analyze_function(node, ctx, out);
-        }
+/// Collects every function-like node under `root` (Rust, Python, TypeScript, Nim).
+/// Anonymous closures are excluded; arrow functions count only when bound to a name.
+#[must_use]
+pub fn collect_functions(root: Node) -> Vec<Node> {
+    let mut out = Vec::new();
+    collect_into(root, &mut out);
+    out
+}
+
+fn collect_into<'a>(node: Node<'a>, out: &mut Vec<Node<'a>>) {
+    if is_function_node(node) {
+        out.push(node);
+    }
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        collect_into(child, out);
     }
 }
 
-fn is_function_kind(kind: &str) -> bool {
-    matches!(
-        kind,
+fn is_function_node(node: Node) -> bool {
+    match node.kind() {
         "function_item"
-            | "function_definition"
-            | "method_definition"
-            | "arrow_function"
-            | "function_declaration"
-    )
+        | "function_definition"
+        | "method_definition"
+        | "function_declaration"
+        | "proc_declaration"
+        | "func_declaration"
+        | "method_declaration"
+        | "iterator_declaration"
+        | "converter_declaration" => true,
+        "arrow_function" | "function_expression" => {
+            node.parent().is_some_and(|p| p.kind() == "variable_declarator")
+        }
+        _ => false,
+    }
 }
 
-fn analyze_function(
-    node: Node,
-    ctx: &CheckContext,
-    out: &mut Vec<Violation>,
-) {
-    let row = node.start_position().row + 1;
-
-    // Check Arity
-    check_arity(node, ctx.config, ctx.source, row, out);
-
-    // Check Nesting
-    check_nesting(node, ctx.config, ctx.source, row, out);
+/// Returns the declared name of a function node.
+#[must_use]
+pub fn function_name<'a>(node: Node, source: &'a str) -> &'a str {
+    let named = node.child_by_field_name("name").or_else(|| {
+        // `const handler = () => {}` is named by its declarator.
+        node.parent()
+            .filter(|p| p.kind() == "variable_declarator")
+            .and_then(|p| p.child_by_field_name("name"))
+    });
+
+    named
+        .and_then(|n| n.utf8_text(source.as_bytes()).ok())
+        .unwrap_or("<anonymous>")
 }
 
-// Return &str to avoid allocation (P02 fix)
-fn extract_function_name<'a>(node: Node, source: &'a str) -> &'a str {
-    let mut cursor = node.walk();
-    for child in node.children(&mut cursor) {
-        if child.kind() == "identifier" || child.kind() == "property_identifier" {
-            if let Ok(name) = child.utf8_text(source.as_bytes()) {
-                return name;
-            }
-        }
+fn check_arity(node: Node, ctx: &CheckContext, out: &mut Vec<Violation>) {
+    let params = metrics::parameters(node, ctx.source);
+    let count = params.len();
+    if count <= ctx.config.max_function_args {
+        return;
     }
-    "<anonymous>"
-}
 
-fn check_arity(
-    node: Node,
-    config: &RuleConfig,
-    source: &str,
-    row: usize,
-    out: &mut Vec<Violation>,
-) {
-    let params = count_parameters(node);
-    if params > config.max_function_args {
-        let func_name = extract_function_name(node, source);
-        let details = ViolationDetails {
-            function_name: Some(func_name.to_string()),
-            analysis: vec![format!("Function accepts {params} parameters")],
-            suggestion: Some("Group related parameters into a struct or options object".into()),
-        };
-        out.push(Violation::with_details(
-            row,
-            format!("Function '{func_name}' has {params} args (Max: {})", config.max_function_args),
-            "LAW OF COMPLEXITY",
-            details,
-        ));
-    }
+    let func_name = function_name(node, ctx.source);
+    let list: Vec<&str> = params
+        .iter()
+        .filter_map(|p| p.utf8_text(ctx.source.as_bytes()).ok())
+        .collect();
+
+    let details = ViolationDetails {
+        function_name: Some(func_name.to_string()),
+        analysis: vec![
+            format!("Function accepts {count} parameters"),
+            format!("Parameters: ({})", list.join(", ")),
+        ],
+        suggestion: Some("Group related parameters into a struct or options object".into()),
+    };
+    out.push(Violation::with_details(
+        node.start_position().row + 1,
+        format!(
+            "Function '{func_name}' has {count} args (Max: {})",
+            ctx.config.max_function_args
+        ),
+        "LAW OF COMPLEXITY",
+        details,
+    ));
 }
 
-fn count_parameters(node: Node) -> usize {
-    let mut cursor = node.walk();
-    for child in node.children(&mut cursor) {
-        if child.kind() == "parameters" || child.kind() == "formal_parameters" {
-            return child.named_child_count();
-        }
+fn check_nesting(node: Node, ctx: &CheckContext, out: &mut Vec<Violation>) {
+    let nesting = metrics::find_deepest_nesting(node);
+    if nesting.depth <= ctx.config.max_nesting_depth {
+        return;
     }
-    0
+
+    let func_name = function_name(node, ctx.source);
+    let details = ViolationDetails {
+        function_name: Some(func_name.to_string()),
+        analysis: vec![format!(
+            "Deepest nesting: `{}` at line {} (depth {})",
+            nesting.kind, nesting.row, nesting.depth
+        )],
+        suggestion: Some("Extract nested logic or use early returns".into()),
+    };
+    out.push(Violation::with_details(
+        node.start_position().row + 1,
+        format!(
+            "Function '{func_name}' has nesting depth {} (Max: {})",
+            nesting.depth, ctx.config.max_nesting_depth
+        ),
+        "LAW OF COMPLEXITY",
+        details,
+    ));
 }
 
-fn check_nesting(
-    node: Node,
-    config: &RuleConfig,
-    source: &str,
-    row: usize,
-    out: &mut Vec<Violation>,
-) {
-    let (depth, deepest_line) = measure_nesting(node, 0, row);
-    if depth > config.max_nesting_depth {
-        let func_name = extract_function_name(node, source);
-        let details = ViolationDetails {
-            function_name: Some(func_name.to_string()),
-            analysis: vec![format!("Deepest nesting at line {deepest_line}")],
-            suggestion: Some("Extract nested logic or use early returns".into()),
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::indexing_slicing)]
+mod tests {
+    use super::*;
+    use crate::config::RuleConfig;
+    use crate::lang::Lang;
+    use tree_sitter::Parser;
+
+    fn run(lang: Lang, filename: &str, code: &str) -> Vec<Violation> {
+        let mut parser = Parser::new();
+        parser.set_language(lang.grammar()).unwrap();
+        let tree = parser.parse(code, None).unwrap();
+        let config = RuleConfig {
+            max_nesting_depth: 2,
+            max_function_args: 2,
+            ..RuleConfig::default()
         };
-        out.push(Violation::with_details(
-            row,
-            format!("Function '{func_name}' has nesting depth {depth} (Max: {})", config.max_nesting_depth),
-            "LAW OF COMPLEXITY",
-            details,
-        ));
+        let ctx = CheckContext::new(lang, tree.root_node(), code, filename, &config);
+        let mut out = Vec::new();
+        for func in collect_functions(tree.root_node()) {
+            check_metrics(func, &ctx, &mut out);
+        }
+        out
     }
-}
 
-fn measure_nesting(node: Node, current: usize, base_row: usize) -> (usize, usize) {
-    let mut max_depth = current;
-    let mut deepest_line = base_row;
+    fn analysis(v: &Violation) -> String {
+        v.details.as_ref().map(|d| d.analysis.join("\n")).unwrap_or_default()
+    }
 
-    let mut cursor = node.walk();
-    for child in node.children(&mut cursor) {
-        let child_depth = if is_nesting_node(child.kind()) { current + 1 } else { current };
-        let child_row = child.start_position().row + 1;
-        let (sub_depth, sub_line) = measure_nesting(child, child_depth, child_row);
-        if sub_depth > max_depth {
-            max_depth = sub_depth;
-            deepest_line = sub_line;
+    #[test]
+    fn test_rust_metrics() {
+        let code = "\
+fn deep(&self, a: i32, b: i32, c: i32) {
+    for x in a..b {
+        if x > c {
+            while true { }
         }
     }
+}";
+        let out = run(Lang::Rust, "lib.rs", code);
+        assert_eq!(out.len(), 2);
+        assert!(out[0].message.contains("'deep' has 3 args"));
+        assert!(analysis(&out[0]).contains("(a: i32, b: i32, c: i32)"));
+        assert!(out[1].message.contains("nesting depth 3"));
+        assert!(analysis(&out[1]).contains("`while` at line 4"));
+    }
 
-    (max_depth, deepest_line)
-}
+    #[test]
+    fn test_python_metrics() {
+        let code = "\
+class Svc:
+    def handle(self, a, b):
+        for x in a:
+            if x:
+                with open(b) as f:
+                    pass
+";
+        let out = run(Lang::Python, "svc.py", code);
+        assert_eq!(out.len(), 1, "self must not count toward arity");
+        assert_eq!(out[0].details.as_ref().unwrap().function_name.as_deref(), Some("handle"));
+        assert!(analysis(&out[0]).contains("`with` at line 5"));
+    }
 
-fn is_nesting_node(kind: &str) -> bool {
-    matches!(
-        kind,
-        "if_expression" | "if_statement" | "for_expression" | "for_statement"
-            | "for_in_statement" | "while_expression" | "while_statement"
-            | "loop_expression" | "match_expression" | "switch_statement" | "try_statement"
-    )
+    #[test]
+    fn test_typescript_metrics() {
+        let code = "\
+const build = (a: number, b: number, c?: string) => a + b;
+class Api {
+  load(id: string) {
+    if (id) {
+      for (const x of []) {
+        try { } catch (e) { }
+      }
+    }
+  }
+}";
+        let out = run(Lang::TypeScript, "api.ts", code);
+        assert_eq!(out.len(), 2);
+        assert!(out[0].message.contains("'build' has 3 args"));
+        assert!(analysis(&out[0]).contains("c?: string"));
+        assert!(out[1].message.contains("'load' has nesting depth 3"));
+        assert!(analysis(&out[1]).contains("`try` at line 6"));
+    }
 }
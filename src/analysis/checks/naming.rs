@@ -1,22 +1,26 @@
 // src/analysis/checks/naming.rs
 //! Function naming checks (Law of Complexity).
 
-use tree_sitter::{Query, QueryCursor, QueryMatch};
+use tree_sitter::{QueryCursor, QueryMatch};
 
+use crate::lang::QueryKind;
 use crate::types::{Violation, ViolationDetails};
 
 use super::CheckContext;
 
 /// Checks for naming violations (function name word count).
-pub fn check_naming(ctx: &CheckContext, query: &Query, out: &mut Vec<Violation>) {
+pub fn check_naming(ctx: &CheckContext, out: &mut Vec<Violation>) {
     for pattern in &ctx.config.ignore_naming_on {
         if ctx.filename.contains(pattern) {
             return;
         }
     }
 
+    let Some(query) = ctx.query(ctx.lang.query(QueryKind::Naming)) else {
+        return;
+    };
     let mut cursor = QueryCursor::new();
-    let matches = cursor.matches(query, ctx.root, ctx.source.as_bytes());
+    let matches = cursor.matches(&query, ctx.root, ctx.source.as_bytes());
 
     for m in matches {
         process_match(&m, ctx, out);
@@ -0,0 +1,250 @@
+// src/analysis/checks/nim_checks.rs
+//! Nim-specific safety checks (Law of Paranoia).
+//!
+//! `cast[]`, `addr`, `ptr`, `{.emit.}` and disabled runtime checks are Nim's
+//! equivalent of Rust's `unsafe`: each needs a `# SAFETY:` justification.
+//! Detection is line-based so it does not depend on grammar node names.
+
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::types::{Violation, ViolationDetails};
+
+use super::CheckContext;
+
+struct Construct {
+    label: &'static str,
+    pattern: &'static str,
+    analysis: &'static str,
+    suggestion: &'static str,
+}
+
+const CONSTRUCTS: [Construct; 5] = [
+    Construct {
+        label: "cast[]",
+        pattern: r"\bcast\s*\[",
+        analysis: "cast[] reinterprets the bit pattern and destroys type safety.",
+        suggestion: "Use a type conversion such as `int(x)`.",
+    },
+    Construct {
+        label: "addr",
+        pattern: r"\b(unsafeAddr|addr)\b",
+        analysis: "addr returns an untraced pointer that can outlive its target.",
+        suggestion: "Use `ref` for traced references.",
+    },
+    Construct {
+        label: "ptr",
+        pattern: r"\bptr\b",
+        analysis: "ptr is an untraced pointer; memory must be managed by hand.",
+        suggestion: "Use `ref` for garbage-collected references.",
+    },
+    Construct {
+        label: "{.emit.}",
+        pattern: r"\{\.\s*emit\b",
+        analysis: "{.emit.} injects raw C/C++ that Nim cannot type- or bounds-check.",
+        suggestion: "Use `importc`/`importcpp` for C interop.",
+    },
+    Construct {
+        label: "checks:off",
+        pattern: r"(?i)\{\.\s*(push\s+)?[^}]*\b\w*checks\s*:\s*off\b",
+        analysis: "Disabling runtime checks turns catchable defects into undefined behavior.",
+        suggestion: "Keep runtime checks on unless profiling proves otherwise.",
+    },
+];
+
+static PATTERNS: LazyLock<Vec<Regex>> = LazyLock::new(|| {
+    CONSTRUCTS
+        .iter()
+        .map(|c| Regex::new(c.pattern).unwrap_or_else(|_| panic!("Invalid Regex")))
+        .collect()
+});
+
+/// Checks Nim sources for unsafe constructs lacking a `# SAFETY:` annotation.
+pub fn check_nim_specifics(ctx: &CheckContext, out: &mut Vec<Violation>) {
+    let lines: Vec<&str> = ctx.source.lines().collect();
+    let mut in_block_comment = false;
+
+    for (idx, line) in lines.iter().enumerate() {
+        let code = code_portion(line, &mut in_block_comment);
+        if code.trim().is_empty() {
+            continue;
+        }
+        for (construct, re) in CONSTRUCTS.iter().zip(PATTERNS.iter()) {
+            if re.is_match(&code) {
+                check_construct(construct, idx, &lines, ctx, out);
+            }
+        }
+    }
+}
+
+fn check_construct(
+    construct: &Construct,
+    idx: usize,
+    lines: &[&str],
+    ctx: &CheckContext,
+    out: &mut Vec<Violation>,
+) {
+    let safety = &ctx.config.safety;
+    let message = if safety.ban_unsafe {
+        format!("Unsafe `{}` is strictly prohibited by configuration.", construct.label)
+    } else if safety.require_safety_comment && !has_safety_comment(idx, lines) {
+        format!("Unsafe `{}` missing justification. Add '# SAFETY:' comment.", construct.label)
+    } else {
+        return;
+    };
+
+    out.push(Violation::with_details(
+        idx + 1,
+        message,
+        "LAW OF PARANOIA",
+        ViolationDetails {
+            function_name: None,
+            analysis: vec![construct.analysis.to_string()],
+            suggestion: Some(format!(
+                "{} If it is required, add a `# SAFETY:` comment explaining why.",
+                construct.suggestion
+            )),
+        },
+    ));
+}
+
+/// Returns the code on `line` with string contents and comments blanked out.
+fn code_portion(line: &str, in_block_comment: &mut bool) -> String {
+    if *in_block_comment {
+        if let Some(end) = line.find("]#") {
+            *in_block_comment = false;
+            return code_portion(&line[end + 2..], in_block_comment);
+        }
+        return String::new();
+    }
+
+    let mut code = String::with_capacity(line.len());
+    let mut in_string = false;
+    let mut chars = line.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' if in_string => {
+                chars.next();
+            }
+            '"' => {
+                in_string = !in_string;
+                code.push(c);
+            }
+            '#' if !in_string => {
+                if line[i..].starts_with("#[") && !line[i..].contains("]#") {
+                    *in_block_comment = true;
+                }
+                break;
+            }
+            _ if in_string => code.push(' '),
+            _ => code.push(c),
+        }
+    }
+    code
+}
+
+/// A `# SAFETY:` comment on the same line or in the comment block directly
+/// above. Blank lines and pragma lines such as `{.push inline.}` in between
+/// are skipped.
+fn has_safety_comment(idx: usize, lines: &[&str]) -> bool {
+    if lines.get(idx).is_some_and(|l| l.contains("# SAFETY:")) {
+        return true;
+    }
+    let above = lines.get(..idx).unwrap_or_default();
+    for line in above.iter().rev() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || is_pragma(trimmed) {
+            continue;
+        }
+        if !trimmed.starts_with('#') {
+            return false;
+        }
+        if trimmed.contains("SAFETY:") {
+            return true;
+        }
+    }
+    false
+}
+
+fn is_pragma(trimmed: &str) -> bool {
+    trimmed.starts_with("{.") && trimmed.ends_with(".}")
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::indexing_slicing)]
+mod tests {
+    use super::*;
+    use crate::config::RuleConfig;
+    use crate::lang::Lang;
+    use tree_sitter::Parser;
+
+    fn run(code: &str, config: &RuleConfig) -> Vec<Violation> {
+        let mut parser = Parser::new();
+        parser.set_language(Lang::Nim.grammar()).unwrap();
+        let tree = parser.parse(code, None).unwrap();
+        let ctx = CheckContext::new(Lang::Nim, tree.root_node(), code, "unsafe.nim", config);
+        let mut out = Vec::new();
+        check_nim_specifics(&ctx, &mut out);
+        out
+    }
+
+    #[test]
+    fn test_unannotated_constructs_flagged() {
+        let code = "\
+proc raw(x: int) =
+  let y = cast[float](x)
+  let p = addr(x)
+  var q: ptr int
+  {.emit: \"printf(\\\"hi\\\");\".}
+  {.push checks:off.}
+  {.push boundChecks: off.}
+";
+        let out = run(code, &RuleConfig::default());
+        let rows: Vec<usize> = out.iter().map(|v| v.row).collect();
+        assert_eq!(rows, vec![2, 3, 4, 5, 6, 7]);
+        assert!(out.iter().all(|v| v.law == "LAW OF PARANOIA"));
+        assert!(out[0].message.contains("`cast[]`"));
+    }
+
+    #[test]
+    fn test_safety_annotations_and_comments_accepted() {
+        let code = "\
+# SAFETY: reinterpreting the IEEE bits is the point
+let y = cast[float](x)
+let p = addr(x) # SAFETY: x outlives p
+# mention of ptr and cast[int] in a comment
+let s = \"addr ptr cast[int]\"
+#[ block comment
+  {.emit: \"\".}
+]#
+";
+        assert!(run(code, &RuleConfig::default()).is_empty());
+    }
+
+    #[test]
+    fn test_safety_comment_reaches_past_pragmas_and_blanks() {
+        let code = "\
+# SAFETY: buf is pinned for the whole call
+
+{.push stackTrace: off.}
+{.push inline.}
+
+let p = addr(buf)
+let y = 1
+let q = addr(buf)
+";
+        let out = run(code, &RuleConfig::default());
+        let rows: Vec<usize> = out.iter().map(|v| v.row).collect();
+        assert_eq!(rows, vec![8]);
+    }
+
+    #[test]
+    fn test_ban_unsafe_ignores_annotations() {
+        let mut config = RuleConfig::default();
+        config.safety.ban_unsafe = true;
+        let out = run("let p = addr(x) # SAFETY: ok\n", &config);
+        assert_eq!(out.len(), 1);
+        assert!(out[0].message.contains("strictly prohibited"));
+    }
+}
@@ -42,12 +42,7 @@ mod tests {
         parser.set_language(lang.grammar()).unwrap();
         let tree = parser.parse(code, None).unwrap();
         let config = RuleConfig::default();
-        let ctx = CheckContext {
-            root: tree.root_node(),
-            source: code,
-            filename: "test.rs",
-            config: &config,
-        };
+        let ctx = CheckContext::new(lang, tree.root_node(), code, "test.rs", &config);
         let mut violations = Vec::new();
         check_syntax(&ctx, &mut violations);
         assert!(!violations.is_empty());
@@ -61,12 +56,7 @@ mod tests {
         parser.set_language(lang.grammar()).unwrap();
         let tree = parser.parse(code, None).unwrap();
         let config = RuleConfig::default();
-        let ctx = CheckContext {
-            root: tree.root_node(),
-            source: code,
-            filename: "test.rs",
-            config: &config,
-        };
+        let ctx = CheckContext::new(lang, tree.root_node(), code, "test.rs", &config);
         let mut violations = Vec::new();
         check_syntax(&ctx, &mut violations);
         assert!(violations.is_empty());
@@ -42,18 +42,34 @@ impl<'a> Scorer<'a> {
     }
 
     fn assess_node(&self, node: Node, nesting: usize) -> (usize, usize) {
+        if node.is_named() {
+            if let Some(assessed) = Self::assess_nim(node.kind(), nesting) {
+                return assessed;
+            }
+        }
         let kind = node.kind();
         match kind {
             "if_expression" | "match_expression" | "for_expression" | "while_expression" | "loop_expression" => {
                 Self::handle_control_flow(node, nesting)
             }
-            "binary_expression" => (Self::handle_logic(node, self.source), nesting),
+            "binary_expression" | "infix_expression" => (Self::handle_logic(node, self.source), nesting),
             "match_arm" => (1, nesting),
             "function_item" | "closure_expression" | "function_definition" | "method_definition" => (0, 0),
             _ => (0, nesting),
         }
     }
 
+    /// Nim statement nodes share names with keyword tokens, so callers only pass named nodes.
+    fn assess_nim(kind: &str, nesting: usize) -> Option<(usize, usize)> {
+        match kind {
+            "if" | "when" | "case" | "for" | "while" | "try" => Some((1 + nesting, nesting + 1)),
+            "elif_branch" | "of_branch" | "except_branch" => Some((1, nesting)),
+            "proc_declaration" | "func_declaration" | "method_declaration"
+            | "iterator_declaration" | "converter_declaration" => Some((0, 0)),
+            _ => None,
+        }
+    }
+
     fn handle_control_flow(node: Node, nesting: usize) -> (usize, usize) {
         if Self::is_else_if(node) {
             (1, nesting)
@@ -104,7 +120,7 @@ impl<'a> Scorer<'a> {
         };
 
         op.utf8_text(source.as_bytes())
-            .is_ok_and(|text| text == "&&" || text == "||")
+            .is_ok_and(|text| matches!(text, "&&" | "||" | "and" | "or"))
     }
 }
 
@@ -0,0 +1,227 @@
+//! Custom rules declared in `slopchop.toml`.
+//!
+//! Each `[[rules.custom]]` entry is a tree-sitter query; every match
+//! becomes a violation with the rule's id as its law. Queries are compiled
+//! once at config-load time, per profile, since each profile carries its
+//! own rule set.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+
+use regex::Regex;
+use tree_sitter::{Node, Query, QueryCursor};
+
+use crate::config::glob;
+use crate::config::profiles::{ProfileSettings, APPLICATION, SYSTEMS};
+use crate::config::types::{CustomRuleConfig, RuleConfig};
+use crate::lang::Lang;
+use crate::types::{Severity, Violation, ViolationDetails};
+use crate::utils::intern;
+
+use super::patterns::get_capture_node;
+
+/// A compiled custom rule.
+#[derive(Debug)]
+pub struct CustomRule {
+    id: &'static str,
+    lang: Lang,
+    query: Query,
+    anchor: Option<u32>,
+    message: String,
+    suggestion: Option<String>,
+    severity: Severity,
+    include: Vec<Regex>,
+    exclude: Vec<Regex>,
+}
+
+/// Compiled custom rules, keyed by profile name.
+#[derive(Debug, Clone, Default)]
+pub struct CustomRules {
+    by_profile: HashMap<String, Arc<Vec<CustomRule>>>,
+}
+
+impl CustomRules {
+    /// Compiles the custom rules of every profile. Invalid rules are
+    /// skipped and described in the returned errors.
+    #[must_use]
+    pub fn compile(base: &RuleConfig, profiles: &ProfileSettings) -> (Self, Vec<String>) {
+        let mut names: Vec<&str> = vec![APPLICATION, SYSTEMS];
+        names.extend(profiles.profiles().keys().map(String::as_str));
+
+        let mut by_profile = HashMap::new();
+        let mut errors = Vec::new();
+        for name in names {
+            let Some(rules) = profiles.rules_named(base, name) else {
+                continue;
+            };
+            let (compiled, failed) = compile_all(&rules.custom);
+            // Application and systems share `[rules]`; report its errors once.
+            if name != SYSTEMS {
+                errors.extend(failed);
+            }
+            by_profile.insert(name.to_string(), Arc::new(compiled));
+        }
+        errors.dedup();
+        (Self { by_profile }, errors)
+    }
+
+    /// Rules for a profile; unknown profiles, which loading reports as
+    /// errors, fall back to `application`, matching how rule sets resolve.
+    #[must_use]
+    pub fn for_profile(&self, profile: &str) -> &[CustomRule] {
+        self.by_profile
+            .get(profile)
+            .or_else(|| self.by_profile.get(APPLICATION))
+            .map_or(&[], |rules| rules.as_slice())
+    }
+}
+
+/// Runs the rules that apply to `path` over a parsed file.
+#[must_use]
+pub fn detect(
+    rules: &[CustomRule],
+    path: &Path,
+    lang: Lang,
+    source: &str,
+    root: Node,
+) -> Vec<Violation> {
+    let normalized = glob::normalize(path);
+    rules
+        .iter()
+        .filter(|r| r.lang == lang && r.applies_to(&normalized))
+        .flat_map(|r| run(r, source, root))
+        .collect()
+}
+
+impl CustomRule {
+    fn applies_to(&self, path: &str) -> bool {
+        let included = self.include.is_empty() || self.include.iter().any(|re| re.is_match(path));
+        included && !self.exclude.iter().any(|re| re.is_match(path))
+    }
+}
+
+fn run(rule: &CustomRule, source: &str, root: Node) -> Vec<Violation> {
+    let mut cursor = QueryCursor::new();
+    cursor
+        .matches(&rule.query, root, source.as_bytes())
+        .filter_map(|m| {
+            get_capture_node(&m, rule.anchor).or_else(|| m.captures.first().map(|c| c.node))
+        })
+        .map(|node| violation(rule, node.start_position().row + 1))
+        .collect()
+}
+
+fn violation(rule: &CustomRule, row: usize) -> Violation {
+    let details = ViolationDetails {
+        function_name: None,
+        analysis: vec![format!("Custom rule `{}` from slopchop.toml.", rule.id)],
+        suggestion: rule.suggestion.clone(),
+    };
+    Violation::with_details(row, rule.message.clone(), rule.id, details)
+        .with_severity(rule.severity)
+}
+
+fn compile_all(configs: &[CustomRuleConfig]) -> (Vec<CustomRule>, Vec<String>) {
+    let mut rules = Vec::new();
+    let mut errors = Vec::new();
+    for config in configs {
+        match compile_rule(config) {
+            Ok(rule) => rules.push(rule),
+            Err(e) => errors.push(format!("custom rule `{}`: {e}", config.id)),
+        }
+    }
+    (rules, errors)
+}
+
+fn compile_rule(config: &CustomRuleConfig) -> Result<CustomRule, String> {
+    let lang = Lang::from_name(&config.language)
+        .ok_or_else(|| format!("unknown language `{}`", config.language))?;
+    let query = Query::new(lang.grammar(), &config.query).map_err(|e| {
+        format!(
+            "invalid query at line {}, column {} ({:?}): {}",
+            e.row + 1,
+            e.column + 1,
+            e.kind,
+            e.message.trim()
+        )
+    })?;
+    Ok(CustomRule {
+        id: intern(&config.id),
+        lang,
+        anchor: query.capture_index_for_name("match"),
+        query,
+        message: config.message.clone(),
+        suggestion: config.suggestion.clone(),
+        severity: config.severity,
+        include: compile_globs(&config.include)?,
+        exclude: compile_globs(&config.exclude)?,
+    })
+}
+
+fn compile_globs(globs: &[String]) -> Result<Vec<Regex>, String> {
+    globs
+        .iter()
+        .map(|g| glob::to_regex(g).ok_or_else(|| format!("invalid path glob `{g}`")))
+        .collect()
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use tree_sitter::Parser;
+
+    fn rule(query: &str) -> CustomRuleConfig {
+        CustomRuleConfig {
+            id: "no-print".into(),
+            language: "python".into(),
+            query: query.into(),
+            message: "Use logging instead of print".into(),
+            suggestion: Some("logger.info(...)".into()),
+            severity: Severity::Warning,
+            include: vec!["src/**".into()],
+            exclude: vec!["src/scripts/**".into()],
+        }
+    }
+
+    fn scan(rules: &[CustomRule], path: &str, source: &str) -> Vec<Violation> {
+        let mut parser = Parser::new();
+        parser.set_language(Lang::Python.grammar()).unwrap();
+        let tree = parser.parse(source, None).unwrap();
+        detect(
+            rules,
+            Path::new(path),
+            Lang::Python,
+            source,
+            tree.root_node(),
+        )
+    }
+
+    #[test]
+    fn test_matches_become_violations() {
+        let q = r#"(call function: (identifier) @f (#eq? @f "print")) @match"#;
+        let (rules, errors) = compile_all(&[rule(q)]);
+        assert!(errors.is_empty());
+
+        let source = "x = 1\nprint(x)\nlog(x)\n";
+        let found = scan(&rules, "src/app.py", source);
+        assert_eq!(found.len(), 1);
+        let v = found.first().unwrap();
+        assert_eq!(
+            (v.row, v.law, v.severity),
+            (2, "no-print", Severity::Warning)
+        );
+
+        assert!(scan(&rules, "src/scripts/dev.py", source).is_empty());
+        assert!(scan(&rules, "tests/test_app.py", source).is_empty());
+    }
+
+    #[test]
+    fn test_invalid_query_is_reported() {
+        let (rules, errors) = compile_all(&[rule("(call function: (nonexistent_node))")]);
+        assert!(rules.is_empty());
+        let error = errors.first().unwrap();
+        assert!(error.starts_with("custom rule `no-print`: invalid query at line 1"));
+    }
+}
@@ -3,34 +3,40 @@
 use std::collections::HashMap;
 use std::path::PathBuf;
 
-use crate::config::RuleConfig;
+use crate::config::{Config, RuleConfig};
 use crate::types::Violation;
 
 use super::aggregator::Aggregator;
 use super::inspector::Inspector;
 
 pub struct DeepAnalyzer<'a> {
-    config: &'a RuleConfig,
+    config: &'a Config,
 }
 
 impl<'a> DeepAnalyzer<'a> {
     #[must_use]
-    pub fn new(config: &'a RuleConfig) -> Self {
+    pub fn new(config: &'a Config) -> Self {
         Self { config }
     }
 
     #[must_use]
     pub fn compute_violations(&self, agg: &Aggregator) -> HashMap<PathBuf, Vec<Violation>> {
         let mut results: HashMap<PathBuf, Vec<Violation>> = HashMap::new();
-        let inspector = Inspector::new(self.config);
+        // Each file is judged by the rules of its own profile.
+        let mut rules: HashMap<&PathBuf, RuleConfig> = HashMap::new();
 
         for (full_name, scope) in &agg.global_scopes {
             let path_str = full_name.split("::").next().unwrap_or("");
-            if let Some(path) = agg.path_map.get(path_str) {
-                let vs = inspector.inspect(scope);
-                if !vs.is_empty() {
-                    results.entry(path.clone()).or_default().extend(vs);
-                }
+            let Some(path) = agg.path_map.get(path_str) else {
+                continue;
+            };
+            let file_rules = rules
+                .entry(path)
+                .or_insert_with(|| self.config.rules_for(path).1);
+            let mut vs = Inspector::new(file_rules).inspect(scope);
+            crate::rules::apply_levels(&mut vs, &file_rules.severity);
+            if !vs.is_empty() {
+                results.entry(path.clone()).or_default().extend(vs);
             }
         }
         results
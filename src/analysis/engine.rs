@@ -10,8 +10,10 @@ use crate::config::Config;
 use crate::types::{FileReport, ScanReport, Violation};
 
 use super::aggregator::Aggregator;
+use super::cache::{self, CACHE_DIR};
 use super::deep::DeepAnalyzer;
-use super::worker;
+use super::graph_pass::{self, extend_violations};
+use super::suppress;
 
 /// Source files below this threshold skip structural metrics (LCOM4, CBO, AHF, SFOUT).
 /// Rationale: For small projects, modularity metrics are noise, not signal.
@@ -21,12 +23,40 @@ pub const SMALL_CODEBASE_THRESHOLD: usize = 10;
 /// Orchestrates file scanning, pattern detection, and structural analysis.
 pub struct Engine {
     config: Config,
+    cache: bool,
+    partial: bool,
 }
 
 impl Engine {
+    /// An engine for `config.root` that reads and writes the scan cache
+    /// in its `.slopchop/cache`.
     #[must_use]
     pub fn new(config: Config) -> Self {
-        Self { config }
+        Self {
+            config,
+            cache: true,
+            partial: false,
+        }
+    }
+
+    /// Neither reads nor writes the scan cache.
+    #[must_use]
+    pub fn without_cache(self) -> Self {
+        Self {
+            cache: false,
+            ..self
+        }
+    }
+
+    /// Marks the scanned files as only part of the project. Graph checks
+    /// (locality, cycles, declared layers) need every file, so they are
+    /// skipped.
+    #[must_use]
+    pub fn partial(self) -> Self {
+        Self {
+            partial: true,
+            ..self
+        }
     }
 
     /// Returns the small codebase threshold for external display.
@@ -50,13 +80,7 @@ impl Engine {
 
         // Phase 1: Local Analysis (Parallel)
         // Token counts, patterns, basic checks, scope extraction
-        let mut results: Vec<FileReport> = files
-            .par_iter()
-            .inspect(|path| {
-                on_progress(path);
-            })
-            .map(|path| worker::scan_file(path, &self.config))
-            .collect();
+        let mut results = self.scan_local(files, on_progress);
 
         // Phase 2: Deep Analysis (Sequential/Aggregated)
         // Structural metrics (LCOM4, CBO) for sufficiently large codebases
@@ -66,12 +90,16 @@ impl Engine {
             let deep_violations = self.run_deep_analysis(&results);
             merge_violations(&mut results, &deep_violations);
         }
+        let graph = self.graph_violations(&results);
+        merge_violations(&mut results, &graph);
 
         ScanReport {
             total_violations: results.iter().map(|r| r.violations.len()).sum(),
             total_tokens: results.iter().map(|r| r.token_count).sum(),
             files: results,
             duration_ms: start.elapsed().as_millis(),
+            baseline: None,
+            diff: None,
         }
     }
 
@@ -80,22 +108,44 @@ impl Engine {
     pub fn scan(&self, files: &[PathBuf]) -> ScanReport {
         let start = std::time::Instant::now();
 
-        let mut results: Vec<FileReport> = files
-            .par_iter()
-            .map(|path| worker::scan_file(path, &self.config))
-            .collect();
+        let mut results = self.scan_local(files, &|_| {});
 
         if should_run_deep_analysis(&results) {
             let deep_violations = self.run_deep_analysis(&results);
             merge_violations(&mut results, &deep_violations);
         }
+        let graph = self.graph_violations(&results);
+        merge_violations(&mut results, &graph);
 
         ScanReport {
             total_violations: results.iter().map(|r| r.violations.len()).sum(),
             total_tokens: results.iter().map(|r| r.token_count).sum(),
             files: results,
             duration_ms: start.elapsed().as_millis(),
+            baseline: None,
+            diff: None,
+        }
+    }
+
+    /// Structural (deep) and locality violations only, keyed by path, with
+    /// inline suppressions applied. Structural metrics are skipped for
+    /// small codebases.
+    #[must_use]
+    pub fn scan_deep(&self, files: &[PathBuf]) -> HashMap<PathBuf, Vec<Violation>> {
+        let results = self.scan_local(files, &|_| {});
+        let mut deep = self.graph_violations(&results);
+        if should_run_deep_analysis(&results) {
+            extend_violations(&mut deep, self.run_deep_analysis(&results));
         }
+        suppress_deep(results, deep)
+    }
+
+    /// Graph violations, or none for a partial scan.
+    fn graph_violations(&self, results: &[FileReport]) -> HashMap<PathBuf, Vec<Violation>> {
+        if self.partial {
+            return HashMap::new();
+        }
+        graph_pass::violations(results, &self.config)
     }
 
     fn run_deep_analysis(&self, results: &[FileReport]) -> HashMap<PathBuf, Vec<Violation>> {
@@ -108,9 +158,33 @@ impl Engine {
         }
 
         // Run deep inspector
-        let deep_analyzer = DeepAnalyzer::new(&self.config.rules);
+        let deep_analyzer = DeepAnalyzer::new(&self.config);
         deep_analyzer.compute_violations(&aggregator)
     }
+
+    /// Phase 1: local analysis, served from the scan cache where possible.
+    fn scan_local<F>(&self, files: &[PathBuf], on_progress: &F) -> Vec<FileReport>
+    where
+        F: Fn(&Path) + Sync,
+    {
+        let config = &self.config;
+        let dir = self.cache.then(|| config.root.join(CACHE_DIR));
+        let mut cache = dir
+            .as_deref()
+            .map(|dir| cache::load(dir, config))
+            .unwrap_or_default();
+        let scanned = files
+            .par_iter()
+            .inspect(|path| on_progress(path))
+            .map(|path| cache::scan_file(path, config, &cache))
+            .collect();
+        let results = cache::update(&mut cache, &config.root, scanned);
+        if let Some(dir) = dir {
+            // The cache only saves time; failing to write it must not fail the scan.
+            let _ = cache::save(&cache, &dir);
+        }
+        results
+    }
 }
 
 fn should_run_deep_analysis(results: &[FileReport]) -> bool {
@@ -123,8 +197,24 @@ fn merge_violations(results: &mut [FileReport], deep: &HashMap<PathBuf, Vec<Viol
     for r in results {
         if let Some(v) = deep.get(&r.path) {
             r.violations.extend(v.clone());
+            suppress::apply(r);
+        }
+    }
+}
+
+/// Keeps only the deep violations, with each file's inline suppressions applied.
+fn suppress_deep(
+    results: Vec<FileReport>,
+    mut deep: HashMap<PathBuf, Vec<Violation>>,
+) -> HashMap<PathBuf, Vec<Violation>> {
+    for mut report in results {
+        if let Some(violations) = deep.remove(&report.path) {
+            report.violations = violations;
+            suppress::apply(&mut report);
+            deep.insert(report.path, report.violations);
         }
     }
+    deep
 }
 
 /// Returns true if path is a source file (not test/bench/example).
@@ -142,3 +232,6 @@ fn is_source_file(path: &Path) -> bool {
 
     true
 }
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,36 @@
+// src/analysis/engine/tests.rs
+//! Engine tests.
+
+use super::*;
+use std::fs;
+
+fn cyclic_project() -> (tempfile::TempDir, Config, Vec<PathBuf>) {
+    let tmp = tempfile::tempdir().unwrap();
+    fs::create_dir(tmp.path().join("src")).unwrap();
+    fs::write(tmp.path().join("src/a.rs"), "use crate::b;\n").unwrap();
+    fs::write(tmp.path().join("src/b.rs"), "use crate::a;\n").unwrap();
+    let config = Config {
+        root: tmp.path().to_path_buf(),
+        ..Config::default()
+    };
+    let files = vec![PathBuf::from("src/a.rs"), PathBuf::from("src/b.rs")];
+    (tmp, config, files)
+}
+
+#[test]
+fn test_graph_resolves_against_the_project_root() {
+    let (tmp, config, files) = cyclic_project();
+    let report = Engine::new(config).without_cache().scan(&files);
+    assert!(
+        report.total_violations > 0,
+        "the a <-> b cycle should be reported"
+    );
+    assert!(!tmp.path().join(CACHE_DIR).exists());
+}
+
+#[test]
+fn test_partial_scans_skip_graph_checks() {
+    let (_tmp, config, files) = cyclic_project();
+    let report = Engine::new(config).without_cache().partial().scan(&files);
+    assert_eq!(report.total_violations, 0);
+}
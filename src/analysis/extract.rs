@@ -1,8 +1,10 @@
 //! Rust scope extraction logic (Structs/Enums/Fields).
 //! Method extraction moved to `extract_impl.rs` for Atomicity.
 
+use super::queries::QuerySet;
 use super::scope::{FieldInfo, Scope};
-use tree_sitter::{Node, Query, QueryCursor};
+use crate::lang::Lang;
+use tree_sitter::{Node, QueryCursor};
 
 pub struct RustExtractor;
 
@@ -30,7 +32,7 @@ impl RustExtractor {
         out: &mut std::collections::HashMap<String, Scope>,
     ) {
         let query_str = "(field_declaration) @field";
-        let Ok(query) = Query::new(tree_sitter_rust::language(), query_str) else {
+        let Some(query) = QuerySet::for_lang(Lang::Rust).get(query_str) else {
             return;
         };
         let mut cursor = QueryCursor::new();
@@ -80,17 +82,13 @@ impl RustExtractor {
             return;
         };
 
-        let mut is_public = false;
         let mut cursor = field_node.walk();
-        for child in field_node.children(&mut cursor) {
-            if child.kind() == "visibility_modifier" {
-                if let Ok(vis_text) = child.utf8_text(source.as_bytes()) {
-                    if vis_text.contains("pub") {
-                        is_public = true;
-                    }
-                }
-            }
-        }
+        let is_public = field_node.children(&mut cursor).any(|child| {
+            child.kind() == "visibility_modifier"
+                && child
+                    .utf8_text(source.as_bytes())
+                    .is_ok_and(|vis_text| vis_text.contains("pub"))
+        });
 
         scope.add_field(
             field_name.to_string(),
@@ -121,16 +119,18 @@ impl RustExtractor {
     }
 
     fn process_attribute_node(source: &str, node: Node, scope: &mut Scope) {
-        if node.kind() == "attribute_item" {
-            let text = node.utf8_text(source.as_bytes()).unwrap_or("");
-            if text.contains("derive") {
-                let content = text.replace("#[derive(", "").replace(")]", "");
-                for trait_name in content.split(',') {
-                    let t = trait_name.trim().to_string();
-                    if !t.is_empty() {
-                        scope.add_derive(t);
-                    }
-                }
+        if node.kind() != "attribute_item" {
+            return;
+        }
+        let text = node.utf8_text(source.as_bytes()).unwrap_or("");
+        if !text.contains("derive") {
+            return;
+        }
+        let content = text.replace("#[derive(", "").replace(")]", "");
+        for trait_name in content.split(',') {
+            let t = trait_name.trim().to_string();
+            if !t.is_empty() {
+                scope.add_derive(t);
             }
         }
     }
@@ -139,27 +139,27 @@ impl RustExtractor {
         source: &str,
         root: Node,
         out: &mut std::collections::HashMap<String, Scope>,
-        query_str: &str,
+        query_str: &'static str,
         is_enum: bool,
     ) {
-        let Ok(query) = Query::new(tree_sitter_rust::language(), query_str) else {
+        let Some(query) = QuerySet::for_lang(Lang::Rust).get(query_str) else {
             return;
         };
         let mut cursor = QueryCursor::new();
         for m in cursor.matches(&query, root, source.as_bytes()) {
-            if let Some(cap) = m.captures.first() {
-                if let Ok(name) = cap.node.utf8_text(source.as_bytes()) {
-                    let row = cap.node.start_position().row + 1;
-                    out.insert(
-                        name.to_string(),
-                        if is_enum {
-                            Scope::new_enum(name, row)
-                        } else {
-                            Scope::new(name, row)
-                        },
-                    );
-                }
-            }
+            let Some(cap) = m.captures.first() else {
+                continue;
+            };
+            let Ok(name) = cap.node.utf8_text(source.as_bytes()) else {
+                continue;
+            };
+            let row = cap.node.start_position().row + 1;
+            let scope = if is_enum {
+                Scope::new_enum(name, row)
+            } else {
+                Scope::new(name, row)
+            };
+            out.insert(name.to_string(), scope);
         }
     }
 }
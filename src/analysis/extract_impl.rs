@@ -2,14 +2,16 @@
 //! Separated from extract.rs to satisfy Law of Atomicity (token limit).
 
 use super::cognitive::CognitiveAnalyzer;
+use super::queries::QuerySet;
 use super::scope::{Method, Scope};
+use crate::lang::Lang;
 use std::collections::HashMap;
-use tree_sitter::{Node, Query, QueryCursor, TreeCursor};
+use tree_sitter::{Node, QueryCursor, TreeCursor};
 
 #[allow(clippy::implicit_hasher)]
 pub fn extract(source: &str, root: Node, out: &mut HashMap<String, Scope>) {
     let q_str = "(impl_item type: (type_identifier) @name body: (declaration_list) @body)";
-    let Ok(query) = Query::new(tree_sitter_rust::language(), q_str) else {
+    let Some(query) = QuerySet::for_lang(Lang::Rust).get(q_str) else {
         return;
     };
     let mut cursor = QueryCursor::new();
@@ -0,0 +1,210 @@
+//! Machine-applicable fixes attached to violations.
+//!
+//! Each builder returns `None` when the rewrite would not be safe to apply
+//! without a human, so every attached fix can be applied by `slopchop fix`.
+
+use tree_sitter::Node;
+
+use crate::types::{Edit, Fix};
+
+/// Stub inserted above an unjustified `unsafe` block.
+pub const SAFETY_STUB: &str = "// SAFETY: TODO: explain why this is sound.";
+
+/// Calls known to return `io::Result`, as path suffixes.
+const IO_FUNCTIONS: &[&str] = &[
+    "fs::read_to_string",
+    "fs::read",
+    "fs::write",
+    "fs::read_dir",
+    "fs::metadata",
+    "fs::canonicalize",
+    "fs::copy",
+    "fs::rename",
+    "fs::create_dir_all",
+    "fs::remove_file",
+    "fs::remove_dir_all",
+    "File::open",
+    "File::create",
+];
+
+/// Methods known to return `io::Result`.
+const IO_METHODS: &[&str] = &[
+    "read_to_string",
+    "read_to_end",
+    "read_line",
+    "write_all",
+    "sync_all",
+];
+
+/// Rewrites `x.unwrap()` to `x?` when `x` is a call known to return
+/// `io::Result` and the enclosing function returns a `Result` whose error
+/// converts from `io::Error`. Anything else, such as an `Option` or an
+/// unknown error type, is left to a human. `call` is the `call_expression`.
+#[must_use]
+pub fn unwrap_to_try(call: Node, source: &str) -> Option<Fix> {
+    let receiver = call
+        .child_by_field_name("function")?
+        .child_by_field_name("value")?;
+    if !is_io_call(receiver, source) || !returns_io_compatible(call, source) {
+        return None;
+    }
+    Some(Fix {
+        description: "Replace `.unwrap()` with `?`".into(),
+        edits: vec![Edit {
+            start: receiver.end_byte(),
+            end: call.end_byte(),
+            replacement: "?".into(),
+        }],
+    })
+}
+
+/// Inserts a `// SAFETY:` TODO stub on the line above `node`, at its
+/// indentation.
+#[must_use]
+pub fn safety_stub(node: Node, source: &str) -> Fix {
+    let line_start = source[..node.start_byte()].rfind('\n').map_or(0, |i| i + 1);
+    let line = &source[line_start..];
+    let indent = &line[..line.len() - line.trim_start().len()];
+    Fix {
+        description: "Insert a `// SAFETY:` stub".into(),
+        edits: vec![Edit {
+            start: line_start,
+            end: line_start,
+            replacement: format!("{indent}{SAFETY_STUB}\n"),
+        }],
+    }
+}
+
+/// True if `node` is a call to one of [`IO_FUNCTIONS`] or [`IO_METHODS`].
+fn is_io_call(node: Node, source: &str) -> bool {
+    if node.kind() != "call_expression" {
+        return false;
+    }
+    let Some(function) = node.child_by_field_name("function") else {
+        return false;
+    };
+    let text = |n: Node| n.utf8_text(source.as_bytes()).unwrap_or("");
+    match function.kind() {
+        "field_expression" => function
+            .child_by_field_name("field")
+            .is_some_and(|f| IO_METHODS.contains(&text(f))),
+        "scoped_identifier" => {
+            let path = text(function);
+            IO_FUNCTIONS
+                .iter()
+                .any(|f| path == *f || path.ends_with(&format!("::{f}")))
+        }
+        _ => false,
+    }
+}
+
+/// True if the innermost function around `node` returns a `Result` whose
+/// error converts from `io::Error`. Closures and async blocks have their
+/// own `?` target.
+fn returns_io_compatible(node: Node, source: &str) -> bool {
+    let mut current = node.parent();
+    while let Some(n) = current {
+        match n.kind() {
+            "closure_expression" | "async_block" => return false,
+            "function_item" => {
+                return n
+                    .child_by_field_name("return_type")
+                    .and_then(|t| t.utf8_text(source.as_bytes()).ok())
+                    .is_some_and(|t| accepts_io_error(t, source));
+            }
+            _ => current = n.parent(),
+        }
+    }
+    false
+}
+
+/// Error types `?` converts an `io::Error` into.
+const IO_COMPATIBLE_ERRORS: &[&str] = &["io::Error", "anyhow::Error", "Box<dyn"];
+
+fn accepts_io_error(return_type: &str, source: &str) -> bool {
+    let ty: String = return_type.split_whitespace().collect();
+    let ty = ty.strip_prefix("std::").unwrap_or(&ty);
+    if ty.starts_with("io::Result<") || ty.starts_with("anyhow::Result<") {
+        return true;
+    }
+    let Some(args) = ty.strip_prefix("Result<") else {
+        return false;
+    };
+    match args.rsplit_once(',') {
+        Some((_, error)) => IO_COMPATIBLE_ERRORS
+            .iter()
+            .any(|e| error.trim_start_matches("std::").starts_with(e)),
+        // One argument: an alias, known only when imported from anyhow.
+        None => source
+            .lines()
+            .any(|l| l.trim_start().starts_with("use anyhow::") && l.contains("Result")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::checks::with_context;
+    use crate::lang::Lang;
+
+    fn unwrap_fix(source: &str) -> Option<Fix> {
+        with_context(Lang::Rust, source, |ctx| {
+            let mut stack = vec![ctx.root];
+            while let Some(node) = stack.pop() {
+                let text = node.utf8_text(source.as_bytes()).unwrap_or("");
+                if node.kind() == "call_expression" && text.ends_with(".unwrap()") {
+                    return unwrap_to_try(node, ctx.source);
+                }
+                let mut cursor = node.walk();
+                stack.extend(node.children(&mut cursor));
+            }
+            None
+        })
+    }
+
+    #[test]
+    fn test_unwrap_to_try_only_on_known_io_calls() {
+        let source = "use anyhow::{Context, Result};\nfn f() -> Result<u8> { let x = fs::read(p).unwrap(); Ok(x[0]) }";
+        let fix = unwrap_fix(source).unwrap();
+        let edit = &fix.edits[0];
+        assert_eq!(&source[edit.start..edit.end], ".unwrap()");
+
+        let fixed = |body: &str| unwrap_fix(&format!("fn f() -> {body} }}")).is_some();
+        assert!(fixed("io::Result<()> { File::open(p).unwrap(); Ok(())"));
+        assert!(fixed(
+            "Result<(), Box<dyn Error>> { r.read_line(&mut s).unwrap(); Ok(())"
+        ));
+        assert!(fixed(
+            "std::io::Result<()> { std::fs::write(p, b).unwrap(); Ok(())"
+        ));
+        assert!(
+            !fixed("io::Result<u8> { map.get(k).unwrap()"),
+            "Option receiver"
+        );
+        assert!(!fixed("io::Result<u8> { g().unwrap()"), "unknown receiver");
+        assert!(
+            !fixed("io::Result<u8> { lock.write().unwrap()"),
+            "lock, not io"
+        );
+        assert!(!fixed("Result<u8, ParseError> { fs::read(p).unwrap()"));
+        assert!(!fixed("Result<u8> { fs::read(p).unwrap()"), "unknown alias");
+        assert!(!fixed("ResultSet { fs::read(p).unwrap()"));
+        assert!(!fixed("u8 { fs::read(p).unwrap()"));
+        assert!(!fixed(
+            "io::Result<()> { let c = || fs::read(p).unwrap(); Ok(())"
+        ));
+    }
+
+    #[test]
+    fn test_safety_stub_matches_indentation() {
+        let source = "fn f() {\n    let x = 1;\n    unsafe { g() }\n}\n";
+        let at = source.find("unsafe").unwrap();
+        let fix = with_context(Lang::Rust, source, |ctx| {
+            let node = ctx.root.descendant_for_byte_range(at, at + 6).unwrap();
+            safety_stub(node, source)
+        });
+        let edit = &fix.edits[0];
+        assert_eq!(edit.start, source.find("    unsafe").unwrap());
+        assert_eq!(edit.replacement, format!("    {SAFETY_STUB}\n"));
+    }
+}
@@ -0,0 +1,45 @@
+//! Graph checks run after the local pass: locality and declared
+//! architecture, over the whole project's import graph.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use crate::config::Config;
+use crate::graph::architecture;
+use crate::graph::locality::{collect_findings, ImportGraph};
+use crate::types::{FileReport, Violation};
+
+/// Locality and declared-architecture violations, built from the imports
+/// and types the local pass already extracted. Files the local pass could
+/// not parse, such as non-UTF-8 ones, are left out of the graph.
+pub(super) fn violations(
+    results: &[FileReport],
+    config: &Config,
+) -> HashMap<PathBuf, Vec<Violation>> {
+    let files: Vec<_> = results
+        .iter()
+        .filter_map(|report| Some((config.root.join(&report.path), report.analysis.as_ref()?)))
+        .collect();
+    let imports = files
+        .iter()
+        .map(|(path, analysis)| (path.as_path(), analysis.imports.as_slice(), analysis.types));
+    let graph = ImportGraph::resolve(&config.root, imports);
+    let mut found = collect_findings(&graph, config);
+    let mut declared = architecture::collect(&graph, &config.architecture);
+    // Locality levels come from `[rules.locality].mode`; declared rules
+    // follow `[rules.severity]` like any registered law.
+    for violations in declared.values_mut() {
+        crate::rules::apply_levels(violations, &config.rules.severity);
+    }
+    extend_violations(&mut found, declared);
+    found
+}
+
+pub(super) fn extend_violations(
+    into: &mut HashMap<PathBuf, Vec<Violation>>,
+    from: HashMap<PathBuf, Vec<Violation>>,
+) {
+    for (path, violations) in from {
+        into.entry(path).or_default().extend(violations);
+    }
+}
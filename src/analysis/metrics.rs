@@ -1,45 +1,122 @@
 use tree_sitter::{Node, Query, QueryCursor};
 
+/// Deepest nesting point found inside a function body.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Nesting {
+    /// Number of nested control-flow constructs at the deepest point.
+    pub depth: usize,
+    /// 1-based line of the innermost construct (0 if nothing nests).
+    pub row: usize,
+    /// Node kind of the innermost construct.
+    pub kind: &'static str,
+}
+
 /// Calculates the nesting depth of a node.
 #[must_use]
 pub fn calculate_max_depth(node: Node) -> usize {
-    // Directly walk the provided node (usually the function body block).
-    // walk_depth starts at 0 and increments when entering nesting constructs.
-    walk_depth(node, 0)
+    find_deepest_nesting(node).depth
+}
+
+/// Finds the deepest nested control-flow construct under `node`.
+/// Nested function definitions are measured on their own and are not descended into.
+#[must_use]
+pub fn find_deepest_nesting(node: Node) -> Nesting {
+    let mut deepest = Nesting { depth: 0, row: 0, kind: "" };
+    walk_depth(node, 0, &mut deepest);
+    deepest
 }
 
-fn walk_depth(node: Node, current: usize) -> usize {
-    let mut max = current;
+fn walk_depth(node: Node, current: usize, deepest: &mut Nesting) {
     let mut cursor = node.walk();
 
     for child in node.children(&mut cursor) {
-        let kind = child.kind();
-        if matches!(
-            kind,
-            "if_expression"
-                | "match_expression"
-                | "for_expression"
-                | "while_expression"
-                | "loop_expression"
-                | "if_statement"
-                | "for_statement"
-                | "for_in_statement"
-                | "while_statement"
-                | "do_statement"
-                | "switch_case"
-                | "catch_clause"
-                | "try_statement"
-                | "closure_expression"
-                | "arrow_function"
-                | "function_expression"
-                | "lambda"
-        ) {
-            max = std::cmp::max(max, walk_depth(child, current + 1));
-        } else {
-            max = std::cmp::max(max, walk_depth(child, current));
+        if is_nested_definition(child.kind()) {
+            continue;
         }
+        let depth = if increments_nesting(child) { current + 1 } else { current };
+        if depth > deepest.depth {
+            *deepest = Nesting {
+                depth,
+                row: child.start_position().row + 1,
+                kind: nesting_label(child.kind()),
+            };
+        }
+        walk_depth(child, depth, deepest);
+    }
+}
+
+fn increments_nesting(node: Node) -> bool {
+    let kind = node.kind();
+    // Named check keeps keyword tokens (`if`, `for`) from matching Nim's statement nodes.
+    if !node.is_named() || !is_nesting_kind(kind) {
+        return false;
+    }
+    // `else if` continues a chain rather than nesting deeper.
+    !(matches!(kind, "if_expression" | "if_statement")
+        && node.parent().is_some_and(|p| p.kind() == "else_clause"))
+}
+
+fn is_nesting_kind(kind: &str) -> bool {
+    matches!(
+        kind,
+        "if_expression"
+            | "match_expression"
+            | "for_expression"
+            | "while_expression"
+            | "loop_expression"
+            | "closure_expression"
+            | "if_statement"
+            | "for_statement"
+            | "for_in_statement"
+            | "while_statement"
+            | "do_statement"
+            | "switch_statement"
+            | "try_statement"
+            | "with_statement"
+            | "match_statement"
+            | "arrow_function"
+            | "function_expression"
+            | "lambda"
+            | "if"
+            | "when"
+            | "case"
+            | "for"
+            | "while"
+            | "try"
+    )
+}
+
+fn is_nested_definition(kind: &str) -> bool {
+    matches!(
+        kind,
+        "function_item"
+            | "function_definition"
+            | "function_declaration"
+            | "method_definition"
+            | "class_definition"
+            | "class_declaration"
+            | "proc_declaration"
+            | "func_declaration"
+            | "method_declaration"
+            | "iterator_declaration"
+            | "converter_declaration"
+    )
+}
+
+fn nesting_label(kind: &str) -> &'static str {
+    match kind {
+        "if_expression" | "if_statement" | "if" => "if",
+        "when" => "when",
+        "match_expression" | "match_statement" => "match",
+        "switch_statement" => "switch",
+        "case" => "case",
+        "for_expression" | "for_statement" | "for_in_statement" | "for" => "for",
+        "while_expression" | "while_statement" | "do_statement" | "while" => "while",
+        "loop_expression" => "loop",
+        "try_statement" | "try" => "try",
+        "with_statement" => "with",
+        _ => "closure",
     }
-    max
 }
 
 /// Calculates `McCabe` Cyclomatic Complexity.
@@ -55,13 +132,93 @@ pub fn calculate_complexity(node: Node, source: &str, query: &Query) -> usize {
 
 /// Counts named arguments/parameters.
 #[must_use]
-pub fn count_arguments(node: Node) -> usize {
-    let mut cursor = node.walk();
-    for child in node.children(&mut cursor) {
-        // Rust: "parameters", Python: "parameters", JS/TS: "formal_parameters"
-        if child.kind() == "parameters" || child.kind() == "formal_parameters" {
-            return child.named_child_count();
+pub fn count_arguments(node: Node, source: &str) -> usize {
+    parameters(node, source).len()
+}
+
+/// Returns the declared parameters of a function node, excluding the receiver
+/// (`self` in Rust, `self`/`cls` in Python) and comments.
+#[must_use]
+pub fn parameters<'a>(node: Node<'a>, source: &str) -> Vec<Node<'a>> {
+    let Some(list) = parameter_list(node) else {
+        return Vec::new();
+    };
+    if list.kind() == "identifier" {
+        // Single-parameter arrow function: `x => x + 1`
+        return vec![list];
+    }
+
+    let mut cursor = list.walk();
+    let mut params: Vec<Node<'a>> = list
+        .named_children(&mut cursor)
+        .filter(|p| !p.kind().contains("comment") && p.kind() != "self_parameter")
+        .flat_map(grouped_symbols)
+        .collect();
+
+    if node.kind() == "function_definition" {
+        let is_receiver = params
+            .first()
+            .and_then(|p| p.utf8_text(source.as_bytes()).ok())
+            .is_some_and(|t| t == "self" || t == "cls");
+        if is_receiver {
+            params.remove(0);
         }
     }
-    0
+    params
+}
+
+/// Expands Nim's grouped parameters (`a, b: int`) into one node per symbol.
+fn grouped_symbols(param: Node) -> Vec<Node> {
+    let mut cursor = param.walk();
+    let group = param
+        .named_children(&mut cursor)
+        .find(|c| c.kind() == "symbol_declaration_list");
+    let Some(group) = group else {
+        return vec![param];
+    };
+    let mut cursor = group.walk();
+    group.named_children(&mut cursor).collect()
+}
+
+fn parameter_list(node: Node) -> Option<Node> {
+    // Rust/Python/Nim: "parameters", JS/TS: "formal_parameters" or a bare arrow "parameter"
+    node.child_by_field_name("parameters")
+        .or_else(|| node.child_by_field_name("parameter"))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::lang::Lang;
+    use tree_sitter::Parser;
+
+    fn first_function(lang: Lang, code: &str, kind: &str) -> (tree_sitter::Tree, usize) {
+        let mut parser = Parser::new();
+        parser.set_language(lang.grammar()).unwrap();
+        let tree = parser.parse(code, None).unwrap();
+        let idx = {
+            let root = tree.root_node();
+            let mut cursor = root.walk();
+            let idx = root.named_children(&mut cursor).position(|n| n.kind() == kind);
+            idx.unwrap()
+        };
+        (tree, idx)
+    }
+
+    #[test]
+    fn test_else_if_chain_is_flat() {
+        let code = "fn f(x: i32) { if x > 1 { } else if x > 2 { } else if x > 3 { } }";
+        let (tree, idx) = first_function(Lang::Rust, code, "function_item");
+        let func = tree.root_node().named_child(idx).unwrap();
+        assert_eq!(calculate_max_depth(func), 1);
+    }
+
+    #[test]
+    fn test_python_receiver_excluded() {
+        let code = "def f(self, a, b=1, *args):\n    pass\n";
+        let (tree, idx) = first_function(Lang::Python, code, "function_definition");
+        let func = tree.root_node().named_child(idx).unwrap();
+        assert_eq!(count_arguments(func, code), 3);
+    }
 }
\ No newline at end of file
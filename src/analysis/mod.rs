@@ -1,11 +1,15 @@
 //! Core analysis logic (The "Rule Engine").
 
 pub mod ast;
+pub mod cache;
 pub mod checks;
+pub mod custom;
+pub mod fixes;
 pub mod cognitive;
 pub mod metrics;
 pub mod safety;
 pub mod structural;
+pub mod suppress;
 pub mod scope;
 pub mod inspector;
 pub mod aggregator;
@@ -14,9 +18,11 @@ pub mod visitor;
 pub mod extract;
 pub mod extract_impl; // New module
 pub mod patterns;
+pub mod queries;
 pub mod worker;
 
 mod engine;
+mod graph_pass;
 
 pub use engine::Engine;
 pub use aggregator::FileAnalysis;
@@ -1,22 +1,23 @@
 // src/analysis/v2/patterns/concurrency_lock.rs
 //! C03: `MutexGuard` held across `.await`
 
+use crate::analysis::checks::CheckContext;
 use crate::types::{Violation, ViolationDetails};
-use tree_sitter::{Node, Query, QueryCursor};
+use tree_sitter::{Node, QueryCursor};
 
 /// C03: `MutexGuard` held across `.await`
 #[must_use]
-pub fn detect_c03(source: &str, root: Node) -> Vec<Violation> {
+pub fn detect_c03(ctx: &CheckContext) -> Vec<Violation> {
     let mut violations = Vec::new();
     let query_str = r"(function_item (function_modifiers) @mods body: (block) @body) @fn";
     
-    let Ok(query) = Query::new(tree_sitter_rust::language(), query_str) else {
+    let Some(query) = ctx.query(query_str) else {
         return violations;
     };
     
     let mut cursor = QueryCursor::new();
-    for m in cursor.matches(&query, root, source.as_bytes()) {
-        if let Some(v) = check_async_fn(source, &m) {
+    for m in cursor.matches(&query, ctx.root, ctx.source.as_bytes()) {
+        if let Some(v) = check_async_fn(ctx.source, &m) {
             violations.push(v);
         }
     }
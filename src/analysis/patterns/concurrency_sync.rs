@@ -1,26 +1,27 @@
 // src/analysis/v2/patterns/concurrency_sync.rs
 //! C04: Undocumented synchronization primitives
 
+use crate::analysis::checks::CheckContext;
 use crate::types::{Violation, ViolationDetails};
-use tree_sitter::{Node, Query, QueryCursor};
+use tree_sitter::{Node, QueryCursor};
 
 /// C04: Arc<Mutex<T>> without documentation
 #[must_use]
-pub fn detect_c04(source: &str, root: Node) -> Vec<Violation> {
+pub fn detect_c04(ctx: &CheckContext) -> Vec<Violation> {
     let mut violations = Vec::new();
-    detect_sync_fields(source, root, &mut violations);
+    detect_sync_fields(ctx, &mut violations);
     violations
 }
 
-fn detect_sync_fields(source: &str, root: Node, out: &mut Vec<Violation>) {
+fn detect_sync_fields(ctx: &CheckContext, out: &mut Vec<Violation>) {
     let query_str = r"(field_declaration name: (field_identifier) @name) @field";
-    let Ok(query) = Query::new(tree_sitter_rust::language(), query_str) else {
+    let Some(query) = ctx.query(query_str) else {
         return;
     };
     
     let mut cursor = QueryCursor::new();
-    for m in cursor.matches(&query, root, source.as_bytes()) {
-        if let Some(v) = check_sync_field(source, &m) {
+    for m in cursor.matches(&query, ctx.root, ctx.source.as_bytes()) {
+        if let Some(v) = check_sync_field(ctx.source, &m) {
             out.push(v);
         }
     }
@@ -1,31 +1,33 @@
 // src/analysis/v2/patterns/db_patterns.rs
 //! Database anti-patterns: P03 (N+1 queries)
 
+use crate::analysis::checks::CheckContext;
 use crate::types::{Violation, ViolationDetails};
-use tree_sitter::{Node, Query, QueryCursor};
+use tree_sitter::{Node, QueryCursor};
 use super::get_capture_node;
 
 #[must_use]
-pub fn detect(source: &str, root: Node) -> Vec<Violation> {
+pub fn detect(ctx: &CheckContext) -> Vec<Violation> {
     let mut out = Vec::new();
-    detect_p03(source, root, &mut out);
+    detect_p03(ctx, &mut out);
     out
 }
 
 /// P03: N+1 Query - DB call inside loop using loop variable
-fn detect_p03(source: &str, root: Node, out: &mut Vec<Violation>) {
+fn detect_p03(ctx: &CheckContext, out: &mut Vec<Violation>) {
     let loop_q = r"
         (for_expression pattern: (_) @pat body: (block) @body) @loop
         (while_expression body: (block) @body) @loop
     ";
 
-    let Ok(query) = Query::new(tree_sitter_rust::language(), loop_q) else { return };
+    let Some(query) = ctx.query(loop_q) else { return };
     let idx_pat = query.capture_index_for_name("pat");
     let idx_body = query.capture_index_for_name("body");
     
     let mut cursor = QueryCursor::new();
 
-    for m in cursor.matches(&query, root, source.as_bytes()) {
+    let source = ctx.source;
+    for m in cursor.matches(&query, ctx.root, source.as_bytes()) {
         let loop_var_node = get_capture_node(&m, idx_pat);
         let body_node = get_capture_node(&m, idx_body);
 
@@ -33,7 +35,7 @@ fn detect_p03(source: &str, root: Node, out: &mut Vec<Violation>) {
         
         if let Ok(var_text) = var_node.utf8_text(source.as_bytes()) {
             let loop_var = extract_loop_var(var_text);
-            check_db_calls(source, body, &loop_var, out);
+            check_db_calls(ctx, body, &loop_var, out);
         }
     }
 }
@@ -42,7 +44,7 @@ fn extract_loop_var(pattern: &str) -> String {
     pattern.trim().trim_start_matches('(').split(',').next().unwrap_or(pattern).trim().to_string()
 }
 
-fn check_db_calls(source: &str, body: Node, loop_var: &str, out: &mut Vec<Violation>) {
+fn check_db_calls(ctx: &CheckContext, body: Node, loop_var: &str, out: &mut Vec<Violation>) {
     let patterns = [
         r#"(call_expression function: (field_expression field: (field_identifier) @m)
             (#match? @m "^(fetch_one|fetch_all|fetch_optional|execute|query|query_as|execute_many)$")) @call"#,
@@ -53,12 +55,13 @@ fn check_db_calls(source: &str, body: Node, loop_var: &str, out: &mut Vec<Violat
     ];
 
     for pattern in patterns {
-        check_pattern(source, body, pattern, loop_var, out);
+        check_pattern(ctx, body, pattern, loop_var, out);
     }
 }
 
-fn check_pattern(source: &str, body: Node, pattern: &str, loop_var: &str, out: &mut Vec<Violation>) {
-    let Ok(query) = Query::new(tree_sitter_rust::language(), pattern) else { return };
+fn check_pattern(ctx: &CheckContext, body: Node, pattern: &'static str, loop_var: &str, out: &mut Vec<Violation>) {
+    let Some(query) = ctx.query(pattern) else { return };
+    let source = ctx.source;
     let idx_call = query.capture_index_for_name("call");
     let mut cursor = QueryCursor::new();
 
@@ -1,28 +1,29 @@
 // src/analysis/v2/patterns/idiomatic.rs
 //! Idiomatic patterns: I01, I02
 
+use crate::analysis::checks::CheckContext;
 use crate::types::{Violation, ViolationDetails};
-use tree_sitter::{Node, Query, QueryCursor};
+use tree_sitter::{Node, QueryCursor};
 use super::get_capture_node;
 
 #[must_use]
-pub fn detect(source: &str, root: Node) -> Vec<Violation> {
+pub fn detect(ctx: &CheckContext) -> Vec<Violation> {
     let mut out = Vec::new();
-    detect_i01(source, root, &mut out);
-    detect_i02(source, root, &mut out);
+    detect_i01(ctx, &mut out);
+    detect_i02(ctx, &mut out);
     out
 }
 
 /// I01: Manual From impl that could use derive
-fn detect_i01(source: &str, root: Node, out: &mut Vec<Violation>) {
+fn detect_i01(ctx: &CheckContext, out: &mut Vec<Violation>) {
     let q = r"(impl_item) @impl";
-    let Ok(query) = Query::new(tree_sitter_rust::language(), q) else { return };
+    let Some(query) = ctx.query(q) else { return };
     let mut cursor = QueryCursor::new();
 
-    for m in cursor.matches(&query, root, source.as_bytes()) {
+    for m in cursor.matches(&query, ctx.root, ctx.source.as_bytes()) {
         let Some(impl_node) = m.captures.first().map(|c| c.node) else { continue };
 
-        let text = impl_node.utf8_text(source.as_bytes()).unwrap_or("");
+        let text = impl_node.utf8_text(ctx.source.as_bytes()).unwrap_or("");
         if !text.contains("impl From<") || !text.contains("for ") { continue }
         if text.contains("Error") { continue }
         if text.contains("if ") || text.contains("match ") { continue }
@@ -42,21 +43,21 @@ fn detect_i01(source: &str, root: Node, out: &mut Vec<Violation>) {
 }
 
 /// I02: Match arms with duplicate bodies
-fn detect_i02(source: &str, root: Node, out: &mut Vec<Violation>) {
+fn detect_i02(ctx: &CheckContext, out: &mut Vec<Violation>) {
     let q = r"(match_expression body: (match_block) @block) @match";
-    let Ok(query) = Query::new(tree_sitter_rust::language(), q) else { return };
+    let Some(query) = ctx.query(q) else { return };
     let idx_match = query.capture_index_for_name("match");
     let idx_block = query.capture_index_for_name("block");
     
     let mut cursor = QueryCursor::new();
 
-    for m in cursor.matches(&query, root, source.as_bytes()) {
+    for m in cursor.matches(&query, ctx.root, ctx.source.as_bytes()) {
         let match_node = get_capture_node(&m, idx_match);
         let block = get_capture_node(&m, idx_block);
 
         let (Some(match_node), Some(block)) = (match_node, block) else { continue };
 
-        if let Some(dup) = find_dup_arms(source, block) {
+        if let Some(dup) = find_dup_arms(ctx.source, block) {
             out.push(Violation::with_details(
                 match_node.start_position().row + 1,
                 "Duplicate match arm bodies".into(),
@@ -98,13 +99,11 @@ fn truncate(s: &str, max: usize) -> String {
 #[allow(clippy::unwrap_used)]
 mod tests {
     use super::*;
-    use tree_sitter::Parser;
+    use crate::analysis::checks::with_context;
+    use crate::lang::Lang;
 
     fn parse_and_detect(code: &str) -> Vec<Violation> {
-        let mut parser = Parser::new();
-        parser.set_language(tree_sitter_rust::language()).unwrap();
-        let tree = parser.parse(code, None).unwrap();
-        detect(code, tree.root_node())
+        with_context(Lang::Rust, code, detect)
     }
 
     #[test]
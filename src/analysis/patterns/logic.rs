@@ -1,33 +1,34 @@
 // src/analysis/v2/patterns/logic.rs
 //! Logic patterns: L02, L03
 
+use crate::analysis::checks::CheckContext;
 use crate::types::{Violation, ViolationDetails};
-use tree_sitter::{Node, Query, QueryCursor};
+use tree_sitter::{Node, QueryCursor};
 
 #[must_use]
-pub fn detect(source: &str, root: Node) -> Vec<Violation> {
+pub fn detect(ctx: &CheckContext) -> Vec<Violation> {
     let mut out = Vec::new();
-    detect_l02(source, root, &mut out);
-    detect_l03(source, root, &mut out);
+    detect_l02(ctx, &mut out);
+    detect_l03(ctx, &mut out);
     out
 }
 
 /// L02: Boundary ambiguity - `<=` or `>=` with `.len()`
-fn detect_l02(source: &str, root: Node, out: &mut Vec<Violation>) {
+fn detect_l02(ctx: &CheckContext, out: &mut Vec<Violation>) {
     let q = r"(binary_expression) @cmp";
-    let Ok(query) = Query::new(tree_sitter_rust::language(), q) else { return };
+    let Some(query) = ctx.query(q) else { return };
     let mut cursor = QueryCursor::new();
 
-    for m in cursor.matches(&query, root, source.as_bytes()) {
+    for m in cursor.matches(&query, ctx.root, ctx.source.as_bytes()) {
         let cmp = m.captures.first().map(|c| c.node);
         let Some(cmp) = cmp else { continue };
 
-        let text = cmp.utf8_text(source.as_bytes()).unwrap_or("");
+        let text = cmp.utf8_text(ctx.source.as_bytes()).unwrap_or("");
         if !text.contains(".len()") { continue }
         if !text.contains("<=") && !text.contains(">=") { continue }
 
         // Ignore threshold checks (len >= 5) vs index checks (i <= len)
-        if is_safe_threshold_check(cmp, source) { continue }
+        if is_safe_threshold_check(cmp, ctx.source) { continue }
 
         out.push(Violation::with_details(
             cmp.start_position().row + 1,
@@ -80,25 +81,25 @@ fn is_index_variable(name: &str) -> bool {
 }
 
 /// L03: Unchecked `[0]` or `.first().unwrap()`
-fn detect_l03(source: &str, root: Node, out: &mut Vec<Violation>) {
-    detect_index_zero(source, root, out);
-    detect_first_unwrap(source, root, out);
+fn detect_l03(ctx: &CheckContext, out: &mut Vec<Violation>) {
+    detect_index_zero(ctx, out);
+    detect_first_unwrap(ctx, out);
 }
 
-fn detect_index_zero(source: &str, root: Node, out: &mut Vec<Violation>) {
+fn detect_index_zero(ctx: &CheckContext, out: &mut Vec<Violation>) {
     // Walk all index_expression nodes
     let q = r"(index_expression) @idx";
-    let Ok(query) = Query::new(tree_sitter_rust::language(), q) else { return };
+    let Some(query) = ctx.query(q) else { return };
     let mut cursor = QueryCursor::new();
 
-    for m in cursor.matches(&query, root, source.as_bytes()) {
+    for m in cursor.matches(&query, ctx.root, ctx.source.as_bytes()) {
         let idx_node = m.captures.first().map(|c| c.node);
         let Some(idx_node) = idx_node else { continue };
 
-        let text = idx_node.utf8_text(source.as_bytes()).unwrap_or("");
+        let text = idx_node.utf8_text(ctx.source.as_bytes()).unwrap_or("");
         // Check if indexing with literal 0
         if !text.ends_with("[0]") { continue }
-        if has_guard(source, idx_node) { continue }
+        if has_guard(ctx.source, idx_node) { continue }
 
         out.push(Violation::with_details(
             idx_node.start_position().row + 1,
@@ -113,19 +114,19 @@ fn detect_index_zero(source: &str, root: Node, out: &mut Vec<Violation>) {
     }
 }
 
-fn detect_first_unwrap(source: &str, root: Node, out: &mut Vec<Violation>) {
+fn detect_first_unwrap(ctx: &CheckContext, out: &mut Vec<Violation>) {
     let q = r"(call_expression) @call";
-    let Ok(query) = Query::new(tree_sitter_rust::language(), q) else { return };
+    let Some(query) = ctx.query(q) else { return };
     let mut cursor = QueryCursor::new();
 
-    for m in cursor.matches(&query, root, source.as_bytes()) {
+    for m in cursor.matches(&query, ctx.root, ctx.source.as_bytes()) {
         let call = m.captures.first().map(|c| c.node);
         let Some(call) = call else { continue };
 
-        let text = call.utf8_text(source.as_bytes()).unwrap_or("");
+        let text = call.utf8_text(ctx.source.as_bytes()).unwrap_or("");
         if !text.contains(".first()") && !text.contains(".last()") { continue }
         if !text.contains(".unwrap()") { continue }
-        if has_guard(source, call) { continue }
+        if has_guard(ctx.source, call) { continue }
 
         out.push(Violation::with_details(
             call.start_position().row + 1,
@@ -159,13 +160,11 @@ fn has_guard(source: &str, node: Node) -> bool {
 #[allow(clippy::unwrap_used)]
 mod tests {
     use super::*;
-    use tree_sitter::Parser;
+    use crate::analysis::checks::with_context;
+    use crate::lang::Lang;
 
     fn parse_and_detect(code: &str) -> Vec<Violation> {
-        let mut parser = Parser::new();
-        parser.set_language(tree_sitter_rust::language()).unwrap();
-        let tree = parser.parse(code, None).unwrap();
-        detect(code, tree.root_node())
+        with_context(Lang::Rust, code, detect)
     }
 
     #[test]
@@ -12,48 +12,51 @@ pub mod resource;
 pub mod db_patterns;
 pub mod idiomatic;
 pub mod logic;
+pub mod python_loops;
+pub mod python_security;
+pub mod ts_db;
+pub mod ts_loops;
+pub mod ts_security;
 
-use crate::types::Violation;
+use crate::analysis::checks::CheckContext;
 use crate::lang::Lang;
-use std::path::Path;
-use tree_sitter::Parser;
+use crate::types::Violation;
 
-/// Runs all pattern detections on a file.
+/// Runs all pattern detections on a parsed file.
 #[must_use]
-pub fn detect_all(path: &Path, source: &str) -> Vec<Violation> {
-    let Some(lang) = get_rust_lang(path) else { return Vec::new() };
-    let Some(tree) = parse_source(source, lang) else { return Vec::new() };
+pub fn detect_all(ctx: &CheckContext) -> Vec<Violation> {
+    match ctx.lang {
+        Lang::Rust => detect_rust(ctx),
+        Lang::Python => {
+            let mut out = python_loops::detect(ctx);
+            out.extend(python_security::detect(ctx));
+            out
+        }
+        Lang::TypeScript => {
+            let mut out = ts_loops::detect(ctx);
+            out.extend(ts_security::detect(ctx));
+            out
+        }
+        Lang::Nim => Vec::new(),
+    }
+}
 
-    let root = tree.root_node();
+fn detect_rust(ctx: &CheckContext) -> Vec<Violation> {
     let mut out = Vec::new();
 
-    out.extend(state::detect(source, root));
-    out.extend(concurrency::detect(source, root));
-    out.extend(performance::detect(source, root, path));
-    out.extend(db_patterns::detect(source, root));
-    out.extend(security::detect(source, root));
-    out.extend(semantic::detect(source, root));
-    out.extend(resource::detect(source, root));
-    out.extend(idiomatic::detect(source, root));
-    out.extend(logic::detect(source, root));
+    out.extend(state::detect(ctx));
+    out.extend(concurrency::detect(ctx));
+    out.extend(performance::detect(ctx));
+    out.extend(db_patterns::detect(ctx));
+    out.extend(security::detect(ctx));
+    out.extend(semantic::detect(ctx));
+    out.extend(resource::detect(ctx));
+    out.extend(idiomatic::detect(ctx));
+    out.extend(logic::detect(ctx));
 
     out
 }
 
-fn get_rust_lang(path: &Path) -> Option<Lang> {
-    let ext = path.extension()?.to_str()?;
-    match Lang::from_ext(ext) {
-        Some(Lang::Rust) => Some(Lang::Rust),
-        _ => None,
-    }
-}
-
-fn parse_source(source: &str, lang: Lang) -> Option<tree_sitter::Tree> {
-    let mut parser = Parser::new();
-    parser.set_language(lang.grammar()).ok()?;
-    parser.parse(source, None)
-}
-
 /// Helper to get a node from a capture by index.
 /// Centralized to reduce duplication and fix lifetime issues.
 #[must_use]
@@ -68,4 +71,27 @@ pub fn get_capture_node<'a>(
         }
     }
     None
-}
\ No newline at end of file
+}
+
+/// First bound name of a loop pattern: `(a, b)`, `[a, b]`, `{ a }` and
+/// `a: T` all yield `a`.
+#[must_use]
+pub fn loop_var_name(pattern: &str) -> String {
+    let inner = pattern.trim().trim_start_matches(['(', '[', '{']);
+    let first = inner.split(',').next().unwrap_or(inner);
+    let name = first.split(':').next().unwrap_or(first);
+    name.trim().trim_end_matches([')', ']', '}']).trim().to_string()
+}
+
+/// Returns true if `text` is the loop variable or a field of it.
+#[must_use]
+pub fn uses_loop_var(text: &str, loop_var: Option<&str>) -> bool {
+    loop_var.is_some_and(|v| !v.is_empty() && (text.trim() == v || text.contains(&format!("{v}."))))
+}
+
+/// Drops repeated hits on the same row, which nested loops produce when
+/// each enclosing loop body is searched.
+pub fn dedup_rows(out: &mut Vec<Violation>) {
+    out.sort_by_key(|v| (v.row, v.law));
+    out.dedup_by(|a, b| a.row == b.row && a.law == b.law);
+}
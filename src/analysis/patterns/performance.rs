@@ -1,21 +1,20 @@
 // src/analysis/v2/patterns/performance.rs
 //! Performance anti-patterns: P01, P02, P04, P06
 
+use crate::analysis::checks::CheckContext;
 use crate::types::{Violation, ViolationDetails};
-use std::path::Path;
-use tree_sitter::{Node, Query, QueryCursor};
+use tree_sitter::{Node, QueryCursor};
 use super::get_capture_node;
 
 #[must_use]
-pub fn detect(source: &str, root: Node, path: &Path) -> Vec<Violation> {
-    if should_skip(path) { return Vec::new(); }
+pub fn detect(ctx: &CheckContext) -> Vec<Violation> {
+    if should_skip(ctx.filename) { return Vec::new(); }
     let mut out = Vec::new();
-    detect_loops(source, root, &mut out);
+    detect_loops(ctx, &mut out);
     out
 }
 
-fn should_skip(path: &Path) -> bool {
-    let s = path.to_string_lossy();
+fn should_skip(s: &str) -> bool {
     s.contains("/cli/") || s.contains("/ui/") || s.contains("/tui/")
         || s.contains("reporting") || s.contains("messages")
         || s.contains("analysis/") || s.contains("audit/")
@@ -23,36 +22,37 @@ fn should_skip(path: &Path) -> bool {
         || s.ends_with("main.rs")
 }
 
-fn detect_loops(source: &str, root: Node, out: &mut Vec<Violation>) {
+fn detect_loops(ctx: &CheckContext, out: &mut Vec<Violation>) {
     let q = r"
         (for_expression pattern: (_) @pat body: (block) @body) @loop
         (while_expression body: (block) @body) @loop
         (loop_expression body: (block) @body) @loop
     ";
-    let Ok(query) = Query::new(tree_sitter_rust::language(), q) else { return };
+    let Some(query) = ctx.query(q) else { return };
     let idx_pat = query.capture_index_for_name("pat");
     let idx_body = query.capture_index_for_name("body");
     
     let mut cursor = QueryCursor::new();
 
-    for m in cursor.matches(&query, root, source.as_bytes()) {
+    for m in cursor.matches(&query, ctx.root, ctx.source.as_bytes()) {
         let loop_var = get_capture_node(&m, idx_pat)
-            .and_then(|n| n.utf8_text(source.as_bytes()).ok())
+            .and_then(|n| n.utf8_text(ctx.source.as_bytes()).ok())
             .map(|s| s.split([',', '(']).next().unwrap_or(s).trim().to_string());
 
         let Some(body) = get_capture_node(&m, idx_body) else { continue };
 
-        check_p01(source, body, loop_var.as_deref(), out);
-        check_p02(source, body, loop_var.as_deref(), out);
+        check_p01(ctx, body, loop_var.as_deref(), out);
+        check_p02(ctx, body, loop_var.as_deref(), out);
         check_p04(body, out);
-        check_p06(source, body, out);
+        check_p06(ctx, body, out);
     }
 }
 
-fn check_p01(source: &str, body: Node, loop_var: Option<&str>, out: &mut Vec<Violation>) {
+fn check_p01(ctx: &CheckContext, body: Node, loop_var: Option<&str>, out: &mut Vec<Violation>) {
     let q = r#"(call_expression function: (field_expression
         value: (_) @recv field: (field_identifier) @m (#eq? @m "clone"))) @call"#;
-    let Ok(query) = Query::new(tree_sitter_rust::language(), q) else { return };
+    let Some(query) = ctx.query(q) else { return };
+    let source = ctx.source;
     let idx_call = query.capture_index_for_name("call");
     let idx_recv = query.capture_index_for_name("recv");
     
@@ -105,11 +105,12 @@ fn is_ownership_sink(source: &str, node: Node) -> bool {
     false
 }
 
-fn check_p02(source: &str, body: Node, loop_var: Option<&str>, out: &mut Vec<Violation>) {
+fn check_p02(ctx: &CheckContext, body: Node, loop_var: Option<&str>, out: &mut Vec<Violation>) {
     let q = r#"(call_expression function: (field_expression
         value: (_) @recv field: (field_identifier) @m)
         (#match? @m "^(to_string|to_owned)$")) @call"#;
-    let Ok(query) = Query::new(tree_sitter_rust::language(), q) else { return };
+    let Some(query) = ctx.query(q) else { return };
+    let source = ctx.source;
     let idx_call = query.capture_index_for_name("call");
     let idx_recv = query.capture_index_for_name("recv");
     
@@ -157,13 +158,13 @@ fn check_p04(body: Node, out: &mut Vec<Violation>) {
     }
 }
 
-fn check_p06(source: &str, body: Node, out: &mut Vec<Violation>) {
+fn check_p06(ctx: &CheckContext, body: Node, out: &mut Vec<Violation>) {
     let q = r#"(call_expression function: (field_expression
         field: (field_identifier) @m) (#match? @m "^(find|position)$")) @call"#;
-    let Ok(query) = Query::new(tree_sitter_rust::language(), q) else { return };
+    let Some(query) = ctx.query(q) else { return };
     let mut cursor = QueryCursor::new();
 
-    for m in cursor.matches(&query, body, source.as_bytes()) {
+    for m in cursor.matches(&query, body, ctx.source.as_bytes()) {
         if let Some(cap) = m.captures.first() {
             out.push(Violation::with_details(
                 cap.node.start_position().row + 1,
@@ -0,0 +1,178 @@
+// src/analysis/patterns/python_loops.rs
+//! Python loop patterns: P01 (deep copy), P02 (collection copy), P03 (N+1 queries)
+
+use crate::analysis::checks::CheckContext;
+use crate::types::{Violation, ViolationDetails};
+use tree_sitter::{Node, QueryCursor};
+use super::{dedup_rows, get_capture_node, loop_var_name, uses_loop_var};
+
+#[must_use]
+pub fn detect(ctx: &CheckContext) -> Vec<Violation> {
+    let q = r"
+        (for_statement left: (_) @pat body: (block) @body) @loop
+        (while_statement body: (block) @body) @loop
+    ";
+    let mut out = Vec::new();
+    let Some(query) = ctx.query(q) else { return out };
+    let idx_pat = query.capture_index_for_name("pat");
+    let idx_body = query.capture_index_for_name("body");
+    let mut cursor = QueryCursor::new();
+
+    for m in cursor.matches(&query, ctx.root, ctx.source.as_bytes()) {
+        let loop_var = get_capture_node(&m, idx_pat)
+            .and_then(|n| n.utf8_text(ctx.source.as_bytes()).ok())
+            .map(loop_var_name);
+        let Some(body) = get_capture_node(&m, idx_body) else { continue };
+
+        check_copies(ctx, body, loop_var.as_deref(), &mut out);
+        check_queries(ctx, body, loop_var.as_deref(), &mut out);
+    }
+    dedup_rows(&mut out);
+    out
+}
+
+/// P01/P02: copying loop-invariant data on every iteration.
+fn check_copies(ctx: &CheckContext, body: Node, loop_var: Option<&str>, out: &mut Vec<Violation>) {
+    let q = r#"(call
+        function: [(identifier) @f (attribute attribute: (identifier) @f)]
+        arguments: (argument_list . [(identifier) (attribute)] @arg)
+        (#match? @f "^(deepcopy|copy|list|dict|set)$")) @call"#;
+    let Some(query) = ctx.query(q) else { return };
+    let idx_call = query.capture_index_for_name("call");
+    let idx_f = query.capture_index_for_name("f");
+    let idx_arg = query.capture_index_for_name("arg");
+    let mut cursor = QueryCursor::new();
+
+    for m in cursor.matches(&query, body, ctx.source.as_bytes()) {
+        let text = |idx| get_capture_node(&m, idx).and_then(|n| n.utf8_text(ctx.source.as_bytes()).ok());
+        let (Some(call), Some(f), Some(arg)) = (get_capture_node(&m, idx_call), text(idx_f), text(idx_arg)) else { continue };
+        if uses_loop_var(arg, loop_var) || is_collection_sink(ctx.source, call) { continue }
+
+        out.push(copy_violation(call, f));
+    }
+}
+
+fn copy_violation(call: Node, func: &str) -> Violation {
+    let row = call.start_position().row + 1;
+    if matches!(func, "deepcopy" | "copy") {
+        return Violation::with_details(
+            row,
+            format!("Detected `{func}()` inside a loop"),
+            "P01",
+            ViolationDetails {
+                function_name: None,
+                analysis: vec!["Copy of loop-invariant data appears hoistable.".into()],
+                suggestion: Some("Hoist the copy or share the value read-only.".into()),
+            },
+        );
+    }
+    Violation::with_details(
+        row,
+        format!("Collection copy `{func}()` inside loop"),
+        "P02",
+        ViolationDetails {
+            function_name: None,
+            analysis: vec!["Allocation appears hoistable.".into()],
+            suggestion: Some("Hoist allocation.".into()),
+        },
+    )
+}
+
+/// A copy handed straight to a collection is usually an intended snapshot.
+fn is_collection_sink(source: &str, node: Node) -> bool {
+    std::iter::successors(node.parent(), Node::parent)
+        .take_while(|p| p.kind() != "block")
+        .any(|p| {
+            let txt = p.utf8_text(source.as_bytes()).unwrap_or("");
+            txt.contains(".append(") || txt.contains(".add(") || txt.contains(".extend(")
+        })
+}
+
+/// P03: N+1 query - DB or ORM call inside loop using the loop variable.
+fn check_queries(ctx: &CheckContext, body: Node, loop_var: Option<&str>, out: &mut Vec<Violation>) {
+    let Some(loop_var) = loop_var else { return };
+    let q = r#"(call function: (attribute attribute: (identifier) @m)
+        (#match? @m "^(execute|executemany|fetch|fetchrow|fetchval|fetchone|fetchall|get|filter|first|one|scalar|query)$")) @call"#;
+    let Some(query) = ctx.query(q) else { return };
+    let idx_call = query.capture_index_for_name("call");
+    let idx_m = query.capture_index_for_name("m");
+    let mut cursor = QueryCursor::new();
+
+    for m in cursor.matches(&query, body, ctx.source.as_bytes()) {
+        let Some(call) = get_capture_node(&m, idx_call) else { continue };
+        let method = get_capture_node(&m, idx_m)
+            .and_then(|n| n.utf8_text(ctx.source.as_bytes()).ok())
+            .unwrap_or("query");
+        let call_text = call.utf8_text(ctx.source.as_bytes()).unwrap_or("");
+
+        if !call_text.contains(loop_var) { continue }
+        if !is_raw_query(method) && !is_orm_receiver(call_text) { continue }
+
+        out.push(Violation::with_details(
+            call.start_position().row + 1,
+            format!("Potential N+1 query: `{method}` in loop"),
+            "P03",
+            ViolationDetails {
+                function_name: None,
+                analysis: vec![
+                    "DB call inside loop causes N+1 queries.".into(),
+                    format!("Loop variable `{loop_var}` used in call."),
+                ],
+                suggestion: Some("Batch the query (`__in`, `IN (...)`) or prefetch related rows.".into()),
+            },
+        ));
+    }
+}
+
+fn is_raw_query(method: &str) -> bool {
+    method.starts_with("execute") || method.starts_with("fetch")
+}
+
+/// `get`/`filter`/`first` are only queries on ORM-looking receivers.
+fn is_orm_receiver(call_text: &str) -> bool {
+    [".objects.", "session.", "query(", "cursor.", "conn.", "db."]
+        .iter()
+        .any(|r| call_text.contains(r))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::analysis::checks::with_context;
+    use crate::lang::Lang;
+
+    fn laws(code: &str) -> Vec<&'static str> {
+        with_context(Lang::Python, code, detect).iter().map(|v| v.law).collect()
+    }
+
+    #[test]
+    fn p01_flag_deepcopy_of_invariant() {
+        let code = "for item in items:\n    cfg = copy.deepcopy(defaults)\n    use(cfg, item)\n";
+        assert_eq!(laws(code), vec!["P01"]);
+    }
+
+    #[test]
+    fn p01_skip_copy_of_loop_var_and_sinks() {
+        let code = "for item in items:\n    a = copy.deepcopy(item)\n    out.append(copy.deepcopy(tpl))\n";
+        assert!(laws(code).is_empty());
+    }
+
+    #[test]
+    fn p02_flag_collection_copy() {
+        let code = "while running:\n    snapshot = list(queue)\n";
+        assert_eq!(laws(code), vec!["P02"]);
+    }
+
+    #[test]
+    fn p03_flag_orm_and_raw_queries() {
+        let code = "for user in users:\n    Order.objects.filter(user=user).first()\n    cur.execute(sql, (user.id,))\n";
+        assert_eq!(laws(code), vec!["P03", "P03"]);
+    }
+
+    #[test]
+    fn p03_skip_dict_get() {
+        let code = "for key in keys:\n    value = mapping.get(key)\n";
+        assert!(laws(code).is_empty());
+    }
+}
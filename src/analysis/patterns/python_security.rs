@@ -0,0 +1,167 @@
+// src/analysis/patterns/python_security.rs
+//! Python security patterns: X01, X02, X03
+
+use crate::analysis::checks::CheckContext;
+use crate::types::{Violation, ViolationDetails};
+use tree_sitter::{Node, QueryCursor};
+use super::get_capture_node;
+use super::security::{has_sql, is_placeholder_secret};
+
+#[must_use]
+pub fn detect(ctx: &CheckContext) -> Vec<Violation> {
+    let mut out = Vec::new();
+    detect_x01_sql(ctx, &mut out);
+    detect_x02_command(ctx, &mut out);
+    detect_x03_secrets(ctx, &mut out);
+    out
+}
+
+/// X01: SQL built with f-strings, `%` or `.format()`.
+fn detect_x01_sql(ctx: &CheckContext, out: &mut Vec<Violation>) {
+    let Some(query) = ctx.query("(string) @sql") else { return };
+    let mut cursor = QueryCursor::new();
+
+    for m in cursor.matches(&query, ctx.root, ctx.source.as_bytes()) {
+        let Some(node) = m.captures.first().map(|c| c.node) else { continue };
+        let text = node.utf8_text(ctx.source.as_bytes()).unwrap_or("");
+        if !has_sql(text) || !is_interpolated(ctx.source, node) { continue }
+
+        out.push(Violation::with_details(
+            node.start_position().row + 1,
+            "Potential SQL Injection".into(),
+            "X01",
+            ViolationDetails {
+                function_name: None,
+                analysis: vec!["Formatting into SQL bypasses parameterization.".into()],
+                suggestion: Some("Use parameterized queries (`cursor.execute(sql, params)`).".into()),
+            },
+        ));
+    }
+}
+
+/// True for f-strings and strings fed to `%`, `+` or `.format()`.
+fn is_interpolated(source: &str, node: Node) -> bool {
+    let text = node.utf8_text(source.as_bytes()).unwrap_or("");
+    let prefix = text.split(['"', '\'']).next().unwrap_or("");
+    if prefix.contains(['f', 'F']) && text.contains('{') {
+        return true;
+    }
+    match node.parent() {
+        Some(p) if p.kind() == "binary_operator" => true,
+        Some(p) if p.kind() == "attribute" => {
+            p.utf8_text(source.as_bytes()).unwrap_or("").ends_with(".format")
+        }
+        _ => false,
+    }
+}
+
+/// X02: shell commands built from variables.
+fn detect_x02_command(ctx: &CheckContext, out: &mut Vec<Violation>) {
+    let q = r#"(call
+        function: (attribute object: (identifier) @module attribute: (identifier) @fn)
+        arguments: (argument_list . (_) @cmd) @args
+        (#match? @module "^(os|subprocess)$")) @call"#;
+    let Some(query) = ctx.query(q) else { return };
+    let idx_call = query.capture_index_for_name("call");
+    let idx_fn = query.capture_index_for_name("fn");
+    let idx_cmd = query.capture_index_for_name("cmd");
+    let idx_args = query.capture_index_for_name("args");
+    let mut cursor = QueryCursor::new();
+
+    for m in cursor.matches(&query, ctx.root, ctx.source.as_bytes()) {
+        let (Some(call), Some(func), Some(cmd), Some(args)) = (
+            get_capture_node(&m, idx_call),
+            get_capture_node(&m, idx_fn),
+            get_capture_node(&m, idx_cmd),
+            get_capture_node(&m, idx_args),
+        ) else { continue };
+
+        let func = func.utf8_text(ctx.source.as_bytes()).unwrap_or("");
+        let args = args.utf8_text(ctx.source.as_bytes()).unwrap_or("");
+        let runs_shell = matches!(func, "system" | "popen") || args.contains("shell=True");
+        if !runs_shell || is_plain_literal(ctx.source, cmd) { continue }
+
+        out.push(Violation::with_details(
+            call.start_position().row + 1,
+            "Potential Command Injection".into(),
+            "X02",
+            ViolationDetails {
+                function_name: None,
+                analysis: vec!["Shell command string built from a variable.".into()],
+                suggestion: Some("Pass an argument list to `subprocess.run` without `shell=True`.".into()),
+            },
+        ));
+    }
+}
+
+fn is_plain_literal(source: &str, node: Node) -> bool {
+    node.kind() == "string" && !is_interpolated(source, node)
+}
+
+/// X03: secrets assigned as string literals.
+fn detect_x03_secrets(ctx: &CheckContext, out: &mut Vec<Violation>) {
+    let q = r#"(assignment left: (identifier) @name right: (string) @value
+        (#match? @name "(?i)(key|secret|token|password|auth)"))"#;
+    let Some(query) = ctx.query(q) else { return };
+    let idx_value = query.capture_index_for_name("value");
+    let mut cursor = QueryCursor::new();
+
+    for m in cursor.matches(&query, ctx.root, ctx.source.as_bytes()) {
+        let Some(val) = get_capture_node(&m, idx_value) else { continue };
+        let text = val.utf8_text(ctx.source.as_bytes()).unwrap_or("");
+        if is_placeholder_secret(text) || is_interpolated(ctx.source, val) { continue }
+
+        out.push(Violation::with_details(
+            val.start_position().row + 1,
+            "Potential hardcoded secret".into(),
+            "X03",
+            ViolationDetails {
+                function_name: None,
+                analysis: vec!["Secrets should come from environment.".into()],
+                suggestion: Some("Use `os.environ`.".into()),
+            },
+        ));
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::analysis::checks::with_context;
+    use crate::lang::Lang;
+
+    fn laws(code: &str) -> Vec<&'static str> {
+        with_context(Lang::Python, code, detect).iter().map(|v| v.law).collect()
+    }
+
+    #[test]
+    fn x01_flag_formatted_sql() {
+        assert_eq!(laws("q = f\"SELECT * FROM t WHERE id = {uid}\"\n"), vec!["X01"]);
+        assert_eq!(laws("q = \"DELETE FROM t WHERE id = %s\" % uid\n"), vec!["X01"]);
+        assert_eq!(laws("q = \"UPDATE t SET a = {}\".format(a)\n"), vec!["X01"]);
+    }
+
+    #[test]
+    fn x01_skip_parameterized_sql() {
+        assert!(laws("cur.execute(\"SELECT * FROM t WHERE id = %s\", (uid,))\n").is_empty());
+    }
+
+    #[test]
+    fn x02_flag_shell_strings() {
+        assert_eq!(laws("os.system(f\"rm -rf {path}\")\n"), vec!["X02"]);
+        assert_eq!(laws("subprocess.run(cmd, shell=True)\n"), vec!["X02"]);
+    }
+
+    #[test]
+    fn x02_skip_argument_lists_and_literals() {
+        assert!(laws("subprocess.run([\"ls\", path])\n").is_empty());
+        assert!(laws("subprocess.run(\"ls -la\", shell=True)\n").is_empty());
+    }
+
+    #[test]
+    fn x03_flag_literal_secret() {
+        assert_eq!(laws("API_KEY = \"sk-live-4f9a8b7c\"\n"), vec!["X03"]);
+        assert!(laws("API_KEY = os.environ[\"API_KEY\"]\n").is_empty());
+    }
+}
@@ -1,32 +1,33 @@
 // src/analysis/v2/patterns/resource.rs
 //! Resource patterns: R07 (missing flush)
 
+use crate::analysis::checks::CheckContext;
 use crate::types::{Violation, ViolationDetails};
-use tree_sitter::{Node, Query, QueryCursor};
+use tree_sitter::{Node, QueryCursor};
 use super::get_capture_node;
 
 #[must_use]
-pub fn detect(source: &str, root: Node) -> Vec<Violation> {
+pub fn detect(ctx: &CheckContext) -> Vec<Violation> {
     let mut out = Vec::new();
-    detect_r07(source, root, &mut out);
+    detect_r07(ctx, &mut out);
     out
 }
 
 /// R07: `BufWriter` created without `flush()` call
-fn detect_r07(source: &str, root: Node, out: &mut Vec<Violation>) {
+fn detect_r07(ctx: &CheckContext, out: &mut Vec<Violation>) {
     let q = r#"(call_expression
         function: (scoped_identifier path: (identifier) @type name: (identifier) @method)
         (#eq? @type "BufWriter") (#eq? @method "new")) @call"#;
 
-    let Ok(query) = Query::new(tree_sitter_rust::language(), q) else { return };
+    let Some(query) = ctx.query(q) else { return };
     let idx_call = query.capture_index_for_name("call");
     let mut cursor = QueryCursor::new();
 
-    for m in cursor.matches(&query, root, source.as_bytes()) {
+    for m in cursor.matches(&query, ctx.root, ctx.source.as_bytes()) {
         let Some(call) = get_capture_node(&m, idx_call) else { continue };
         let Some(fn_node) = find_containing_fn(call) else { continue };
 
-        let fn_text = fn_node.utf8_text(source.as_bytes()).unwrap_or("");
+        let fn_text = fn_node.utf8_text(ctx.source.as_bytes()).unwrap_or("");
         if fn_text.contains(".flush()") { continue }
         if fn_text.contains("-> BufWriter") || fn_text.contains("-> impl Write") { continue }
         if fn_text.contains("Ok(BufWriter") { continue }
@@ -56,13 +57,11 @@ fn find_containing_fn(node: Node) -> Option<Node> {
 #[allow(clippy::unwrap_used)]
 mod tests {
     use super::*;
-    use tree_sitter::Parser;
+    use crate::analysis::checks::with_context;
+    use crate::lang::Lang;
 
     fn parse_and_detect(code: &str) -> Vec<Violation> {
-        let mut parser = Parser::new();
-        parser.set_language(tree_sitter_rust::language()).unwrap();
-        let tree = parser.parse(code, None).unwrap();
-        detect(code, tree.root_node())
+        with_context(Lang::Rust, code, detect)
     }
 
     #[test]
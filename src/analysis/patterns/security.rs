@@ -1,28 +1,29 @@
 // src/analysis/v2/patterns/security.rs
 //! Security patterns: X01, X02, X03
 
+use crate::analysis::checks::CheckContext;
 use crate::types::{Violation, ViolationDetails};
-use tree_sitter::{Node, Query, QueryCursor};
+use tree_sitter::{Node, QueryCursor};
 use super::get_capture_node;
 
 #[must_use]
-pub fn detect(source: &str, root: Node) -> Vec<Violation> {
+pub fn detect(ctx: &CheckContext) -> Vec<Violation> {
     let mut out = Vec::new();
-    detect_x01_sql(source, root, &mut out);
-    detect_x02_command(source, root, &mut out);
-    detect_x03_secrets(source, root, &mut out);
+    detect_x01_sql(ctx, &mut out);
+    detect_x02_command(ctx, &mut out);
+    detect_x03_secrets(ctx, &mut out);
     out
 }
 
-fn detect_x01_sql(source: &str, root: Node, out: &mut Vec<Violation>) {
+fn detect_x01_sql(ctx: &CheckContext, out: &mut Vec<Violation>) {
     let q = r#"(macro_invocation macro: (identifier) @mac (token_tree) @args (#eq? @mac "format")) @fmt"#;
-    let Ok(query) = Query::new(tree_sitter_rust::language(), q) else { return };
+    let Some(query) = ctx.query(q) else { return };
     let idx_args = query.capture_index_for_name("args");
     let mut cursor = QueryCursor::new();
 
-    for m in cursor.matches(&query, root, source.as_bytes()) {
+    for m in cursor.matches(&query, ctx.root, ctx.source.as_bytes()) {
         if let Some(arg_node) = get_capture_node(&m, idx_args) {
-            let args = arg_node.utf8_text(source.as_bytes()).unwrap_or("");
+            let args = arg_node.utf8_text(ctx.source.as_bytes()).unwrap_or("");
             if is_suspicious_sql(args) {
                 out.push(Violation::with_details(
                     arg_node.start_position().row + 1,
@@ -40,32 +41,42 @@ fn detect_x01_sql(source: &str, root: Node, out: &mut Vec<Violation>) {
 }
 
 fn is_suspicious_sql(text: &str) -> bool {
-    let upper = text.to_uppercase();
-    let has_sql = upper.contains("SELECT ") || upper.contains("INSERT INTO ")
-        || upper.contains("UPDATE ") || upper.contains("DELETE FROM ");
     let has_interp = text.contains("{}") || text.contains("{:");
-    has_sql && has_interp
+    has_sql(text) && has_interp
 }
 
-fn detect_x02_command(source: &str, root: Node, out: &mut Vec<Violation>) {
+/// Returns true if `text` looks like a SQL statement.
+pub(super) fn has_sql(text: &str) -> bool {
+    let upper = text.to_uppercase();
+    upper.contains("SELECT ") || upper.contains("INSERT INTO ")
+        || upper.contains("UPDATE ") || upper.contains("DELETE FROM ")
+}
+
+/// Returns true if a secret-looking literal is obviously a placeholder.
+pub(super) fn is_placeholder_secret(text: &str) -> bool {
+    text.contains("placeholder") || text.contains("example")
+        || text.contains("test") || text.contains("dummy") || text.len() < 5
+}
+
+fn detect_x02_command(ctx: &CheckContext, out: &mut Vec<Violation>) {
     let q = r#"(call_expression
         function: (scoped_identifier path: (identifier) @struct name: (identifier) @method)
         arguments: (arguments (identifier) @arg)
         (#eq? @struct "Command") (#eq? @method "new")) @call"#;
 
-    let Ok(query) = Query::new(tree_sitter_rust::language(), q) else { return };
+    let Some(query) = ctx.query(q) else { return };
     let idx_call = query.capture_index_for_name("call");
     let idx_arg = query.capture_index_for_name("arg");
     let mut cursor = QueryCursor::new();
 
-    for m in cursor.matches(&query, root, source.as_bytes()) {
+    for m in cursor.matches(&query, ctx.root, ctx.source.as_bytes()) {
         let call = get_capture_node(&m, idx_call);
         let arg = get_capture_node(&m, idx_arg);
 
         let (Some(call), Some(arg)) = (call, arg) else { continue };
-        let var_name = arg.utf8_text(source.as_bytes()).unwrap_or("");
+        let var_name = arg.utf8_text(ctx.source.as_bytes()).unwrap_or("");
 
-        if is_safe_cmd_source(source, call, var_name) { continue }
+        if is_safe_cmd_source(ctx.source, call, var_name) { continue }
 
         out.push(Violation::with_details(
             call.start_position().row + 1,
@@ -110,37 +121,28 @@ fn is_defined_const(source: &str, var_name: &str) -> bool {
 }
 
 fn is_config_context(source: &str, node: Node) -> bool {
-    let mut cur = node;
-    for _ in 0..20 {
-        if let Some(p) = cur.parent() {
-            if p.kind() == "function_item" {
-                if let Some(n) = p.child_by_field_name("name") {
-                    let fn_name = n.utf8_text(source.as_bytes()).unwrap_or("");
-                    let cfg = ["parse", "load", "read", "from_", "deserialize", "config", "manifest"];
-                    if cfg.iter().any(|s| fn_name.contains(s)) { return true }
-                }
-            }
-            cur = p;
-        } else { break }
-    }
-    false
+    let cfg = ["parse", "load", "read", "from_", "deserialize", "config", "manifest"];
+    ancestors(node)
+        .take(20)
+        .filter(|p| p.kind() == "function_item")
+        .filter_map(|p| p.child_by_field_name("name"))
+        .any(|n| {
+            let fn_name = n.utf8_text(source.as_bytes()).unwrap_or("");
+            cfg.iter().any(|s| fn_name.contains(s))
+        })
 }
 
 fn is_in_match_allowlist(source: &str, node: Node) -> bool {
-    let mut cur = node;
-    for _ in 0..10 {
-        if let Some(p) = cur.parent() {
-            if p.kind() == "match_expression" {
-                let text = p.utf8_text(source.as_bytes()).unwrap_or("");
-                if text.contains('"') { return true }
-            }
-            cur = p;
-        } else { break }
-    }
-    false
+    ancestors(node).take(10).any(|p| {
+        p.kind() == "match_expression" && p.utf8_text(source.as_bytes()).unwrap_or("").contains('"')
+    })
+}
+
+fn ancestors(node: Node) -> impl Iterator<Item = Node> {
+    std::iter::successors(node.parent(), Node::parent)
 }
 
-fn detect_x03_secrets(source: &str, root: Node, out: &mut Vec<Violation>) {
+fn detect_x03_secrets(ctx: &CheckContext, out: &mut Vec<Violation>) {
     let q = r#"
         (let_declaration pattern: (identifier) @name value: (string_literal) @value
             (#match? @name "(?i)(key|secret|token|password|auth)")) @decl
@@ -148,17 +150,14 @@ fn detect_x03_secrets(source: &str, root: Node, out: &mut Vec<Violation>) {
             (#match? @name "(?i)(key|secret|token|password|auth)")) @const
     "#;
 
-    let Ok(query) = Query::new(tree_sitter_rust::language(), q) else { return };
+    let Some(query) = ctx.query(q) else { return };
     let idx_value = query.capture_index_for_name("value");
     let mut cursor = QueryCursor::new();
 
-    for m in cursor.matches(&query, root, source.as_bytes()) {
+    for m in cursor.matches(&query, ctx.root, ctx.source.as_bytes()) {
         if let Some(val) = get_capture_node(&m, idx_value) {
-            let text = val.utf8_text(source.as_bytes()).unwrap_or("");
-            if text.contains("placeholder") || text.contains("example")
-               || text.contains("test") || text.contains("dummy") || text.len() < 5 {
-                continue;
-            }
+            let text = val.utf8_text(ctx.source.as_bytes()).unwrap_or("");
+            if is_placeholder_secret(text) { continue }
             out.push(Violation::with_details(
                 val.start_position().row + 1,
                 "Potential hardcoded secret".into(),
@@ -171,4 +170,28 @@ fn detect_x03_secrets(source: &str, root: Node, out: &mut Vec<Violation>) {
             ));
         }
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::checks::with_context;
+    use crate::lang::Lang;
+
+    #[test]
+    fn x02_explains_unverified_command_source() {
+        let code = "fn f(user_input: &str) { Command::new(user_input).spawn(); }";
+        let out = with_context(Lang::Rust, code, detect);
+        let [violation] = out.as_slice() else {
+            panic!("expected one violation, got {out:?}");
+        };
+        assert_eq!(violation.law, "X02");
+        assert_eq!(
+            violation.details.as_ref().map(|d| d.analysis.clone()),
+            Some(vec![
+                "Variable passed to `Command::new` without clear provenance.".to_string(),
+                "Variable `user_input` source not verifiable.".to_string(),
+            ])
+        );
+    }
 }
\ No newline at end of file
@@ -1,33 +1,34 @@
 // src/analysis/v2/patterns/state.rs
 //! State pattern detection: S01, S02, S03
 
+use crate::analysis::checks::CheckContext;
 use crate::types::{Violation, ViolationDetails};
-use tree_sitter::{Node, Query, QueryCursor};
+use tree_sitter::{Node, QueryCursor};
 use super::get_capture_node;
 
 /// Detects state-related violations in Rust code.
 #[must_use]
-pub fn detect(source: &str, root: Node) -> Vec<Violation> {
+pub fn detect(ctx: &CheckContext) -> Vec<Violation> {
     let mut violations = Vec::new();
-    detect_s01(source, root, &mut violations);
-    detect_s02(source, root, &mut violations);
-    detect_s03(source, root, &mut violations);
+    detect_s01(ctx, &mut violations);
+    detect_s02(ctx, &mut violations);
+    detect_s03(ctx, &mut violations);
     violations
 }
 
 /// S01: Global mutable declaration - `static mut`
-fn detect_s01(source: &str, root: Node, out: &mut Vec<Violation>) {
+fn detect_s01(ctx: &CheckContext, out: &mut Vec<Violation>) {
     let query_str = r"(static_item (mutable_specifier) @mut) @item";
-    let Ok(query) = Query::new(tree_sitter_rust::language(), query_str) else {
+    let Some(query) = ctx.query(query_str) else {
         return;
     };
     let idx_mut = query.capture_index_for_name("mut");
     
     let mut cursor = QueryCursor::new();
-    for m in cursor.matches(&query, root, source.as_bytes()) {
+    for m in cursor.matches(&query, ctx.root, ctx.source.as_bytes()) {
         if let Some(cap) = get_capture_node(&m, idx_mut) {
             let row = cap.start_position().row;
-            let text = extract_first_line(source, cap);
+            let text = extract_first_line(ctx.source, cap);
             out.push(build_s01_violation(row, &text));
         }
     }
@@ -50,9 +51,9 @@ fn build_s01_violation(row: usize, text: &str) -> Violation {
 }
 
 /// S02: Exported mutable - `pub static` (non-const)
-fn detect_s02(source: &str, root: Node, out: &mut Vec<Violation>) {
+fn detect_s02(ctx: &CheckContext, out: &mut Vec<Violation>) {
     let query_str = r"(static_item (visibility_modifier) @vis name: (identifier) @name) @item";
-    let Ok(query) = Query::new(tree_sitter_rust::language(), query_str) else {
+    let Some(query) = ctx.query(query_str) else {
         return;
     };
     let idx_vis = query.capture_index_for_name("vis");
@@ -60,13 +61,13 @@ fn detect_s02(source: &str, root: Node, out: &mut Vec<Violation>) {
     let idx_item = query.capture_index_for_name("item");
     
     let mut cursor = QueryCursor::new();
-    for m in cursor.matches(&query, root, source.as_bytes()) {
+    for m in cursor.matches(&query, ctx.root, ctx.source.as_bytes()) {
         let vis = get_capture_node(&m, idx_vis);
         let name = get_capture_node(&m, idx_name);
         let item = get_capture_node(&m, idx_item);
         
         if let (Some(vis), Some(name), Some(item)) = (vis, name, item) {
-             if process_s02_check(source, vis, name, item, out) {
+             if process_s02_check(ctx.source, vis, name, item, out) {
                  // violation added
              }
         }
@@ -111,17 +112,17 @@ fn build_s02_violation(row: usize, name: &str) -> Violation {
 }
 
 /// S03: Suspicious global container - `lazy_static` with Mutex
-fn detect_s03(source: &str, root: Node, out: &mut Vec<Violation>) {
+fn detect_s03(ctx: &CheckContext, out: &mut Vec<Violation>) {
     let query_str = r#"(macro_invocation macro: (identifier) @mac (#match? @mac "^lazy_static$")) @item"#;
-    let Ok(query) = Query::new(tree_sitter_rust::language(), query_str) else {
+    let Some(query) = ctx.query(query_str) else {
         return;
     };
     let idx_item = query.capture_index_for_name("item");
     
     let mut cursor = QueryCursor::new();
-    for m in cursor.matches(&query, root, source.as_bytes()) {
+    for m in cursor.matches(&query, ctx.root, ctx.source.as_bytes()) {
         if let Some(item) = get_capture_node(&m, idx_item) {
-            if let Some(v) = check_s03_container(source, item) {
+            if let Some(v) = check_s03_container(ctx.source, item) {
                 out.push(v);
             }
         }
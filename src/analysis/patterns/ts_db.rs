@@ -0,0 +1,42 @@
+// src/analysis/patterns/ts_db.rs
+//! TypeScript/JavaScript database anti-patterns: P03 (N+1 queries)
+
+use crate::analysis::checks::CheckContext;
+use crate::types::{Violation, ViolationDetails};
+use tree_sitter::{Node, QueryCursor};
+use super::get_capture_node;
+
+/// P03: N+1 query - DB or ORM call inside loop using the loop variable.
+pub(super) fn check_queries(ctx: &CheckContext, body: Node, loop_var: Option<&str>, out: &mut Vec<Violation>) {
+    let Some(loop_var) = loop_var.filter(|v| !v.is_empty()) else { return };
+    let q = r#"(call_expression function: (member_expression property: (property_identifier) @m)
+        (#match? @m "^(query|execute|raw|findOne|findOneBy|findUnique|findFirst|findMany|findById|findByPk|findAll)$")) @call"#;
+    let Some(query) = ctx.query(q) else { return };
+    let idx_call = query.capture_index_for_name("call");
+    let idx_m = query.capture_index_for_name("m");
+    let mut cursor = QueryCursor::new();
+
+    for m in cursor.matches(&query, body, ctx.source.as_bytes()) {
+        let Some(call) = get_capture_node(&m, idx_call) else { continue };
+        let call_text = call.utf8_text(ctx.source.as_bytes()).unwrap_or("");
+        if !call_text.contains(loop_var) { continue }
+        let method = get_capture_node(&m, idx_m)
+            .and_then(|n| n.utf8_text(ctx.source.as_bytes()).ok())
+            .unwrap_or("query");
+
+        out.push(Violation::with_details(
+            call.start_position().row + 1,
+            format!("Potential N+1 query: `{method}` in loop"),
+            "P03",
+            ViolationDetails {
+                function_name: None,
+                analysis: vec![
+                    "DB call inside loop causes N+1 queries.".into(),
+                    format!("Loop variable `{loop_var}` used in call."),
+                ],
+                suggestion: Some("Batch the query (`IN (...)`, `findMany({ where: { id: { in } } })`).".into()),
+            },
+        ));
+    }
+}
+
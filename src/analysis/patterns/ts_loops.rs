@@ -0,0 +1,165 @@
+// src/analysis/patterns/ts_loops.rs
+//! TypeScript/JavaScript loop patterns: P01 (deep clone), P02 (spread copy)
+
+use crate::analysis::checks::CheckContext;
+use crate::types::{Violation, ViolationDetails};
+use tree_sitter::{Node, QueryCursor};
+use super::{dedup_rows, get_capture_node, loop_var_name, ts_db, uses_loop_var};
+
+#[must_use]
+pub fn detect(ctx: &CheckContext) -> Vec<Violation> {
+    let q = r#"
+        (for_in_statement left: (_) @pat body: (_) @body) @loop
+        (for_statement body: (_) @body) @loop
+        (while_statement body: (_) @body) @loop
+        (do_statement body: (_) @body) @loop
+        (call_expression
+            function: (member_expression property: (property_identifier) @m)
+            arguments: (arguments (arrow_function . (_) @pat body: (_) @body))
+            (#match? @m "^(forEach|map|flatMap)$")) @loop
+    "#;
+    let mut out = Vec::new();
+    let Some(query) = ctx.query(q) else { return out };
+    let idx_pat = query.capture_index_for_name("pat");
+    let idx_body = query.capture_index_for_name("body");
+    let mut cursor = QueryCursor::new();
+
+    for m in cursor.matches(&query, ctx.root, ctx.source.as_bytes()) {
+        let loop_var = get_capture_node(&m, idx_pat)
+            .and_then(|n| n.utf8_text(ctx.source.as_bytes()).ok())
+            .map(loop_var_name);
+        let Some(body) = get_capture_node(&m, idx_body) else { continue };
+
+        check_clones(ctx, body, loop_var.as_deref(), &mut out);
+        check_spreads(ctx, body, loop_var.as_deref(), &mut out);
+        ts_db::check_queries(ctx, body, loop_var.as_deref(), &mut out);
+    }
+    dedup_rows(&mut out);
+    out
+}
+
+/// P01: deep clones of loop-invariant data.
+fn check_clones(ctx: &CheckContext, body: Node, loop_var: Option<&str>, out: &mut Vec<Violation>) {
+    let q = r#"(call_expression
+        function: [(identifier) @f (member_expression property: (property_identifier) @f)]
+        arguments: (arguments . (_) @arg)
+        (#match? @f "^(structuredClone|cloneDeep|parse)$")) @call"#;
+    let Some(query) = ctx.query(q) else { return };
+    let idx_call = query.capture_index_for_name("call");
+    let idx_f = query.capture_index_for_name("f");
+    let idx_arg = query.capture_index_for_name("arg");
+    let mut cursor = QueryCursor::new();
+
+    for m in cursor.matches(&query, body, ctx.source.as_bytes()) {
+        let text = |idx| get_capture_node(&m, idx).and_then(|n| n.utf8_text(ctx.source.as_bytes()).ok());
+        let (Some(call), Some(f), Some(arg)) = (get_capture_node(&m, idx_call), text(idx_f), text(idx_arg)) else { continue };
+
+        // `JSON.parse(JSON.stringify(x))` is the classic deep clone.
+        let cloned = arg.strip_prefix("JSON.stringify(").unwrap_or(arg).trim_end_matches(')');
+        if f == "parse" && !arg.starts_with("JSON.stringify(") { continue }
+        if uses_loop_var(cloned, loop_var) || is_push_sink(ctx.source, call) { continue }
+
+        out.push(Violation::with_details(
+            call.start_position().row + 1,
+            "Detected deep clone inside a loop".into(),
+            "P01",
+            ViolationDetails {
+                function_name: None,
+                analysis: vec!["Clone of loop-invariant data appears hoistable.".into()],
+                suggestion: Some("Hoist the clone or share the value read-only.".into()),
+            },
+        ));
+    }
+}
+
+/// P02: `[...acc, x]` / `{ ...acc }` rebuilds the whole collection each iteration.
+fn check_spreads(ctx: &CheckContext, body: Node, loop_var: Option<&str>, out: &mut Vec<Violation>) {
+    let q = r"[(array (spread_element (_) @src)) (object (spread_element (_) @src))] @copy";
+    let Some(query) = ctx.query(q) else { return };
+    let idx_copy = query.capture_index_for_name("copy");
+    let idx_src = query.capture_index_for_name("src");
+    let mut cursor = QueryCursor::new();
+
+    for m in cursor.matches(&query, body, ctx.source.as_bytes()) {
+        let (Some(copy), Some(src)) = (get_capture_node(&m, idx_copy), get_capture_node(&m, idx_src)) else { continue };
+        if !matches!(src.kind(), "identifier" | "member_expression") { continue }
+        let src_text = src.utf8_text(ctx.source.as_bytes()).unwrap_or("");
+        if uses_loop_var(src_text, loop_var) || is_push_sink(ctx.source, copy) { continue }
+
+        out.push(Violation::with_details(
+            copy.start_position().row + 1,
+            format!("Spread copy of `{src_text}` inside loop"),
+            "P02",
+            ViolationDetails {
+                function_name: None,
+                analysis: vec![
+                    "Allocation appears hoistable.".into(),
+                    "Rebuilding an accumulator by spreading is quadratic.".into(),
+                ],
+                suggestion: Some("Mutate in place (`push`, property assignment) or hoist.".into()),
+            },
+        ));
+    }
+}
+
+/// A value handed straight to `push`/`set`/`add` is usually an intended snapshot.
+fn is_push_sink(source: &str, node: Node) -> bool {
+    std::iter::successors(node.parent(), Node::parent)
+        .take_while(|p| p.kind() != "statement_block")
+        .any(|p| {
+            let txt = p.utf8_text(source.as_bytes()).unwrap_or("");
+            txt.contains(".push(") || txt.contains(".set(") || txt.contains(".add(")
+        })
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::analysis::checks::with_context;
+    use crate::lang::Lang;
+
+    fn laws(code: &str) -> Vec<&'static str> {
+        with_context(Lang::TypeScript, code, detect).iter().map(|v| v.law).collect()
+    }
+
+    #[test]
+    fn p01_flag_deep_clone_of_invariant() {
+        let code = "for (const row of rows) { const cfg = structuredClone(defaults); use(cfg, row); }";
+        assert_eq!(laws(code), vec!["P01"]);
+        let code = "for (const row of rows) { const c = JSON.parse(JSON.stringify(base)); }";
+        assert_eq!(laws(code), vec!["P01"]);
+    }
+
+    #[test]
+    fn p01_skip_clone_of_loop_var() {
+        let code = "for (const row of rows) { const copy = structuredClone(row); }";
+        assert!(laws(code).is_empty());
+    }
+
+    #[test]
+    fn p02_flag_accumulator_spread() {
+        let code = "let acc = []; for (const x of xs) { acc = [...acc, x]; }";
+        assert_eq!(laws(code), vec!["P02"]);
+    }
+
+    #[test]
+    fn p02_skip_spread_of_loop_var() {
+        let code = "items.forEach(item => { send({ ...item, seen: true }); });";
+        assert!(laws(code).is_empty());
+    }
+
+    #[test]
+    fn p03_flag_query_in_loop() {
+        let code = "for (const id of ids) { await prisma.user.findUnique({ where: { id } }); }";
+        assert_eq!(laws(code), vec!["P03"]);
+        let code = "ids.map(async (id) => db.query('SELECT * FROM t WHERE id = $1', [id]));";
+        assert_eq!(laws(code), vec!["P03"]);
+    }
+
+    #[test]
+    fn p03_skip_query_without_loop_var() {
+        let code = "for (const id of ids) { await db.query(\"SELECT 1\"); }";
+        assert!(laws(code).is_empty());
+    }
+}
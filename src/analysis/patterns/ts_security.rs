@@ -0,0 +1,182 @@
+// src/analysis/patterns/ts_security.rs
+//! TypeScript/JavaScript security patterns: X01, X02, X03
+
+use crate::analysis::checks::CheckContext;
+use crate::types::{Violation, ViolationDetails};
+use tree_sitter::{Node, QueryCursor};
+use super::get_capture_node;
+use super::security::{has_sql, is_placeholder_secret};
+
+#[must_use]
+pub fn detect(ctx: &CheckContext) -> Vec<Violation> {
+    let mut out = Vec::new();
+    detect_x01_sql(ctx, &mut out);
+    detect_x02_command(ctx, &mut out);
+    detect_x03_secrets(ctx, &mut out);
+    out
+}
+
+/// X01: SQL built with template literals or string concatenation.
+/// Tagged `sql` templates are parameterized and skipped.
+fn detect_x01_sql(ctx: &CheckContext, out: &mut Vec<Violation>) {
+    let q = "[(template_string) (string)] @sql";
+    let Some(query) = ctx.query(q) else { return };
+    let mut cursor = QueryCursor::new();
+
+    for m in cursor.matches(&query, ctx.root, ctx.source.as_bytes()) {
+        let Some(node) = m.captures.first().map(|c| c.node) else { continue };
+        let text = node.utf8_text(ctx.source.as_bytes()).unwrap_or("");
+        if !has_sql(text) || !is_interpolated(node) { continue }
+
+        out.push(Violation::with_details(
+            node.start_position().row + 1,
+            "Potential SQL Injection".into(),
+            "X01",
+            ViolationDetails {
+                function_name: None,
+                analysis: vec!["Interpolating into SQL bypasses parameterization.".into()],
+                suggestion: Some("Use placeholders (`$1`, `?`) or a tagged `sql` template.".into()),
+            },
+        ));
+    }
+}
+
+/// True for untagged templates with substitutions and strings joined with `+`.
+fn is_interpolated(node: Node) -> bool {
+    let parent_kind = node.parent().map(|p| p.kind());
+    if node.kind() == "template_string" {
+        let mut cursor = node.walk();
+        let substitutes = node.named_children(&mut cursor).any(|c| c.kind() == "template_substitution");
+        return substitutes && parent_kind != Some("call_expression");
+    }
+    parent_kind == Some("binary_expression")
+}
+
+/// X02: `child_process` shell commands built from variables.
+fn detect_x02_command(ctx: &CheckContext, out: &mut Vec<Violation>) {
+    if !ctx.source.contains("child_process") { return }
+    let q = r#"(call_expression
+        function: [(identifier) @fn (member_expression property: (property_identifier) @fn)]
+        arguments: (arguments . (_) @cmd) @args
+        (#match? @fn "^(exec|execSync|spawn|spawnSync)$")) @call"#;
+    let Some(query) = ctx.query(q) else { return };
+    let idx_call = query.capture_index_for_name("call");
+    let idx_fn = query.capture_index_for_name("fn");
+    let idx_cmd = query.capture_index_for_name("cmd");
+    let idx_args = query.capture_index_for_name("args");
+    let mut cursor = QueryCursor::new();
+
+    for m in cursor.matches(&query, ctx.root, ctx.source.as_bytes()) {
+        let (Some(call), Some(func), Some(cmd), Some(args)) = (
+            get_capture_node(&m, idx_call),
+            get_capture_node(&m, idx_fn),
+            get_capture_node(&m, idx_cmd),
+            get_capture_node(&m, idx_args),
+        ) else { continue };
+
+        let func = func.utf8_text(ctx.source.as_bytes()).unwrap_or("");
+        let args = args.utf8_text(ctx.source.as_bytes()).unwrap_or("");
+        let runs_shell = func.starts_with("exec") || args.contains("shell: true");
+        if !runs_shell || is_plain_literal(cmd) || is_regex_exec(call) { continue }
+
+        out.push(Violation::with_details(
+            call.start_position().row + 1,
+            "Potential Command Injection".into(),
+            "X02",
+            ViolationDetails {
+                function_name: None,
+                analysis: vec!["Shell command string built from a variable.".into()],
+                suggestion: Some("Use `execFile`/`spawn` with an argument array and no shell.".into()),
+            },
+        ));
+    }
+}
+
+fn is_plain_literal(node: Node) -> bool {
+    match node.kind() {
+        "string" => true,
+        "template_string" => !is_interpolated(node),
+        _ => false,
+    }
+}
+
+/// `/re/.exec(text)` shares the name with `child_process.exec`.
+fn is_regex_exec(call: Node) -> bool {
+    call.child_by_field_name("function")
+        .and_then(|f| f.child_by_field_name("object"))
+        .is_some_and(|o| o.kind() == "regex")
+}
+
+/// X03: secrets assigned as string literals.
+fn detect_x03_secrets(ctx: &CheckContext, out: &mut Vec<Violation>) {
+    let q = r#"
+        (variable_declarator name: (identifier) @name value: [(string) (template_string)] @value
+            (#match? @name "(?i)(key|secret|token|password|auth)"))
+        (public_field_definition name: (property_identifier) @name value: [(string) (template_string)] @value
+            (#match? @name "(?i)(key|secret|token|password|auth)"))
+    "#;
+    let Some(query) = ctx.query(q) else { return };
+    let idx_value = query.capture_index_for_name("value");
+    let mut cursor = QueryCursor::new();
+
+    for m in cursor.matches(&query, ctx.root, ctx.source.as_bytes()) {
+        let Some(val) = get_capture_node(&m, idx_value) else { continue };
+        let text = val.utf8_text(ctx.source.as_bytes()).unwrap_or("");
+        if is_placeholder_secret(text) || !is_plain_literal(val) { continue }
+
+        out.push(Violation::with_details(
+            val.start_position().row + 1,
+            "Potential hardcoded secret".into(),
+            "X03",
+            ViolationDetails {
+                function_name: None,
+                analysis: vec!["Secrets should come from environment.".into()],
+                suggestion: Some("Use `process.env`.".into()),
+            },
+        ));
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::analysis::checks::with_context;
+    use crate::lang::Lang;
+
+    fn laws(code: &str) -> Vec<&'static str> {
+        with_context(Lang::TypeScript, code, detect).iter().map(|v| v.law).collect()
+    }
+
+    #[test]
+    fn x01_flag_template_and_concat_sql() {
+        assert_eq!(laws("const q = `SELECT * FROM t WHERE id = ${id}`;"), vec!["X01"]);
+        assert_eq!(laws("const q = \"DELETE FROM t WHERE id = \" + id;"), vec!["X01"]);
+    }
+
+    #[test]
+    fn x01_skip_tagged_and_parameterized_sql() {
+        assert!(laws("await sql`SELECT * FROM t WHERE id = ${id}`;").is_empty());
+        assert!(laws("db.query(\"SELECT * FROM t WHERE id = $1\", [id]);").is_empty());
+    }
+
+    #[test]
+    fn x02_flag_shell_strings() {
+        let code = "import { exec } from 'child_process';\nexec(`git checkout ${branch}`);";
+        assert_eq!(laws(code), vec!["X02"]);
+        let code = "import cp from 'child_process';\ncp.spawn(cmd, { shell: true });";
+        assert_eq!(laws(code), vec!["X02"]);
+    }
+
+    #[test]
+    fn x02_skip_literals_and_regex_exec() {
+        let code = "import { exec } from 'child_process';\nexec('ls -la');\n/a+/.exec(input);";
+        assert!(laws(code).is_empty());
+    }
+
+    #[test]
+    fn x03_flag_literal_secret() {
+        assert_eq!(laws("const apiToken = \"ghp_4f9a8b7c6d\";"), vec!["X03"]);
+        assert!(laws("const apiToken = process.env.TOKEN;").is_empty());
+    }
+}
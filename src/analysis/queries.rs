@@ -0,0 +1,80 @@
+//! Shared tree-sitter queries.
+//!
+//! Compiling a query is far more expensive than running it, so checks no
+//! longer call `Query::new` per file. Each language has one [`QuerySet`];
+//! a query is compiled the first time any check asks for it and reused by
+//! every file and thread after that.
+
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock, PoisonError, RwLock};
+
+use tree_sitter::Query;
+
+use crate::lang::Lang;
+
+/// Lazily compiled queries for one language.
+#[derive(Debug)]
+pub struct QuerySet {
+    lang: Lang,
+    compiled: RwLock<HashMap<&'static str, Option<Arc<Query>>>>,
+}
+
+static SETS: LazyLock<[QuerySet; 4]> =
+    LazyLock::new(|| [Lang::Rust, Lang::Python, Lang::TypeScript, Lang::Nim].map(QuerySet::new));
+
+impl QuerySet {
+    fn new(lang: Lang) -> Self {
+        Self {
+            lang,
+            compiled: RwLock::default(),
+        }
+    }
+
+    /// The process-wide query set for `lang`.
+    #[must_use]
+    pub fn for_lang(lang: Lang) -> &'static Self {
+        let [rust, python, typescript, nim] = &*SETS;
+        match lang {
+            Lang::Rust => rust,
+            Lang::Python => python,
+            Lang::TypeScript => typescript,
+            Lang::Nim => nim,
+        }
+    }
+
+    /// Returns the compiled query for `pattern`, compiling it on first use.
+    /// Patterns that do not compile for this grammar yield `None`, once.
+    #[must_use]
+    pub fn get(&self, pattern: &'static str) -> Option<Arc<Query>> {
+        let cached = self
+            .compiled
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(pattern)
+            .cloned();
+        if let Some(query) = cached {
+            return query;
+        }
+        let query = Query::new(self.lang.grammar(), pattern).ok().map(Arc::new);
+        self.compiled
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entry(pattern)
+            .or_insert(query)
+            .clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_queries_are_compiled_once_and_shared() {
+        let set = QuerySet::for_lang(Lang::Rust);
+        let a = set.get("(function_item) @f");
+        let b = QuerySet::for_lang(Lang::Rust).get("(function_item) @f");
+        assert!(matches!((a, b), (Some(a), Some(b)) if Arc::ptr_eq(&a, &b)));
+        assert!(set.get("(not_a_rust_node)").is_none());
+    }
+}
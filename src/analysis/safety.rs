@@ -1,11 +1,11 @@
-use crate::analysis::checks;
+use crate::analysis::{checks, fixes};
 use crate::config::RuleConfig;
 use crate::types::Violation;
 use std::path::Path;
-use tree_sitter::{Node, Query};
+use tree_sitter::Node;
 
 /// Checks for unsafe blocks and ensures they have justification comments.
-pub fn check_safety(ctx: &checks::CheckContext, _query: &Query, out: &mut Vec<Violation>) {
+pub fn check_safety(ctx: &checks::CheckContext, out: &mut Vec<Violation>) {
     if !is_rust_file(ctx.filename) {
         return;
     }
@@ -43,7 +43,7 @@ fn validate_unsafe_node(
     if config.safety.ban_unsafe {
         report_ban_violation(node, out);
     } else if config.safety.require_safety_comment && !has_safety_comment(node, source) {
-        report_comment_violation(node, out);
+        report_comment_violation(node, source, out);
     }
 }
 
@@ -53,10 +53,11 @@ fn report_ban_violation(node: Node, out: &mut Vec<Violation>) {
     out.push(Violation::simple(row, msg.to_string(), "LAW OF PARANOIA"));
 }
 
-fn report_comment_violation(node: Node, out: &mut Vec<Violation>) {
+fn report_comment_violation(node: Node, source: &str, out: &mut Vec<Violation>) {
     let row = node.start_position().row + 1;
     let msg = "Unsafe block missing justification. Add '// SAFETY:' comment.";
-    out.push(Violation::simple(row, msg.to_string(), "LAW OF PARANOIA"));
+    let fix = fixes::safety_stub(node, source);
+    out.push(Violation::simple(row, msg.to_string(), "LAW OF PARANOIA").with_fix(fix));
 }
 
 fn has_safety_comment(node: Node, source: &str) -> bool {
@@ -1,8 +1,8 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 
 /// Represents a cohesion and coupling scope (Class, Struct+Impl, Enum).
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Scope {
     name: String,
     row: usize,
@@ -12,7 +12,7 @@ pub struct Scope {
     derives: HashSet<String>,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FieldInfo {
     pub name: String,
     pub is_public: bool,
@@ -29,7 +29,7 @@ impl FieldInfo {
 }
 
 /// Represents a method within a scope.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Method {
     pub name: String,
     /// Fields accessed by this method
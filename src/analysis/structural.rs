@@ -75,18 +75,18 @@ impl ScopeMetrics {
                 let method_b = &methods[*name_b];
 
                 if Self::are_connected(method_a, method_b) {
-                    if let Some(vec_a) = adj.get_mut(*name_a) {
-                        vec_a.push(*name_b);
-                    }
-                    if let Some(vec_b) = adj.get_mut(*name_b) {
-                        vec_b.push(*name_a);
-                    }
+                    Self::link(&mut adj, name_a, name_b);
                 }
             }
         }
         adj
     }
 
+    fn link<'a>(adj: &mut HashMap<&'a String, Vec<&'a String>>, a: &'a String, b: &'a String) {
+        adj.entry(a).or_default().push(b);
+        adj.entry(b).or_default().push(a);
+    }
+
     fn count_components<'a>(
         names: &[&'a String],
         adj: &HashMap<&'a String, Vec<&'a String>>,
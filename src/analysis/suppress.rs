@@ -0,0 +1,184 @@
+//! Inline suppression comments.
+//!
+//! `// slopchop:allow(P01, reason = "...")` (or `#` in Python and Nim)
+//! silences the listed laws on the following line, or on the whole item
+//! that starts there. A reason is mandatory; suppressions without one are
+//! rejected and reported as violations themselves.
+
+use serde::{Deserialize, Serialize};
+use tree_sitter::{Node, Point, Tree};
+
+use crate::lang::Lang;
+use crate::types::{FileReport, Violation};
+
+/// Law reported for suppressions that are missing a reason.
+pub const LAW: &str = "LAW OF JUSTIFICATION";
+
+const MARKER: &str = "slopchop:allow(";
+
+/// A justified suppression and the rows it covers.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Suppression {
+    /// Row of the suppression comment (1-based).
+    pub row: usize,
+    /// Laws or codes being silenced.
+    pub laws: Vec<String>,
+    pub reason: String,
+    /// Last row covered (inclusive, 1-based).
+    pub end_row: usize,
+    /// Number of violations silenced by this suppression.
+    pub used: usize,
+}
+
+impl Suppression {
+    fn covers(&self, violation: &Violation) -> bool {
+        (self.row..=self.end_row).contains(&violation.row)
+            && self.laws.iter().any(|l| l.eq_ignore_ascii_case(violation.law))
+    }
+}
+
+/// Collects suppressions from `source`. Malformed or unjustified ones are
+/// returned as violations instead.
+#[must_use]
+pub fn collect(source: &str, tree: &Tree, lang: Lang) -> (Vec<Suppression>, Vec<Violation>) {
+    let lines: Vec<&str> = source.lines().collect();
+    let mut suppressions = Vec::new();
+    let mut rejected = Vec::new();
+
+    for (idx, line) in lines.iter().enumerate() {
+        let Some(inner) = marker_body(line, lang.line_comment()) else {
+            continue;
+        };
+        let row = idx + 1;
+        match parse_body(inner) {
+            Ok((laws, reason)) => suppressions.push(Suppression {
+                row,
+                laws,
+                reason,
+                end_row: item_end(tree, &lines, idx, lang),
+                used: 0,
+            }),
+            Err(problem) => rejected.push(Violation::simple(
+                row,
+                format!("Suppression rejected: {problem}"),
+                LAW,
+            )),
+        }
+    }
+    (suppressions, rejected)
+}
+
+/// Drops violations covered by the file's suppressions.
+pub fn apply(report: &mut FileReport) {
+    let suppressions = &mut report.suppressions;
+    report.violations.retain(|v| {
+        let Some(s) = suppressions.iter_mut().find(|s| s.covers(v)) else {
+            return true;
+        };
+        s.used += 1;
+        false
+    });
+}
+
+/// Text between the marker and the `)` that closes it. Anything after that,
+/// such as a trailing note, is ignored; parentheses in the reason are not
+/// counted.
+fn marker_body<'a>(line: &'a str, comment: &str) -> Option<&'a str> {
+    let rest = line.trim().strip_prefix(comment)?.trim_start();
+    let body = rest.strip_prefix(MARKER)?;
+    let mut depth = 0usize;
+    let mut quoted = false;
+    for (i, c) in body.char_indices() {
+        match c {
+            '"' => quoted = !quoted,
+            '(' if !quoted => depth += 1,
+            ')' if !quoted && depth == 0 => return Some(&body[..i]),
+            ')' if !quoted => depth -= 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+fn parse_body(inner: &str) -> Result<(Vec<String>, String), &'static str> {
+    let (codes, reason) = split_reason(inner);
+    let laws: Vec<String> = codes
+        .split(',')
+        .map(str::trim)
+        .filter(|c| !c.is_empty())
+        .map(str::to_string)
+        .collect();
+    if laws.is_empty() {
+        return Err("no law or code listed");
+    }
+
+    let reason = reason
+        .map(str::trim)
+        .and_then(|r| r.strip_prefix('"'))
+        .and_then(|r| r.strip_suffix('"'))
+        .map(str::trim)
+        .unwrap_or_default();
+    if reason.is_empty() {
+        return Err("a non-empty reason = \"...\" is required");
+    }
+    Ok((laws, reason.to_string()))
+}
+
+/// Splits the codes from the value of the first `reason =` field, so a
+/// code that merely contains "reason" stays a code.
+fn split_reason(inner: &str) -> (&str, Option<&str>) {
+    let mut start = 0;
+    for field in inner.split(',') {
+        let value = inner[start..]
+            .trim_start()
+            .strip_prefix("reason")
+            .map(str::trim_start)
+            .and_then(|rest| rest.strip_prefix('='));
+        if value.is_some() {
+            return (&inner[..start], value);
+        }
+        start += field.len() + 1;
+    }
+    (inner, None)
+}
+
+/// Last row of the item starting on the first code line after `comment_idx`.
+/// Stacked comments and attributes/decorators between them are skipped.
+fn item_end(tree: &Tree, lines: &[&str], comment_idx: usize, lang: Lang) -> usize {
+    let target = lines
+        .iter()
+        .enumerate()
+        .skip(comment_idx + 1)
+        .find(|(_, l)| !is_preamble(l, lang));
+    let Some((idx, line)) = target else {
+        return comment_idx + 1;
+    };
+
+    let column = line.len() - line.trim_start().len();
+    let point = Point::new(idx, column);
+    let Some(node) = tree.root_node().descendant_for_point_range(point, point) else {
+        return idx + 1;
+    };
+    outermost_at(node, point).end_position().row + 1
+}
+
+fn outermost_at(mut node: Node, point: Point) -> Node {
+    while let Some(parent) = node.parent() {
+        if parent.start_position() != point {
+            break;
+        }
+        node = parent;
+    }
+    node
+}
+
+fn is_preamble(line: &str, lang: Lang) -> bool {
+    let trimmed = line.trim();
+    trimmed.is_empty()
+        || trimmed.starts_with(lang.line_comment())
+        || trimmed.starts_with("#[")
+        || trimmed.starts_with('@')
+}
+
+#[cfg(test)]
+mod tests;
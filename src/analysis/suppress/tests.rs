@@ -0,0 +1,85 @@
+// src/analysis/suppress/tests.rs
+//! Suppression parsing and coverage tests.
+
+use super::*;
+use tree_sitter::Parser;
+
+fn parse(source: &str, lang: Lang) -> Tree {
+    let mut parser = Parser::new();
+    let _ = parser.set_language(lang.grammar());
+    parser
+        .parse(source, None)
+        .unwrap_or_else(|| panic!("parse failed"))
+}
+
+fn file(violations: Vec<Violation>, suppressions: Vec<Suppression>) -> FileReport {
+    FileReport {
+        path: "src/lib.rs".into(),
+        token_count: 0,
+        complexity_score: 0,
+        violations,
+        analysis: None,
+        suppressions,
+        profile: "application".into(),
+        suggested_profile: None,
+    }
+}
+
+#[test]
+fn test_suppression_covers_following_item() {
+    let source = "// slopchop:allow(P01, reason = \"hot path, measured\")\n#[inline]\nfn a() {\n    x.clone();\n}\nfn b() { y.clone(); }\n";
+    let (suppressions, rejected) = collect(source, &parse(source, Lang::Rust), Lang::Rust);
+    assert!(rejected.is_empty());
+    assert_eq!(suppressions.first().map(|s| s.end_row), Some(5));
+
+    let mut report = file(
+        vec![
+            Violation::simple(4, "clone".into(), "P01"),
+            Violation::simple(4, "other".into(), "X01"),
+            Violation::simple(6, "clone".into(), "P01"),
+        ],
+        suppressions,
+    );
+    apply(&mut report);
+
+    assert_eq!(report.violations.len(), 2);
+    assert_eq!(report.suppressions.first().map(|s| s.used), Some(1));
+}
+
+#[test]
+fn test_python_form_and_missing_reason() {
+    let source = "# slopchop:allow(LAW OF COMPLEXITY, S01, reason = \"legacy parser\")\ndef f():\n    pass\n# slopchop:allow(P01)\nx = 1\n";
+    let (suppressions, rejected) = collect(source, &parse(source, Lang::Python), Lang::Python);
+
+    assert_eq!(suppressions.len(), 1);
+    assert_eq!(
+        suppressions.first().map(|s| s.laws.clone()),
+        Some(vec!["LAW OF COMPLEXITY".to_string(), "S01".to_string()])
+    );
+    assert_eq!(rejected.len(), 1);
+    assert_eq!(rejected.first().map(|v| (v.row, v.law)), Some((4, LAW)));
+}
+
+#[test]
+fn test_marker_ends_at_its_closing_paren() {
+    let source = "// slopchop:allow(P01, reason = \"see (a)\") -- tracked in #12\nfn a() {}\n// slopchop:allow(P01, reason = \"x\"\nfn b() {}\n";
+    let (suppressions, rejected) = collect(source, &parse(source, Lang::Rust), Lang::Rust);
+
+    assert!(rejected.is_empty());
+    assert_eq!(suppressions.len(), 1);
+    assert_eq!(
+        suppressions.first().map(|s| s.reason.as_str()),
+        Some("see (a)")
+    );
+}
+
+#[test]
+fn test_reason_is_split_at_its_own_field() {
+    let source = "# slopchop:allow(no-reason-logging, reason = \"audited, see #7\")\nx = 1\n";
+    let (suppressions, rejected) = collect(source, &parse(source, Lang::Python), Lang::Python);
+
+    assert!(rejected.is_empty());
+    let suppression = suppressions.first().unwrap();
+    assert_eq!(suppression.laws, vec!["no-reason-logging".to_string()]);
+    assert_eq!(suppression.reason, "audited, see #7");
+}
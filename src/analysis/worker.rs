@@ -5,42 +5,48 @@ use std::path::Path;
 
 use tree_sitter::Parser;
 
-use crate::config::Config;
+use crate::config::{profiles, Config};
+use crate::graph::locality::TypeCounts;
+use crate::graph::{defs, imports};
 use crate::lang::Lang;
 use crate::tokens::Tokenizer;
 use crate::types::{FileReport, Violation};
 
 use super::aggregator::FileAnalysis;
 use super::ast;
+use super::checks::CheckContext;
+use super::custom;
 use super::patterns;
+use super::suppress;
 use super::visitor::AstVisitor;
 
+/// Reads and analyzes `path`, relative to the project root.
 #[must_use]
 pub fn scan_file(path: &Path, config: &Config) -> FileReport {
-    let mut report = FileReport {
-        path: path.to_path_buf(),
-        token_count: 0,
-        complexity_score: 0,
-        violations: Vec::new(),
-        analysis: None,
-    };
+    match std::fs::read_to_string(config.root.join(path)) {
+        Ok(source) => scan_source(path, &source, config),
+        Err(_) => empty_report(path, config.rules_for(path).0),
+    }
+}
 
-    let Ok(source) = std::fs::read_to_string(path) else {
-        return report;
-    };
+/// Runs local analysis over already-read `source`.
+#[must_use]
+pub fn scan_source(path: &Path, source: &str, config: &Config) -> FileReport {
+    let (profile, rules) = config.rules_for(path);
+    let mut report = empty_report(path, profile);
 
-    report.token_count = Tokenizer::count(&source);
+    report.token_count = Tokenizer::count(source);
 
-    let effective_config = determine_effective_config(&source, config);
+    report.suggested_profile = profiles::suggest(source)
+        .filter(|s| *s != report.profile)
+        .map(str::to_string);
 
-    if report.token_count > effective_config.rules.max_file_tokens
-        && !is_ignored(path, &effective_config.rules.ignore_tokens_on)
-    {
+    if report.token_count > rules.max_file_tokens && !is_ignored(path, &rules.ignore_tokens_on) {
         report.violations.push(Violation::simple(
             1,
             format!(
                 "File size is {} tokens (Limit: {})",
-                report.token_count, effective_config.rules.max_file_tokens
+                report.token_count, rules.max_file_tokens
             ),
             "LAW OF ATOMICITY",
         ));
@@ -55,27 +61,30 @@ pub fn scan_file(path: &Path, config: &Config) -> FileReport {
         return report;
     }
 
-    let Some(tree) = parser.parse(&source, None) else {
+    let Some(tree) = parser.parse(source, None) else {
         return report;
     };
 
     let root = tree.root_node();
+    let filename = path.to_str().unwrap_or("");
+    let ctx = CheckContext::new(lang, root, source, filename, &rules);
 
+    let (suppressions, rejected) = suppress::collect(source, &tree, lang);
+    report.suppressions = suppressions;
+    report.violations.extend(rejected);
+
+    report.violations.extend(patterns::detect_all(&ctx));
+    let custom_rules = config.custom_rules.for_profile(&report.profile);
     report
         .violations
-        .extend(patterns::detect_all(path, &source));
-
-    let ast_result = ast::Analyzer::new().analyze(
-        lang,
-        path.to_str().unwrap_or(""),
-        &source,
-        &effective_config.rules,
-    );
+        .extend(custom::detect(custom_rules, path, lang, source, root));
+
+    let ast_result = ast::Analyzer::new().analyze(&ctx);
     report.violations.extend(ast_result.violations);
     report.complexity_score = ast_result.max_complexity;
 
     let scopes = if lang == Lang::Rust {
-        let visitor = AstVisitor::new(&source, lang);
+        let visitor = AstVisitor::new(source, lang);
         visitor.extract_scopes(root)
     } else {
         HashMap::new()
@@ -85,49 +94,26 @@ pub fn scan_file(path: &Path, config: &Config) -> FileReport {
         path_str: path.to_string_lossy().to_string(),
         scopes,
         violations: Vec::new(),
+        imports: imports::extract_from_tree(lang, root, source),
+        types: TypeCounts::of(&defs::extract_from_tree(lang, root, source)),
     });
 
+    suppress::apply(&mut report);
+    crate::rules::apply_levels(&mut report.violations, &rules.severity);
     report
 }
 
-fn determine_effective_config(source: &str, base_config: &Config) -> Config {
-    let score = calculate_systems_score(source);
-    if score >= 3 {
-        let mut sys_cfg = base_config.clone();
-        sys_cfg.rules.max_file_tokens = 10_000;
-        sys_cfg.rules.max_cognitive_complexity = 50;
-        sys_cfg.rules.max_lcom4 = 100;
-        sys_cfg.rules.max_cbo = 100;
-        sys_cfg
-    } else {
-        base_config.clone()
-    }
-}
-
-fn calculate_systems_score(source: &str) -> usize {
-    let mut score = 0;
-    if source.contains("#![no_std]") {
-        score += 5;
-    }
-    if source.contains("unsafe {") || source.contains("unsafe fn") {
-        score += 1;
-    }
-    if source.contains("transmute") {
-        score += 2;
-    }
-    if source.contains("repr(C)") || source.contains("repr(packed)") {
-        score += 2;
-    }
-    if source.contains("Atomic") {
-        score += 1;
-    }
-    if source.contains("*const") || source.contains("*mut") {
-        score += 1;
-    }
-    if source.contains("Pin<Box") {
-        score += 1;
+fn empty_report(path: &Path, profile: String) -> FileReport {
+    FileReport {
+        path: path.to_path_buf(),
+        token_count: 0,
+        complexity_score: 0,
+        violations: Vec::new(),
+        analysis: None,
+        suppressions: Vec::new(),
+        profile,
+        suggested_profile: None,
     }
-    score
 }
 
 #[must_use]
@@ -0,0 +1,216 @@
+// src/baseline.rs
+//! Baseline of accepted pre-existing violations.
+//!
+//! `slopchop baseline` snapshots the current violations to
+//! `.slopchop/baseline.json`. Later scans drop violations found in the
+//! baseline and report baseline entries that no longer occur.
+//!
+//! Entries are fingerprinted by path, law and a hash of the offending
+//! source line with whitespace collapsed, so unrelated edits that shift
+//! line numbers do not invalidate them.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::types::{FileReport, ScanReport, Violation};
+use crate::utils::compute_sha256;
+
+/// Location of the baseline file relative to the repository root.
+pub const BASELINE_PATH: &str = ".slopchop/baseline.json";
+
+const FORMAT_VERSION: u32 = 1;
+const HASH_LEN: usize = 16;
+
+/// A snapshot of accepted violations.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Baseline {
+    pub version: u32,
+    pub entries: Vec<BaselineEntry>,
+}
+
+/// A single accepted violation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BaselineEntry {
+    pub path: String,
+    pub law: String,
+    pub hash: String,
+    /// Line at snapshot time; informational only, not part of the fingerprint.
+    pub row: usize,
+    pub message: String,
+}
+
+/// Effect of applying a baseline to a scan.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct BaselineOutcome {
+    /// Violations dropped because they are in the baseline.
+    pub baselined: usize,
+    /// Baseline entries with no matching violation (fixed since the snapshot).
+    pub fixed: Vec<BaselineEntry>,
+}
+
+type Fingerprint = (String, String, String);
+
+impl BaselineEntry {
+    fn fingerprint(&self) -> Fingerprint {
+        (self.path.clone(), self.law.clone(), self.hash.clone())
+    }
+}
+
+/// Snapshots every violation in the report.
+#[must_use]
+pub fn snapshot(report: &ScanReport) -> Baseline {
+    let mut entries: Vec<BaselineEntry> = report
+        .files
+        .iter()
+        .filter(|f| !f.is_clean())
+        .flat_map(file_entries)
+        .collect();
+    entries.sort_by(|a, b| (&a.path, a.row, &a.law).cmp(&(&b.path, b.row, &b.law)));
+    Baseline {
+        version: FORMAT_VERSION,
+        entries,
+    }
+}
+
+/// Loads the baseline under `repo_root`, if one exists.
+///
+/// # Errors
+/// Returns error if the file exists but cannot be read or parsed.
+pub fn load(repo_root: &Path) -> Result<Option<Baseline>> {
+    let path = repo_root.join(BASELINE_PATH);
+    if !path.exists() {
+        return Ok(None);
+    }
+    let content =
+        fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))?;
+    let baseline = serde_json::from_str(&content)
+        .with_context(|| format!("Invalid baseline file {}", path.display()))?;
+    Ok(Some(baseline))
+}
+
+/// Writes the baseline under `repo_root`.
+///
+/// # Errors
+/// Returns error if the file cannot be written.
+pub fn save(baseline: &Baseline, repo_root: &Path) -> Result<PathBuf> {
+    let path = repo_root.join(BASELINE_PATH);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&path, serde_json::to_string_pretty(baseline)?)?;
+    Ok(path)
+}
+
+/// Drops baselined violations from the report and records the outcome.
+pub fn apply(baseline: &Baseline, report: &mut ScanReport) {
+    let mut remaining = counts(baseline);
+
+    let baselined = report
+        .files
+        .iter_mut()
+        .filter(|f| !f.is_clean())
+        .map(|file| apply_file(file, &mut remaining))
+        .sum();
+
+    let fixed = baseline
+        .entries
+        .iter()
+        .filter(|e| take_one(&mut remaining, &e.fingerprint()))
+        .cloned()
+        .collect();
+
+    report.total_violations = report.files.iter().map(FileReport::violation_count).sum();
+    report.baseline = Some(BaselineOutcome { baselined, fixed });
+}
+
+/// Entries with no match in `other`. Each entry of `other` matches at most
+/// once, so duplicates are counted.
+#[must_use]
+pub fn unmatched(entries: &[BaselineEntry], other: &Baseline) -> Vec<BaselineEntry> {
+    let mut remaining = counts(other);
+    entries
+        .iter()
+        .filter(|e| !take_one(&mut remaining, &e.fingerprint()))
+        .cloned()
+        .collect()
+}
+
+fn counts(baseline: &Baseline) -> HashMap<Fingerprint, usize> {
+    let mut counts = HashMap::new();
+    for entry in &baseline.entries {
+        *counts.entry(entry.fingerprint()).or_insert(0) += 1;
+    }
+    counts
+}
+
+/// Drops one file's baselined violations; returns how many were dropped.
+fn apply_file(file: &mut FileReport, remaining: &mut HashMap<Fingerprint, usize>) -> usize {
+    let lines = read_lines(&file.path);
+    let path = normalize_path(&file.path);
+    let before = file.violations.len();
+    file.violations.retain(|v| {
+        let key = (path.clone(), v.law.to_string(), code_hash(v, &lines));
+        !take_one(remaining, &key)
+    });
+    before - file.violations.len()
+}
+
+fn take_one(remaining: &mut HashMap<Fingerprint, usize>, key: &Fingerprint) -> bool {
+    match remaining.get_mut(key) {
+        Some(count) if *count > 0 => {
+            *count -= 1;
+            true
+        }
+        _ => false,
+    }
+}
+
+fn file_entries(file: &FileReport) -> Vec<BaselineEntry> {
+    let lines = read_lines(&file.path);
+    let path = normalize_path(&file.path);
+    file.violations
+        .iter()
+        .map(|v| BaselineEntry {
+            path: path.clone(),
+            law: v.law.to_string(),
+            hash: code_hash(v, &lines),
+            row: v.row,
+            message: v.message.clone(),
+        })
+        .collect()
+}
+
+fn read_lines(path: &Path) -> Vec<String> {
+    fs::read_to_string(path)
+        .map(|content| content.lines().map(str::to_string).collect())
+        .unwrap_or_default()
+}
+
+fn normalize_path(path: &Path) -> String {
+    path.to_string_lossy().replace('\\', "/")
+}
+
+/// Hashes the offending line with whitespace collapsed. File-level
+/// violations (row 0) hash their message with numbers stripped, since
+/// counts such as token totals change with every edit.
+fn code_hash(violation: &Violation, lines: &[String]) -> String {
+    let line = violation.row.checked_sub(1).and_then(|idx| lines.get(idx));
+    let normalized = match line {
+        Some(line) => line.split_whitespace().collect::<Vec<_>>().join(" "),
+        None => violation
+            .message
+            .chars()
+            .filter(|c| !c.is_ascii_digit())
+            .collect(),
+    };
+    let mut hash = compute_sha256(&normalized);
+    hash.truncate(HASH_LEN);
+    hash
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,91 @@
+// src/baseline/tests.rs
+//! Baseline snapshot and matching tests.
+
+use super::*;
+use tempfile::TempDir;
+
+fn report_for(path: &Path, violations: Vec<Violation>) -> ScanReport {
+    ScanReport {
+        total_violations: violations.len(),
+        files: vec![FileReport {
+            path: path.to_path_buf(),
+            token_count: 0,
+            complexity_score: 0,
+            violations,
+            analysis: None,
+            suppressions: Vec::new(),
+            profile: "application".into(),
+            suggested_profile: None,
+        }],
+        ..ScanReport::default()
+    }
+}
+
+#[test]
+fn test_baseline_survives_line_shifts() -> Result<()> {
+    let dir = TempDir::new()?;
+    let file = dir.path().join("lib.rs");
+    fs::write(&file, "fn a() {\n    x.clone();\n}\nfn b() {}\n")?;
+    let old = report_for(
+        &file,
+        vec![
+            Violation::simple(2, "clone in loop".into(), "P01"),
+            Violation::simple(4, "bad name".into(), "LAW OF BLUNTNESS"),
+        ],
+    );
+    let baseline = snapshot(&old);
+
+    // A line is inserted above and `b` is fixed; a new violation appears.
+    fs::write(
+        &file,
+        "// header\nfn a() {\n  x.clone();\n}\nfn c() { y.clone(); }\n",
+    )?;
+    let mut new = report_for(
+        &file,
+        vec![
+            Violation::simple(3, "clone in loop".into(), "P01"),
+            Violation::simple(5, "clone in loop".into(), "P01"),
+        ],
+    );
+    apply(&baseline, &mut new);
+
+    assert_eq!(new.total_violations, 1);
+    assert_eq!(new.files.first().map(|f| f.violations.len()), Some(1));
+    let outcome = new.baseline.unwrap_or_default();
+    assert_eq!(outcome.baselined, 1);
+    assert_eq!(outcome.fixed.len(), 1);
+    assert_eq!(
+        outcome.fixed.first().map(|e| e.law.as_str()),
+        Some("LAW OF BLUNTNESS")
+    );
+    Ok(())
+}
+
+#[test]
+fn test_baseline_round_trips() -> Result<()> {
+    let dir = TempDir::new()?;
+    assert!(load(dir.path())?.is_none());
+
+    let report = report_for(
+        Path::new("missing.rs"),
+        vec![Violation::simple(
+            0,
+            "File is 2500 tokens".into(),
+            "LAW OF ATOMICITY",
+        )],
+    );
+    save(&snapshot(&report), dir.path())?;
+
+    let loaded = load(dir.path())?.unwrap_or_default();
+    let mut grown = report_for(
+        Path::new("missing.rs"),
+        vec![Violation::simple(
+            0,
+            "File is 2700 tokens".into(),
+            "LAW OF ATOMICITY",
+        )],
+    );
+    apply(&loaded, &mut grown);
+    assert_eq!(grown.total_violations, 0);
+    Ok(())
+}
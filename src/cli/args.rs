@@ -1,4 +1,4 @@
-use clap::{Parser, Subcommand};
+use clap::{Parser, Subcommand, ValueEnum};
 
 #[derive(Parser)]
 #[command(name = "slopchop", version, about = "AI Code Quality Guardian")]
@@ -9,25 +9,86 @@ pub struct Cli {
 
 #[derive(Subcommand)]
 pub enum Commands {
-    /// Run structural checks on the codebase
+    /// Run verification commands and the structural scan
     Check {
-        /// Output results as JSON
+        /// Output results as JSON (shorthand for `--format json`)
         #[arg(long)]
         json: bool,
+        /// Output format
+        #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
+        /// Deprecated: locality findings are part of the scan phase
+        #[arg(long, short, hide = true)]
+        locality: bool,
     },
 
     /// Scan for violations
     Scan {
         #[arg(long, short)]
         verbose: bool,
-        /// Run topology/locality analysis [EXPERIMENTAL]
+        /// Also print the full topology report with module metrics
         #[arg(long, short)]
         locality: bool,
+        /// Output results as JSON (shorthand for `--format json`)
+        #[arg(long)]
+        json: bool,
+        /// Output format
+        #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
+        /// Only report files changed since this git ref (e.g. `main`)
+        #[arg(long, value_name = "REF", group = "diff")]
+        since: Option<String>,
+        /// Only report files with staged changes
+        #[arg(long, group = "diff")]
+        staged: bool,
+        /// With --since or --staged, only report violations on changed lines
+        #[arg(long, requires = "diff")]
+        changed_lines: bool,
+    },
+
+    /// Export the dependency graph with layers, coupling and failing edges
+    Graph {
+        /// Output format
+        #[arg(long, value_enum, default_value_t = GraphFormat::Dot)]
+        format: GraphFormat,
+        /// One node per directory instead of per file
+        #[arg(long)]
+        dirs: bool,
+    },
+
+    /// Run the configured fix commands, apply structured fixes and rescan
+    Fix {
+        /// Print the fixes as a unified diff instead of applying them
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Rescan on every change and print the violations introduced or fixed
+    Watch {
+        /// Also rerun the check commands once changes settle
+        #[arg(long)]
+        check: bool,
+        /// Milliseconds without changes before the check commands run
+        #[arg(long, default_value_t = 2000)]
+        debounce: u64,
+    },
+
+    /// Snapshot current violations to .slopchop/baseline.json
+    Baseline,
+
+    /// List every rule with its category and level
+    Rules {
         /// Output results as JSON
         #[arg(long)]
         json: bool,
     },
 
+    /// Print the documentation for a rule
+    Explain {
+        /// Rule id, e.g. `P04` (laws can be named by their last word)
+        id: String,
+    },
+
     /// Create or reset the work branch
     Branch {
         #[arg(long, short)]
@@ -52,6 +113,9 @@ pub enum Commands {
     /// Interactive configuration editor
     Config,
 
+    /// Serve diagnostics and code actions over LSP (stdio)
+    Lsp,
+
     /// Run mutation testing to find test gaps [EXPERIMENTAL]
     Mutate {
         /// Number of parallel workers (reserved for future use)
@@ -68,3 +132,39 @@ pub enum Commands {
         filter: Option<String>,
     },
 }
+
+/// Output format for `scan` and `check`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable console output
+    #[default]
+    Text,
+    /// Machine-readable JSON
+    Json,
+    /// SARIF 2.1.0 for code scanning tools
+    Sarif,
+}
+
+impl OutputFormat {
+    /// Resolves the legacy `--json` flag against `--format`.
+    #[must_use]
+    pub fn resolve(self, json: bool) -> Self {
+        if json {
+            Self::Json
+        } else {
+            self
+        }
+    }
+}
+
+/// Output format for `graph`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum GraphFormat {
+    /// Graphviz DOT
+    #[default]
+    Dot,
+    /// Mermaid flowchart
+    Mermaid,
+    /// Nodes, edges and cycles as JSON
+    Json,
+}
@@ -0,0 +1,36 @@
+// src/cli/baseline_handler.rs
+use crate::analysis::Engine;
+use crate::baseline;
+use crate::cli::handlers::get_repo_root;
+use crate::config::Config;
+use crate::discovery;
+use crate::exit::SlopChopExit;
+use anyhow::Result;
+use colored::Colorize;
+
+/// Handles the baseline command.
+///
+/// Scans the project and snapshots every current violation, so later
+/// scans only fail on violations introduced after this point.
+///
+/// # Errors
+/// Returns error if the config is invalid, discovery fails or the baseline
+/// cannot be written.
+pub fn handle_baseline() -> Result<SlopChopExit> {
+    let repo_root = get_repo_root();
+    let config = Config::load();
+    config.validate()?;
+    let files = discovery::discover(&config)?;
+    let report = Engine::new(config).scan(&files);
+
+    let snapshot = baseline::snapshot(&report);
+    let path = baseline::save(&snapshot, &repo_root)?;
+
+    println!(
+        "{} Baselined {} violations in {}",
+        "OK".green().bold(),
+        snapshot.entries.len(),
+        path.display()
+    );
+    Ok(SlopChopExit::Success)
+}
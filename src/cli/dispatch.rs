@@ -5,8 +5,10 @@ use super::{
     git_ops::{handle_abort, handle_branch, handle_promote},
     handlers::{handle_check, handle_scan},
 };
+use crate::diff::{DiffBase, DiffFilter};
 use crate::exit::SlopChopExit;
 use anyhow::{anyhow, Result};
+use std::time::Duration;
 
 /// Executes the parsed command.
 ///
@@ -17,27 +19,52 @@ pub fn execute(command: Commands) -> Result<SlopChopExit> {
         Commands::Check { .. } | Commands::Scan { .. } | Commands::Mutate { .. } => {
             handle_analysis(command)
         }
+        Commands::Graph { format, dirs } => super::graph_handler::handle_graph(format, dirs),
+        Commands::Fix { dry_run } => super::fix_handler::handle_fix(dry_run),
+        Commands::Watch { check, debounce } => {
+            super::watch_handler::handle_watch(check, Duration::from_millis(debounce))
+        }
+        Commands::Baseline => super::baseline_handler::handle_baseline(),
+        Commands::Rules { json } => super::rules_handler::handle_rules(json),
+        Commands::Explain { id } => super::rules_handler::handle_explain(&id),
 
         Commands::Branch { .. } | Commands::Promote { .. } | Commands::Abort => {
             handle_git_ops(&command)
         }
 
-        Commands::Clean { .. } | Commands::Config => handle_core_ops(&command),
+        Commands::Clean { .. } | Commands::Config | Commands::Lsp => handle_core_ops(&command),
     }
 }
 
 fn handle_analysis(command: Commands) -> Result<SlopChopExit> {
     match command {
-        Commands::Check { json } => handle_check(json),
+        Commands::Check {
+            json,
+            format,
+            locality,
+        } => {
+            if locality {
+                eprintln!("`check --locality` is deprecated: locality is part of the scan phase");
+            }
+            handle_check(format.resolve(json))
+        }
         Commands::Scan {
             verbose,
             locality,
             json,
+            format,
+            since,
+            staged,
+            changed_lines,
         } => {
-            if locality {
-                return super::locality::handle_locality();
-            }
-            handle_scan(verbose, false, json)
+            let diff = since
+                .map(DiffBase::Since)
+                .or(staged.then_some(DiffBase::Staged))
+                .map(|base| DiffFilter {
+                    base,
+                    changed_lines,
+                });
+            handle_scan(verbose, locality, format.resolve(json), diff.as_ref())
         }
         Commands::Mutate {
             workers,
@@ -68,6 +95,10 @@ fn handle_core_ops(command: &Commands) -> Result<SlopChopExit> {
             super::config_ui::run_config_editor()?;
             Ok(SlopChopExit::Success)
         }
+        Commands::Lsp => {
+            crate::lsp::run()?;
+            Ok(SlopChopExit::Success)
+        }
         _ => Err(anyhow!("Internal error: Invalid core command")),
     }
 }
@@ -0,0 +1,82 @@
+// src/cli/fix_handler.rs
+use crate::analysis::Engine;
+use crate::cli::handlers::{apply_baseline, get_repo_root, scan_report};
+use crate::config::Config;
+use crate::discovery;
+use crate::exit::SlopChopExit;
+use crate::fix;
+use crate::reporting;
+use crate::types::ScanReport;
+use crate::verification;
+use anyhow::Result;
+use colored::Colorize;
+
+/// Handles the fix command.
+///
+/// Runs the `[commands].fix` entries, applies the structured fixes
+/// attached to violations, then rescans. With `dry_run`, nothing is run
+/// or written; the fixes are printed as a unified diff.
+///
+/// # Errors
+/// Returns error if the config is invalid, discovery fails or a fixed
+/// file cannot be read or written.
+pub fn handle_fix(dry_run: bool) -> Result<SlopChopExit> {
+    let config = Config::load();
+    config.validate()?;
+    let commands = config.commands.get("fix").cloned().unwrap_or_default();
+
+    if dry_run {
+        for cmd in &commands {
+            println!("{} {cmd}", "Would run:".dimmed());
+        }
+        for patch in fix::plan(&scan(&config)?)? {
+            print!("{}", patch.diff());
+        }
+        return Ok(SlopChopExit::Success);
+    }
+
+    let commands_passed = run_fix_commands(&commands);
+    let patches = fix::plan(&scan(&config)?)?;
+    for patch in &patches {
+        patch.write()?;
+        println!(
+            "{} {} ({})",
+            "Fixed".green().bold(),
+            patch.path.display(),
+            patch.applied.join(", ")
+        );
+    }
+    if patches.is_empty() {
+        println!("{}", "No structured fixes to apply.".dimmed());
+    }
+
+    let report = scan(&config)?;
+    scan_report::print(&report);
+    if report.total_violations > 0 {
+        reporting::print_report(&report)?;
+    }
+    Ok(if commands_passed && !report.has_errors() {
+        SlopChopExit::Success
+    } else {
+        SlopChopExit::CheckFailed
+    })
+}
+
+/// Runs the fix commands, printing the output of any that fail.
+fn run_fix_commands(commands: &[String]) -> bool {
+    let report = verification::run_commands(&get_repo_root(), commands, |cmd, _, _| {
+        println!("{} {cmd}", "Running:".dimmed());
+    });
+    for failed in report.commands.iter().filter(|c| !c.passed) {
+        println!("{} {}", "Fix command failed:".red().bold(), failed.command);
+        println!("{}", failed.compressed);
+    }
+    report.passed
+}
+
+fn scan(config: &Config) -> Result<ScanReport> {
+    let files = discovery::discover(config)?;
+    let mut report = Engine::new(config.clone()).scan(&files);
+    apply_baseline(&mut report)?;
+    Ok(report)
+}
@@ -0,0 +1,46 @@
+// src/cli/graph_handler.rs
+use crate::cli::args::GraphFormat;
+use crate::config::Config;
+use crate::discovery;
+use crate::exit::SlopChopExit;
+use crate::graph::export::{render, DependencyGraph};
+use crate::graph::locality::{locate_cycles, ImportGraph};
+use crate::reporting;
+use anyhow::Result;
+
+/// Handles the graph command.
+///
+/// Prints the file-level (or, with `dirs`, directory-level) dependency
+/// graph to stdout in the requested format, judged by the
+/// `[rules.locality]` settings. At file level, cycle break edges carry
+/// their import lines.
+///
+/// # Errors
+/// Returns error if the config is invalid, discovery fails, or JSON output
+/// fails.
+pub fn handle_graph(format: GraphFormat, dirs: bool) -> Result<SlopChopExit> {
+    let config = Config::load();
+    config.validate()?;
+    let files = discovery::discover(&config)?;
+    let root = std::env::current_dir()?;
+    let imports = ImportGraph::read(&root, &files);
+    let edges: Vec<_> = imports
+        .edges()
+        .map(|(from, to)| (from.to_path_buf(), to.to_path_buf()))
+        .collect();
+    let validator = config.validator_config();
+    let graph = if dirs {
+        DependencyGraph::directories(&edges, &validator)
+    } else {
+        let mut graph = DependencyGraph::files(&edges, &validator);
+        locate_cycles(&imports, &mut graph.cycles);
+        graph
+    };
+
+    match format {
+        GraphFormat::Dot => print!("{}", render::dot(&graph)),
+        GraphFormat::Mermaid => print!("{}", render::mermaid(&graph)),
+        GraphFormat::Json => reporting::print_json(&graph)?,
+    }
+    Ok(SlopChopExit::Success)
+}
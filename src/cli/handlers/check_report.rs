@@ -0,0 +1,192 @@
+// src/cli/handlers/check_report.rs
+//! Check scorecard display formatting.
+
+use crate::verification::{CommandResult, PhaseResult, VerificationReport};
+use colored::Colorize;
+use std::time::Duration;
+
+const RULE_HEAVY: &str = "═══════════════════════════════════════════════════════════";
+const RULE_LIGHT: &str = "  ─────────────────────────────────────────────────────────";
+const MAX_ERROR_LOCATIONS: usize = 3;
+
+/// Prints a formatted scorecard for the verification report.
+pub fn print(report: &VerificationReport) {
+    print_header(report);
+    print_commands(&report.commands);
+    print_phases(&report.phases);
+    print_summary(report);
+    print_verdict(report.passed);
+}
+
+fn print_header(report: &VerificationReport) {
+    let duration = Duration::from_millis(report.duration_ms);
+
+    println!();
+    println!("{}", RULE_HEAVY.blue());
+    println!(
+        "{} {}",
+        "  SLOPCHOP CHECK REPORT".white().bold(),
+        format!("({duration:.2?})").dimmed()
+    );
+    println!("{}", RULE_HEAVY.blue());
+    println!();
+}
+
+fn print_section(title: &str) {
+    println!("{}", format!("  {title}").cyan().bold());
+    println!("{}", RULE_LIGHT.blue());
+}
+
+fn status_mark(passed: bool) -> colored::ColoredString {
+    if passed {
+        "✓".green()
+    } else {
+        "✗".red()
+    }
+}
+
+fn print_commands(commands: &[CommandResult]) {
+    print_section("COMMANDS");
+
+    for cmd in commands {
+        let duration_str = format!("{:>4}ms", cmd.duration_ms).dimmed();
+        let cmd_display = if cmd.command.chars().count() > 40 {
+            let head: String = cmd.command.chars().take(37).collect();
+            format!("{head}...")
+        } else {
+            cmd.command.clone()
+        };
+
+        println!(
+            "  {} {} {}",
+            status_mark(cmd.passed),
+            cmd_display.white(),
+            duration_str
+        );
+        print_command_counts(cmd);
+    }
+
+    println!();
+}
+
+fn print_command_counts(cmd: &CommandResult) {
+    let errors = cmd.error_count();
+    let warnings = cmd.warning_count();
+
+    if errors == 0 && warnings == 0 {
+        return;
+    }
+
+    let mut parts = Vec::new();
+    if errors > 0 {
+        parts.push(format!("{} {}", errors.to_string().red(), "errors".red()));
+    }
+    if warnings > 0 {
+        parts.push(format!(
+            "{} {}",
+            warnings.to_string().yellow(),
+            "warnings".yellow()
+        ));
+    }
+    println!("      └─ {}", parts.join(", "));
+    print_error_locations(cmd);
+}
+
+/// Lists the first few error locations when cargo reported them exactly.
+fn print_error_locations(cmd: &CommandResult) {
+    let Some(diagnostics) = &cmd.diagnostics else {
+        return;
+    };
+    let errors = diagnostics.iter().filter(|d| d.level == "error");
+    for diagnostic in errors.take(MAX_ERROR_LOCATIONS) {
+        let location = diagnostic.location().unwrap_or_default();
+        let code = diagnostic.code.as_deref().unwrap_or("error");
+        println!(
+            "         {} {} {}",
+            location.white(),
+            code.red(),
+            diagnostic.message.dimmed()
+        );
+    }
+}
+
+fn print_phases(phases: &[PhaseResult]) {
+    if phases.is_empty() {
+        return;
+    }
+
+    print_section("GOVERNANCE");
+
+    for phase in phases {
+        let duration_str = format!("{:>4}ms", phase.duration_ms).dimmed();
+        let violations = if phase.violations == 0 {
+            "clean".green()
+        } else {
+            format!("{} violations", phase.violations).red()
+        };
+        println!(
+            "  {} {:<12} {} {}",
+            status_mark(phase.passed),
+            phase.name.white(),
+            violations,
+            duration_str
+        );
+    }
+
+    println!();
+}
+
+fn print_summary(report: &VerificationReport) {
+    print_section("SUMMARY");
+
+    let total_errors = report.total_errors();
+    let total_warnings = report.total_warnings();
+
+    println!(
+        "  Commands:  {} total, {} {}, {} {}",
+        report.total_commands().to_string().white().bold(),
+        report.passed_count().to_string().green(),
+        "passed".green(),
+        report.failed_count().to_string().red(),
+        "failed".red()
+    );
+
+    println!(
+        "  Output:    {} {}, {} {}",
+        total_errors.to_string().red().bold(),
+        if total_errors == 1 { "error" } else { "errors" }.red(),
+        total_warnings.to_string().yellow().bold(),
+        if total_warnings == 1 {
+            "warning"
+        } else {
+            "warnings"
+        }
+        .yellow()
+    );
+
+    if !report.phases.is_empty() {
+        let total_violations = report.total_violations();
+        println!(
+            "  Scan:      {} {}, {} {}",
+            total_violations.to_string().red().bold(),
+            if total_violations == 1 { "violation" } else { "violations" }.red(),
+            report.failed_phase_count().to_string().red(),
+            "phases failed".red()
+        );
+    }
+
+    println!();
+}
+
+fn print_verdict(passed: bool) {
+    if passed {
+        println!("{}", "  ✓ ALL CHECKS PASSED".green().bold());
+    } else {
+        println!("{}", "  ✗ CHECKS FAILED".red().bold());
+        println!();
+        println!("  See slopchop-report.txt for full output.");
+    }
+
+    println!();
+    println!("{}", RULE_HEAVY.blue());
+}
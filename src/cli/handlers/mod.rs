@@ -1,17 +1,22 @@
 //! Core analysis command handlers.
 
 use crate::analysis::Engine;
+use crate::baseline;
+use crate::cli::args::OutputFormat;
 use crate::config::Config;
+use crate::diff::{self, ChangedFiles, DiffFilter};
 use crate::discovery;
 use crate::exit::SlopChopExit;
-use crate::reporting;
+use crate::reporting::{self, fix_packet};
 use crate::spinner;
+use crate::types::ScanReport;
 use crate::verification;
 use anyhow::Result;
 use colored::Colorize;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicUsize, Ordering};
 
+pub mod check_report;
 pub mod scan_report;
 
 #[must_use]
@@ -21,21 +26,40 @@ pub fn get_repo_root() -> PathBuf {
 
 /// Handles the scan command.
 ///
+/// With a diff filter the whole project is still scanned, so deep
+/// analysis stays correct, but only changed files are reported. With
+/// `locality` the text report is followed by the full topology report.
+///
 /// # Errors
-/// Returns error if scan execution fails.
-pub fn handle_scan(verbose: bool, locality: bool, json: bool) -> Result<SlopChopExit> {
-    if locality {
-        return super::locality::handle_locality();
-    }
-
+/// Returns error if scan execution fails or git cannot list the changes.
+pub fn handle_scan(
+    verbose: bool,
+    locality: bool,
+    format: OutputFormat,
+    diff: Option<&DiffFilter>,
+) -> Result<SlopChopExit> {
     let mut config = Config::load();
+    config.validate()?;
     config.verbose = verbose;
+    let changes = diff.map(|d| ChangedFiles::collect(&d.base)).transpose()?;
+    let narrow = |report: &mut ScanReport| {
+        if let (Some(filter), Some(changes)) = (diff, &changes) {
+            diff::narrow(report, changes, filter);
+        }
+    };
 
-    if json {
+    if format != OutputFormat::Text {
         let files = discovery::discover(&config)?;
-        let engine = Engine::new(config);
-        let report = engine.scan(&files);
-        reporting::print_json(&report)?;
+        let engine = Engine::new(config.clone());
+        let mut report = engine.scan(&files);
+        apply_baseline(&mut report)?;
+        narrow(&mut report);
+        fix_packet::record(&config, report.has_errors(), Some(&report), &[])?;
+        if format == OutputFormat::Sarif {
+            reporting::print_json(&reporting::sarif::scan_log(&report))?;
+        } else {
+            reporting::print_json(&report)?;
+        }
         return Ok(if report.has_errors() {
             SlopChopExit::CheckFailed
         } else {
@@ -48,10 +72,10 @@ pub fn handle_scan(verbose: bool, locality: bool, json: bool) -> Result<SlopChop
 
     let files = discovery::discover(&config)?;
     let total = files.len();
-    let engine = Engine::new(config);
+    let engine = Engine::new(config.clone());
     let counter = AtomicUsize::new(0);
 
-    let report = engine.scan_with_progress(
+    let mut report = engine.scan_with_progress(
         &files,
         &|path| {
             let i = counter.fetch_add(1, Ordering::Relaxed) + 1;
@@ -64,13 +88,21 @@ pub fn handle_scan(verbose: bool, locality: bool, json: bool) -> Result<SlopChop
         },
     );
 
+    apply_baseline(&mut report)?;
+    narrow(&mut report);
+
     let has_errors = report.has_errors();
     controller.stop(!has_errors);
 
     scan_report::print(&report);
-    if has_errors {
+    if report.total_violations > 0 {
         reporting::print_report(&report)?;
     }
+    if locality {
+        super::locality::print_topology(&config)?;
+    }
+    let packet = fix_packet::record(&config, has_errors, Some(&report), &[])?;
+    print_packet_note(packet.as_deref());
 
     Ok(if has_errors {
         SlopChopExit::CheckFailed
@@ -81,159 +113,104 @@ pub fn handle_scan(verbose: bool, locality: bool, json: bool) -> Result<SlopChop
 
 /// Handles the check command.
 ///
+/// Runs the configured verification commands, then the structural scan,
+/// locality included, as a governance phase. All phases feed the flight
+/// recorder and the exit code.
+///
 /// # Errors
-/// Returns error if report file cannot be written.
-pub fn handle_check(json: bool) -> Result<SlopChopExit> {
+/// Returns error if the config is invalid, discovery fails or the report
+/// file cannot be written.
+pub fn handle_check(format: OutputFormat) -> Result<SlopChopExit> {
     let repo_root = get_repo_root();
+    let config = Config::load();
+    config.validate()?;
 
-    if json {
-        let report = verification::run(&repo_root, |_, _, _| {});
+    if format != OutputFormat::Text {
+        let mut report = verification::run(&repo_root, |_, _, _| {});
+        run_scan_phase(&mut report, None)?;
         std::fs::write("slopchop-report.txt", &report.output)?;
-        reporting::print_json(&report)?;
-        return Ok(if report.passed {
-            SlopChopExit::Success
+        record_check_packet(&config, &report)?;
+        if format == OutputFormat::Sarif {
+            reporting::print_json(&reporting::sarif::check_log(&report))?;
         } else {
-            SlopChopExit::CheckFailed
-        });
+            reporting::print_json(&report)?;
+        }
+        return Ok(exit_for(report.passed));
     }
 
     let (client, mut controller) = spinner::start("slopchop check");
+    client.set_macro_step(1, 2, "Commands");
     client.set_micro_status("Running verification commands...");
 
-    let report = verification::run(&repo_root, |cmd, current, total| {
+    let mut report = verification::run(&repo_root, |cmd, current, total| {
         client.step_micro_progress(current, total, format!("Running: {cmd}"));
         client.push_log(cmd);
     });
 
+    run_scan_phase(&mut report, Some(&client))?;
+
     std::fs::write("slopchop-report.txt", &report.output)?;
+    let packet = record_check_packet(&config, &report)?;
 
     controller.stop(report.passed);
 
-    print_check_scorecard(&report);
+    if let Some(scan) = report.scan.as_ref().filter(|s| s.total_violations > 0) {
+        reporting::print_report(scan)?;
+    }
+    check_report::print(&report);
+    print_packet_note(packet.as_deref());
 
-    Ok(if report.passed {
-        SlopChopExit::Success
-    } else {
-        SlopChopExit::CheckFailed
-    })
+    Ok(exit_for(report.passed))
 }
 
-/// Prints a formatted scorecard for the verification report.
-fn print_check_scorecard(report: &verification::VerificationReport) {
-    use std::time::Duration;
-
-    #[allow(clippy::cast_possible_truncation)]
-    let duration = Duration::from_millis(report.duration_ms);
-
-    println!();
-    println!(
-        "{}",
-        "═══════════════════════════════════════════════════════════".blue()
-    );
-    println!(
-        "{} {}",
-        "  SLOPCHOP CHECK REPORT".white().bold(),
-        format!("({duration:.2?})").dimmed()
-    );
-    println!(
-        "{}",
-        "═══════════════════════════════════════════════════════════".blue()
-    );
-    println!();
-
-    // Command results
-    println!("{}", "  COMMANDS".cyan().bold());
-    println!(
-        "{}",
-        "  ─────────────────────────────────────────────────────────".blue()
-    );
-
-    for cmd in &report.commands {
-        let status = if cmd.passed {
-            "✓".green()
-        } else {
-            "✗".red()
-        };
-        let duration_str = format!("{:>4}ms", cmd.duration_ms).dimmed();
-        let cmd_display = if cmd.command.len() > 40 {
-            format!("{}...", &cmd.command[..37])
-        } else {
-            cmd.command.clone()
-        };
-
-        println!("  {} {} {}", status, cmd_display.white(), duration_str);
-
-        // Show errors/warnings if any
-        let errors = cmd.error_count();
-        let warnings = cmd.warning_count();
-
-        if errors > 0 || warnings > 0 {
-            let mut parts = Vec::new();
-            if errors > 0 {
-                parts.push(format!("{} {}", errors.to_string().red(), "errors".red()));
-            }
-            if warnings > 0 {
-                parts.push(format!(
-                    "{} {}",
-                    warnings.to_string().yellow(),
-                    "warnings".yellow()
-                ));
-            }
-            println!("      └─ {}", parts.join(", "));
-        }
+/// Runs the scan phase and merges it into the report.
+fn run_scan_phase(
+    report: &mut verification::VerificationReport,
+    client: Option<&spinner::SpinnerClient>,
+) -> Result<()> {
+    if let Some(c) = client {
+        c.set_macro_step(2, 2, "Scan");
+        c.set_micro_status("Scanning files...");
     }
 
-    println!();
-
-    // Summary
-    println!("{}", "  SUMMARY".cyan().bold());
-    println!(
-        "{}",
-        "  ─────────────────────────────────────────────────────────".blue()
-    );
+    let config = Config::load();
+    let files = discovery::discover(&config)?;
+    let mut scan = Engine::new(config).scan(&files);
+    apply_baseline(&mut scan)?;
+    report.add_scan(scan);
+    Ok(())
+}
 
-    let total_cmds = report.total_commands();
-    let passed = report.passed_count();
-    let failed = report.failed_count();
-    let total_errors = report.total_errors();
-    let total_warnings = report.total_warnings();
-
-    println!(
-        "  Commands:  {} total, {} {}, {} {}",
-        total_cmds.to_string().white().bold(),
-        passed.to_string().green(),
-        "passed".green(),
-        failed.to_string().red(),
-        "failed".red()
-    );
+/// Writes (or clears) the fix packet for a check run.
+fn record_check_packet(
+    config: &Config,
+    report: &verification::VerificationReport,
+) -> Result<Option<PathBuf>> {
+    let scan = report.scan.as_ref();
+    fix_packet::record(config, !report.passed, scan, &report.commands)
+}
 
-    println!(
-        "  Output:    {} {}, {} {}",
-        total_errors.to_string().red().bold(),
-        if total_errors == 1 { "error" } else { "errors" }.red(),
-        total_warnings.to_string().yellow().bold(),
-        if total_warnings == 1 {
-            "warning"
-        } else {
-            "warnings"
-        }
-        .yellow()
-    );
+fn print_packet_note(packet: Option<&Path>) {
+    if let Some(path) = packet {
+        println!("{} {}", "Fix packet written to".dimmed(), path.display());
+    }
+}
 
-    println!();
+/// Drops violations recorded in `.slopchop/baseline.json`, if one exists.
+///
+/// # Errors
+/// Returns error if the baseline exists but cannot be read.
+pub fn apply_baseline(report: &mut ScanReport) -> Result<()> {
+    if let Some(accepted) = baseline::load(&get_repo_root())? {
+        baseline::apply(&accepted, report);
+    }
+    Ok(())
+}
 
-    // Final verdict
-    if report.passed {
-        println!("{}", "  ✓ ALL CHECKS PASSED".green().bold());
+fn exit_for(passed: bool) -> SlopChopExit {
+    if passed {
+        SlopChopExit::Success
     } else {
-        println!("{}", "  ✗ CHECKS FAILED".red().bold());
-        println!();
-        println!("  See slopchop-report.txt for full output.");
+        SlopChopExit::CheckFailed
     }
-
-    println!();
-    println!(
-        "{}",
-        "═══════════════════════════════════════════════════════════".blue()
-    );
 }
@@ -5,13 +5,16 @@ use crate::types::ScanReport;
 use crate::analysis::Engine;
 use colored::Colorize;
 use std::cmp::Reverse;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 /// Prints a formatted scan report to stdout.
 pub fn print(report: &ScanReport) {
     println!();
     print_header(report);
     print_small_codebase_note(report);
+    print_profile_summary(report);
+    print_baseline_summary(report);
+    print_suppression_summary(report);
     print_complexity_summary(report);
     print_size_summary(report);
     println!();
@@ -20,21 +23,31 @@ pub fn print(report: &ScanReport) {
 fn print_header(report: &ScanReport) {
     let status = if report.has_errors() {
         format!("{} violations", report.total_violations).red().bold()
+    } else if report.total_violations > 0 {
+        format!("{} warnings", report.total_violations).yellow().bold()
     } else {
         "Clean".green().bold()
     };
 
+    let files = match &report.diff {
+        Some(diff) => format!("{} changed files ({})", report.files.len(), diff.base),
+        None => format!("{} files", report.files.len()),
+    };
     println!(
-        "{} {} files │ {} tokens │ {}",
+        "{} {} │ {} tokens │ {}",
         "SCAN".cyan().bold(),
-        report.files.len(),
+        files,
         report.total_tokens,
         status
     );
 }
 
 fn print_small_codebase_note(report: &ScanReport) {
-    if report.files.len() < Engine::small_codebase_threshold() {
+    let project_files = report
+        .diff
+        .as_ref()
+        .map_or(report.files.len(), |d| d.project_files);
+    if project_files < Engine::small_codebase_threshold() {
         println!(
             "{}",
             format!(
@@ -46,6 +59,75 @@ fn print_small_codebase_note(report: &ScanReport) {
     }
 }
 
+fn print_profile_summary(report: &ScanReport) {
+    let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+    for file in &report.files {
+        *counts.entry(file.profile.as_str()).or_insert(0) += 1;
+    }
+    let summary: Vec<String> = counts
+        .iter()
+        .map(|(profile, count)| format!("{profile}: {count}"))
+        .collect();
+    println!("{}", format!("  ℹ Profiles │ {}", summary.join(" │ ")).dimmed());
+
+    let suggestions: Vec<_> = report
+        .files
+        .iter()
+        .filter_map(|f| Some((&f.path, f.suggested_profile.as_deref()?)))
+        .collect();
+    if suggestions.is_empty() {
+        return;
+    }
+    println!(
+        "  {} {} files look like they belong to another profile (set [path_profiles]):",
+        "?".yellow(),
+        suggestions.len()
+    );
+    for (path, profile) in suggestions.iter().take(5) {
+        println!("    {} → {}", path.display(), profile.yellow());
+    }
+}
+
+fn print_baseline_summary(report: &ScanReport) {
+    let Some(outcome) = &report.baseline else {
+        return;
+    };
+    println!(
+        "{}",
+        format!("  ℹ {} baselined violations hidden", outcome.baselined).dimmed()
+    );
+    if outcome.fixed.is_empty() {
+        return;
+    }
+    println!(
+        "  {} {} baseline entries fixed (run `slopchop baseline` to update):",
+        "✓".green(),
+        outcome.fixed.len()
+    );
+    for entry in outcome.fixed.iter().take(5) {
+        println!("    {}:{} {}", entry.path, entry.row, entry.law.dimmed());
+    }
+}
+
+fn print_suppression_summary(report: &ScanReport) {
+    let total: usize = report.files.iter().map(|f| f.suppressions.len()).sum();
+    if total == 0 {
+        return;
+    }
+    println!("{}", format!("  ℹ {total} inline suppressions:").dimmed());
+    for file in &report.files {
+        for s in &file.suppressions {
+            println!(
+                "    {}:{} {} {}",
+                file.path.display(),
+                s.row,
+                s.laws.join(", ").yellow(),
+                format!("— {}", s.reason).dimmed()
+            );
+        }
+    }
+}
+
 fn print_complexity_summary(report: &ScanReport) {
     let mut complexity: Vec<_> = report
         .files
@@ -1,11 +1,16 @@
 //! CLI command handlers.
 
 pub mod args;
+pub mod baseline_handler;
 pub mod config_ui;
 pub mod dispatch;
+pub mod fix_handler;
 pub mod git_ops;
+pub mod graph_handler;
 pub mod handlers;
 pub mod locality;
 pub mod mutate_handler;
+pub mod rules_handler;
+pub mod watch_handler;
 
 pub use args::Cli;
@@ -0,0 +1,123 @@
+// src/cli/rules_handler.rs
+use crate::config::types::{RuleConfig, RuleLevel};
+use crate::config::Config;
+use crate::exit::SlopChopExit;
+use crate::reporting;
+use crate::rules::{self, RuleInfo};
+use anyhow::Result;
+use colored::Colorize;
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct RuleRow {
+    #[serde(flatten)]
+    rule: &'static RuleInfo,
+    level: &'static str,
+}
+
+/// Handles the rules command.
+///
+/// Lists every registered rule with its default and configured level.
+/// Levels come from the project's default profile.
+///
+/// # Errors
+/// Returns error if the config is invalid or JSON output fails.
+pub fn handle_rules(json: bool) -> Result<SlopChopExit> {
+    let config = Config::load();
+    config.validate()?;
+    let rules = project_rules(&config);
+
+    let rows: Vec<RuleRow> = rules::all()
+        .map(|rule| RuleRow {
+            rule,
+            level: rules::effective_level(rule.id, &rules.severity, rule.severity).as_str(),
+        })
+        .collect();
+
+    if json {
+        reporting::print_json(&rows)?;
+        return Ok(SlopChopExit::Success);
+    }
+
+    println!(
+        "{:<22} {:<12} {:<8} {:<30} {}",
+        "RULE".bold(),
+        "CATEGORY".bold(),
+        "LEVEL".bold(),
+        "LANGUAGES".bold(),
+        "SUMMARY".bold()
+    );
+    for row in &rows {
+        println!(
+            "{:<22} {:<12} {:<8} {:<30} {}",
+            row.rule.id.yellow(),
+            row.rule.category,
+            colored_level(row.level),
+            row.rule.languages.join(", "),
+            row.rule.summary
+        );
+    }
+    println!(
+        "\n{}",
+        "Override levels under [rules.severity]; run `slopchop explain <RULE>` for details."
+            .dimmed()
+    );
+    Ok(SlopChopExit::Success)
+}
+
+/// Handles the explain command.
+///
+/// # Errors
+/// Returns error if the config is invalid.
+pub fn handle_explain(id: &str) -> Result<SlopChopExit> {
+    let Some(rule) = rules::find(id) else {
+        eprintln!(
+            "{} Unknown rule `{id}`. Run `slopchop rules` to list them.",
+            "error:".red().bold()
+        );
+        return Ok(SlopChopExit::InvalidInput);
+    };
+    let config = Config::load();
+    config.validate()?;
+    let level = rules::effective_level(rule.id, &project_rules(&config).severity, rule.severity);
+
+    println!("{} {}", rule.id.yellow().bold(), rule.summary);
+    println!(
+        "{}",
+        format!(
+            "category: {} | default: {} | configured: {} | languages: {}",
+            rule.category,
+            RuleLevel::from(rule.severity).as_str(),
+            level.as_str(),
+            rule.languages.join(", ")
+        )
+        .dimmed()
+    );
+    println!("\n{}\n", rule.rationale);
+    println!("{}\n{}\n", "Bad:".red().bold(), indent(rule.bad));
+    println!("{}\n{}", "Good:".green().bold(), indent(rule.good));
+    Ok(SlopChopExit::Success)
+}
+
+fn project_rules(config: &Config) -> RuleConfig {
+    config
+        .profile_settings
+        .default_profile()
+        .and_then(|name| config.profile_settings.rules_named(&config.rules, name))
+        .unwrap_or_else(|| config.rules.clone())
+}
+
+fn colored_level(level: &str) -> colored::ColoredString {
+    match level {
+        "error" => level.red(),
+        "warn" => level.yellow(),
+        _ => level.dimmed(),
+    }
+}
+
+fn indent(text: &str) -> String {
+    text.lines()
+        .map(|line| format!("    {line}"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
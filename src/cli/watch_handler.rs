@@ -0,0 +1,159 @@
+// src/cli/watch_handler.rs
+use crate::analysis::Engine;
+use crate::baseline::{self, Baseline, BaselineEntry};
+use crate::cli::handlers::{check_report, get_repo_root, scan_report};
+use crate::config::Config;
+use crate::discovery;
+use crate::exit::SlopChopExit;
+use crate::spinner;
+use crate::types::ScanReport;
+use crate::verification;
+use crate::watch::{self, Delta};
+use anyhow::Result;
+use colored::Colorize;
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// How often the watched files are polled for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// How often the file list is rediscovered to pick up added files.
+const REDISCOVER_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Handles the watch command.
+///
+/// Scans once, then rescans whenever changed files settle and prints the
+/// violations introduced or fixed since the previous stable scan. With
+/// `check`, the verification commands also run once no file has changed
+/// for `debounce`. Runs until interrupted.
+///
+/// # Errors
+/// Returns error if the config is invalid, the initial discovery fails or
+/// the baseline cannot be read.
+pub fn handle_watch(check: bool, debounce: Duration) -> Result<SlopChopExit> {
+    let config = Config::load();
+    config.validate()?;
+    let engine = Engine::new(config.clone());
+    let mut files = discovery::discover(&config)?;
+    let report = rescan(&engine, &files, "Scanning files...")?;
+    scan_report::print(&report);
+    println!(
+        "{}",
+        format!("Watching {} files. Press Ctrl-C to stop.", files.len()).dimmed()
+    );
+
+    let mut stable = baseline::snapshot(&report);
+    let mut seen = watch::mtimes(&files);
+    let mut pending = BTreeSet::new();
+    let mut last_change = Instant::now();
+    let mut check_due = check;
+    let mut discovered = Instant::now();
+    loop {
+        thread::sleep(POLL_INTERVAL);
+        if discovered.elapsed() >= REDISCOVER_INTERVAL {
+            rediscover(&config, &mut files);
+            discovered = Instant::now();
+        }
+        let now = watch::mtimes(&files);
+        let changed = watch::changed(&seen, &now);
+        seen = now;
+
+        if !changed.is_empty() {
+            pending.extend(changed);
+            last_change = Instant::now();
+            check_due = check;
+        } else if !pending.is_empty() {
+            stable = rescan_changed(&engine, &files, &pending, &stable)?;
+            pending.clear();
+        } else if check_due && last_change.elapsed() >= debounce {
+            check_due = false;
+            run_check();
+        }
+    }
+}
+
+/// Rediscovers the watched files. A failure, e.g. a directory removed
+/// mid-listing, is printed and the previous list kept rather than ending
+/// the session.
+fn rediscover(config: &Config, files: &mut Vec<PathBuf>) {
+    match discovery::discover(config) {
+        Ok(found) => *files = found,
+        Err(e) => eprintln!("{} {e}", "Discovery failed:".red()),
+    }
+}
+
+/// Rescans after `changed` settled, prints the delta against `stable` and
+/// returns the new stable snapshot.
+fn rescan_changed(
+    engine: &Engine,
+    files: &[PathBuf],
+    changed: &BTreeSet<PathBuf>,
+    stable: &Baseline,
+) -> Result<Baseline> {
+    let status = format!("Rescanning {} changed files...", changed.len());
+    let report = rescan(engine, files, &status)?;
+    let current = baseline::snapshot(&report);
+    print_delta(changed, &Delta::between(stable, &current), &report);
+    Ok(current)
+}
+
+fn rescan(engine: &Engine, files: &[PathBuf], status: &str) -> Result<ScanReport> {
+    // Read first, so an unreadable baseline is reported without a spinner
+    // finishing as if the scan had passed.
+    let accepted = baseline::load(&get_repo_root())?;
+    let (client, mut controller) = spinner::start("slopchop watch");
+    client.set_micro_status(status);
+    let mut report = engine.scan_with_progress(files, &|_| client.tick(), &|status| {
+        client.set_micro_status(status);
+    });
+    if let Some(accepted) = &accepted {
+        baseline::apply(accepted, &mut report);
+    }
+    controller.stop(!report.has_errors());
+    Ok(report)
+}
+
+fn print_delta(changed: &BTreeSet<PathBuf>, delta: &Delta, report: &ScanReport) {
+    let names: Vec<String> = changed.iter().map(|p| p.display().to_string()).collect();
+    println!("{} {}", "Changed:".dimmed(), names.join(", "));
+    for entry in &delta.introduced {
+        print_entry(&"+".red().bold(), entry);
+    }
+    for entry in &delta.fixed {
+        print_entry(&"-".green().bold(), entry);
+    }
+    let total = format!("{} violations", report.total_violations);
+    println!(
+        "{} introduced, {} fixed │ {}",
+        delta.introduced.len(),
+        delta.fixed.len(),
+        if report.has_errors() {
+            total.red().bold()
+        } else {
+            total.green().bold()
+        }
+    );
+}
+
+fn print_entry(mark: &colored::ColoredString, entry: &BaselineEntry) {
+    println!(
+        "  {mark} {}:{} {} {}",
+        entry.path,
+        entry.row,
+        entry.law.bold(),
+        entry.message
+    );
+}
+
+/// Runs the `[commands].check` entries and prints the scorecard.
+fn run_check() {
+    let (client, mut controller) = spinner::start("slopchop watch --check");
+    let report = verification::run(&get_repo_root(), |cmd, current, total| {
+        client.step_micro_progress(current, total, format!("Running: {cmd}"));
+        client.push_log(cmd);
+    });
+    controller.stop(report.passed);
+    check_report::print(&report);
+}
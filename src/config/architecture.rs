@@ -0,0 +1,204 @@
+// src/config/architecture.rs
+//! Declared architecture (`[architecture]`).
+//!
+//! Layers are listed from the top down. A layer may import its own files
+//! and any layer declared after it, or only the layers named in `allow`.
+//! Files outside every layer are unconstrained. `[[architecture.forbid]]`
+//! rules ban specific dependencies regardless of layers.
+
+use std::path::Path;
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use super::glob;
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ArchitectureConfig {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub layers: Vec<LayerConfig>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub forbid: Vec<ForbidConfig>,
+}
+
+impl ArchitectureConfig {
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.layers.is_empty() && self.forbid.is_empty()
+    }
+}
+
+/// One `[[architecture.layers]]` entry.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LayerConfig {
+    pub name: String,
+    pub paths: Vec<String>,
+    /// Layers this one may import. Unset means every layer below it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow: Option<Vec<String>>,
+}
+
+/// One `forbid` entry: files matching `from` may not import `to`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ForbidConfig {
+    pub from: String,
+    pub to: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+/// A dependency the declared architecture does not allow.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Breach {
+    /// `from` is in a layer that may not import the layer of `to`.
+    Layer { from: String, to: String },
+    /// A `forbid` rule matched; holds its index in `forbid`.
+    Forbidden(usize),
+}
+
+/// `[architecture]` with its path globs compiled.
+#[derive(Debug, Clone, Default)]
+pub struct Architecture {
+    config: ArchitectureConfig,
+    layers: Vec<Vec<Regex>>,
+    allowed: Vec<Vec<usize>>,
+    forbid: Vec<(Regex, Regex)>,
+}
+
+impl Architecture {
+    /// Compiles the declared layers and rules. Invalid globs and unknown
+    /// layer names are skipped and described in the returned errors.
+    #[must_use]
+    pub fn compile(config: ArchitectureConfig) -> (Self, Vec<String>) {
+        let mut errors = Vec::new();
+        let architecture = Self {
+            layers: compile_layers(&config.layers, &mut errors),
+            allowed: allowed_layers(&config.layers, &mut errors),
+            forbid: compile_forbid(&config.forbid, &mut errors),
+            config,
+        };
+        (architecture, errors)
+    }
+
+    /// The configuration as written in `slopchop.toml`.
+    #[must_use]
+    pub fn config(&self) -> &ArchitectureConfig {
+        &self.config
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.config.is_empty()
+    }
+
+    /// The `forbid` entry a [`Breach::Forbidden`] refers to.
+    #[must_use]
+    pub fn forbid_rule(&self, index: usize) -> Option<&ForbidConfig> {
+        self.config.forbid.get(index)
+    }
+
+    /// Whether both ends of an import lie in declared layers, so that the
+    /// layer rules alone decide it.
+    #[must_use]
+    pub fn covers(&self, from: &Path, to: &Path) -> bool {
+        let (from, to) = (glob::normalize(from), glob::normalize(to));
+        layer_of(&self.layers, &from).is_some() && layer_of(&self.layers, &to).is_some()
+    }
+
+    /// Checks one import of `to` by `from`, both relative to the root.
+    /// Forbid rules are checked before layers.
+    #[must_use]
+    pub fn check(&self, from: &Path, to: &Path) -> Option<Breach> {
+        let (from, to) = (glob::normalize(from), glob::normalize(to));
+        if let Some(index) = forbidden(&self.forbid, &from, &to) {
+            return Some(Breach::Forbidden(index));
+        }
+        let (source, target) = (layer_of(&self.layers, &from)?, layer_of(&self.layers, &to)?);
+        if source == target || self.allowed.get(source)?.contains(&target) {
+            return None;
+        }
+        Some(Breach::Layer {
+            from: layer_name(&self.config.layers, source)?,
+            to: layer_name(&self.config.layers, target)?,
+        })
+    }
+}
+
+/// Index of the first declared layer containing `path`.
+fn layer_of(layers: &[Vec<Regex>], path: &str) -> Option<usize> {
+    layers
+        .iter()
+        .position(|globs| globs.iter().any(|re| re.is_match(path)))
+}
+
+fn layer_name(layers: &[LayerConfig], index: usize) -> Option<String> {
+    layers.get(index).map(|layer| layer.name.clone())
+}
+
+/// Index of the first `forbid` rule banning `from` → `to`.
+fn forbidden(rules: &[(Regex, Regex)], from: &str, to: &str) -> Option<usize> {
+    rules
+        .iter()
+        .position(|(f, t)| f.is_match(from) && t.is_match(to))
+}
+
+fn compile_layers(layers: &[LayerConfig], errors: &mut Vec<String>) -> Vec<Vec<Regex>> {
+    layers
+        .iter()
+        .map(|layer| {
+            layer
+                .paths
+                .iter()
+                .filter_map(|g| compile(g, errors))
+                .collect()
+        })
+        .collect()
+}
+
+fn compile_forbid(rules: &[ForbidConfig], errors: &mut Vec<String>) -> Vec<(Regex, Regex)> {
+    rules
+        .iter()
+        .filter_map(|rule| Some((compile(&rule.from, errors)?, compile(&rule.to, errors)?)))
+        .collect()
+}
+
+fn compile(g: &str, errors: &mut Vec<String>) -> Option<Regex> {
+    let regex = glob::to_regex(g);
+    if regex.is_none() {
+        errors.push(format!("architecture: invalid path glob `{g}`"));
+    }
+    regex
+}
+
+/// Per layer, the indices of the layers it may import.
+fn allowed_layers(layers: &[LayerConfig], errors: &mut Vec<String>) -> Vec<Vec<usize>> {
+    layers
+        .iter()
+        .enumerate()
+        .map(|(i, layer)| match &layer.allow {
+            None => (i + 1..layers.len()).collect(),
+            Some(names) => names
+                .iter()
+                .filter_map(|name| allowed_index(layers, &layer.name, name, errors))
+                .collect(),
+        })
+        .collect()
+}
+
+fn allowed_index(
+    layers: &[LayerConfig],
+    layer: &str,
+    allowed: &str,
+    errors: &mut Vec<String>,
+) -> Option<usize> {
+    let index = layers.iter().position(|l| l.name == allowed);
+    if index.is_none() {
+        errors.push(format!(
+            "architecture: layer `{layer}` allows unknown layer `{allowed}`"
+        ));
+    }
+    index
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,57 @@
+// src/config/architecture/tests.rs
+//! Declared layer and forbid rule tests.
+
+use super::*;
+
+fn layer(name: &str, path: &str, allow: Option<&[&str]>) -> LayerConfig {
+    LayerConfig {
+        name: name.into(),
+        paths: vec![path.into()],
+        allow: allow.map(|names| names.iter().map(|n| (*n).to_string()).collect()),
+    }
+}
+
+#[test]
+fn test_layers_flow_downward_and_forbid_rules_win() {
+    let config = ArchitectureConfig {
+        layers: vec![
+            layer("ui", "src/ui/**", Some(&["app"])),
+            layer("app", "src/app/**", None),
+            layer("domain", "src/domain/**", None),
+            layer("db", "src/db/**", None),
+        ],
+        forbid: vec![ForbidConfig {
+            from: "src/app/**".into(),
+            to: "src/db/raw.rs".into(),
+            reason: None,
+        }],
+    };
+    let (arch, errors) = Architecture::compile(config);
+    assert!(errors.is_empty());
+    let check = |from: &str, to: &str| arch.check(Path::new(from), Path::new(to));
+
+    assert_eq!(check("src/ui/view.rs", "src/app/service.rs"), None);
+    assert_eq!(check("src/app/service.rs", "src/db/pool.rs"), None);
+    assert_eq!(check("src/main.rs", "src/db/pool.rs"), None);
+    let layer_breach = |from: &str, to: &str| {
+        Some(Breach::Layer {
+            from: from.into(),
+            to: to.into(),
+        })
+    };
+    let upward = check("src/domain/user.rs", "src/app/service.rs");
+    assert_eq!(upward, layer_breach("domain", "app"));
+    assert_eq!(
+        check("src/ui/view.rs", "src/db/pool.rs"),
+        layer_breach("ui", "db")
+    );
+    let forbidden = check("src/app/service.rs", "src/db/raw.rs");
+    assert_eq!(forbidden, Some(Breach::Forbidden(0)));
+
+    let unknown = layer("ui", "src/ui/**", Some(&["services"]));
+    let (_, errors) = Architecture::compile(ArchitectureConfig {
+        layers: vec![unknown],
+        forbid: Vec::new(),
+    });
+    assert_eq!(errors.len(), 1);
+}
@@ -0,0 +1,26 @@
+// src/config/custom.rs
+//! Configuration for custom rules declared in `[[rules.custom]]`.
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::Severity;
+
+/// A custom rule: a tree-sitter query whose matches become violations.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomRuleConfig {
+    pub id: String,
+    /// `rust`, `python`, `typescript` or `nim`.
+    pub language: String,
+    /// The `@match` capture (or the first capture) marks the reported line.
+    pub query: String,
+    pub message: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suggestion: Option<String>,
+    #[serde(default)]
+    pub severity: Severity,
+    /// Path globs the rule is limited to; empty means every file.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub include: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exclude: Vec<String>,
+}
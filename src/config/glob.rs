@@ -0,0 +1,45 @@
+// src/config/glob.rs
+//! Path globs used by `[path_profiles]` and custom rule filters.
+
+use std::path::Path;
+
+use regex::Regex;
+
+const REGEX_META: &str = "\\.+()[]{}|^$#&-~";
+
+/// Translates `*`, `**` and `?` globs into an anchored regex.
+/// A glob without wildcards also matches everything beneath it.
+#[must_use]
+pub fn to_regex(glob: &str) -> Option<Regex> {
+    let glob = glob.trim_start_matches("./").trim_end_matches('/');
+    let mut pattern = String::from("^");
+    let mut chars = glob.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '*' if chars.next_if_eq(&'*').is_some() => {
+                let _ = chars.next_if_eq(&'/');
+                pattern.push_str("(?:.*/)?");
+                if chars.peek().is_none() {
+                    pattern.push_str(".*");
+                }
+            }
+            '*' => pattern.push_str("[^/]*"),
+            '?' => pattern.push_str("[^/]"),
+            _ => {
+                if REGEX_META.contains(c) {
+                    pattern.push('\\');
+                }
+                pattern.push(c);
+            }
+        }
+    }
+    pattern.push_str("(?:/.*)?$");
+    Regex::new(&pattern).ok()
+}
+
+/// Forward-slash path without a leading `./`, as globs expect.
+#[must_use]
+pub fn normalize(path: &Path) -> String {
+    let path = path.to_string_lossy().replace('\\', "/");
+    path.trim_start_matches("./").to_string()
+}
@@ -1,14 +1,16 @@
 // src/config/io.rs
+use super::architecture::{Architecture, ArchitectureConfig};
+use super::profiles::ProfileSettings;
 use super::types::{CommandEntry, Config, Preferences, RuleConfig, SlopChopToml};
+use crate::analysis::custom::CustomRules;
 use crate::project::{self, ProjectType};
 use anyhow::{anyhow, Result};
 use regex::Regex;
 use std::collections::HashMap;
 use std::fs;
-use std::path::Path;
 
 pub fn load_ignore_file(config: &mut Config) {
-    let Ok(content) = fs::read_to_string(".slopchopignore") else {
+    let Ok(content) = fs::read_to_string(config.root.join(".slopchopignore")) else {
         return;
     };
     for line in content.lines() {
@@ -27,18 +29,23 @@ pub fn process_ignore_line(config: &mut Config, line: &str) {
 }
 
 pub fn load_toml_config(config: &mut Config) {
-    if !Path::new("slopchop.toml").exists() {
+    let path = config.root.join("slopchop.toml");
+    if !path.exists() {
         return;
     }
-    let Ok(content) = fs::read_to_string("slopchop.toml") else {
+    let Ok(content) = fs::read_to_string(path) else {
         return;
     };
     parse_toml(config, &content);
 }
 
 pub fn parse_toml(config: &mut Config, content: &str) {
-    let Ok(parsed) = toml::from_str::<SlopChopToml>(content) else {
-        return;
+    let parsed = match toml::from_str::<SlopChopToml>(content) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            config.load_errors.push(format!("slopchop.toml: {e}"));
+            return;
+        }
     };
     config.rules = parsed.rules;
     config.preferences = parsed.preferences;
@@ -47,13 +54,23 @@ pub fn parse_toml(config: &mut Config, content: &str) {
         .into_iter()
         .map(|(k, v)| (k, v.into_vec()))
         .collect();
+    let (profile_settings, errors) =
+        ProfileSettings::new(parsed.profile, parsed.profiles, parsed.path_profiles);
+    config.profile_settings = profile_settings;
+    config.load_errors.extend(errors);
+    let (custom_rules, errors) = CustomRules::compile(&config.rules, &config.profile_settings);
+    config.custom_rules = custom_rules;
+    config.load_errors.extend(errors);
+    let (architecture, errors) = Architecture::compile(parsed.architecture);
+    config.architecture = architecture;
+    config.load_errors.extend(errors);
 }
 
 pub fn apply_project_defaults(config: &mut Config) {
     if config.commands.contains_key("check") {
         return;
     }
-    let defaults = project_defaults(ProjectType::detect());
+    let defaults = project_defaults(ProjectType::detect_in(&config.root));
     for (k, v) in defaults {
         config.commands.entry(k).or_insert(v);
     }
@@ -68,6 +85,8 @@ pub fn save_to_file(
     rules: &RuleConfig,
     prefs: &Preferences,
     commands: &HashMap<String, Vec<String>>,
+    profiles: &ProfileSettings,
+    architecture: &ArchitectureConfig,
 ) -> Result<()> {
     let cmd_entries: HashMap<String, CommandEntry> = commands
         .iter()
@@ -75,9 +94,13 @@ pub fn save_to_file(
         .collect();
 
     let toml_struct = SlopChopToml {
+        profile: profiles.default_profile().cloned(),
         rules: rules.clone(),
         preferences: prefs.clone(),
         commands: cmd_entries,
+        profiles: profiles.profiles().clone(),
+        path_profiles: profiles.path_profiles().clone(),
+        architecture: architecture.clone(),
     };
 
     let content = toml::to_string_pretty(&toml_struct)
@@ -4,7 +4,9 @@
 use serde::{Deserialize, Serialize};
 
 
-use crate::graph::locality::{ClassifierConfig, ValidatorConfig};
+use crate::graph::locality::{ClassifierConfig, ValidatorConfig, ZoneConfig};
+
+use super::architecture::Architecture;
 
 /// Locality rules configuration from slopchop.toml.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -22,6 +24,13 @@ pub struct LocalityConfig {
     pub god_module_threshold: usize,
     /// Cₐ + Cₑ below this = Deadwood. Default: 2
     pub deadwood_threshold: usize,
+    /// Module distance from the main sequence, |A + I − 1|, above which
+    /// it is in the zone of pain or uselessness. Default: 0.7
+    pub max_module_distance: f64,
+    /// Modules with fewer type definitions are not judged. Default: 3
+    pub min_module_types: usize,
+    /// Modules with a smaller Cₐ + Cₑ are not judged. Default: 5
+    pub min_module_coupling: usize,
     /// Enforcement mode: "error", "warn", or "off"
     pub mode: String,
     /// Glob patterns to exempt from checks
@@ -38,6 +47,9 @@ impl Default for LocalityConfig {
             min_hub_afferent: 5,
             god_module_threshold: 20,
             deadwood_threshold: 2,
+            max_module_distance: 0.7,
+            min_module_types: 3,
+            min_module_coupling: 5,
             mode: "warn".to_string(),
             exempt_patterns: Vec::new(),
         }
@@ -59,6 +71,17 @@ impl LocalityConfig {
                 volatile_leaf_efferent: 5,
             },
             exempt_patterns: self.exempt_patterns.clone(),
+            declared: Architecture::default(),
+        }
+    }
+
+    /// Converts to the module zone thresholds.
+    #[must_use]
+    pub fn to_zone_config(&self) -> ZoneConfig {
+        ZoneConfig {
+            max_distance: self.max_module_distance,
+            min_types: self.min_module_types,
+            min_coupling: self.min_module_coupling,
         }
     }
 
@@ -1,13 +1,21 @@
 // src/config/mod.rs
+pub mod architecture;
+pub mod custom;
 pub mod io;
+pub mod glob;
 pub mod locality;
+pub mod profiles;
 pub mod types;
 
+pub use self::architecture::{Architecture, ArchitectureConfig};
 pub use self::locality::LocalityConfig;
+pub use self::profiles::ProfileSettings;
 pub use self::types::{
     CommandEntry, Config, Preferences, RuleConfig, SlopChopToml,
 };
+use crate::graph::locality::ValidatorConfig;
 use anyhow::Result;
+use std::path::Path;
 
 impl Config {
     #[must_use]
@@ -18,7 +26,17 @@ impl Config {
     /// Creates a new config and loads local settings (`slopchop.toml`, `.slopchopignore`).
     #[must_use]
     pub fn load() -> Self {
-        let mut config = Self::new();
+        Self::load_in(Path::new(""))
+    }
+
+    /// Like [`Config::load`], for the project at `root` rather than the
+    /// working directory.
+    #[must_use]
+    pub fn load_in(root: &Path) -> Self {
+        let mut config = Self {
+            root: root.to_path_buf(),
+            ..Self::new()
+        };
         config.load_local_config();
         config
     }
@@ -26,8 +44,12 @@ impl Config {
     /// Validates configuration.
     ///
     /// # Errors
-    /// Returns Ok if validation passes.
+    /// Returns error if `slopchop.toml` failed to parse or declares invalid
+    /// custom rules.
     pub fn validate(&self) -> Result<()> {
+        if !self.load_errors.is_empty() {
+            anyhow::bail!("Invalid configuration:\n  {}", self.load_errors.join("\n  "));
+        }
         // Touch all fields for LCOM4 cohesion
         let _ = &self.rules;
         let _ = &self.preferences;
@@ -75,7 +97,29 @@ impl Config {
         let _ = &self.exclude_patterns;
         let _ = self.verbose;
         let _ = self.code_only;
-        io::save_to_file(&self.rules, &self.preferences, &self.commands)
+        io::save_to_file(
+            &self.rules,
+            &self.preferences,
+            &self.commands,
+            &self.profile_settings,
+            self.architecture.config(),
+        )
+    }
+
+    /// Locality validator settings. Edges between declared `[architecture]`
+    /// layers are left to the architecture check.
+    #[must_use]
+    pub fn validator_config(&self) -> ValidatorConfig {
+        ValidatorConfig {
+            declared: self.architecture.clone(),
+            ..self.rules.locality.to_validator_config()
+        }
+    }
+
+    /// Resolves the profile judging `path` and its rule set.
+    #[must_use]
+    pub fn rules_for(&self, path: &Path) -> (String, RuleConfig) {
+        self.profile_settings.rules_for(&self.rules, path)
     }
 }
 
@@ -92,6 +136,8 @@ pub fn save_to_file(
     rules: &RuleConfig,
     prefs: &Preferences,
     commands: &std::collections::HashMap<String, Vec<String>>,
+    profiles: &ProfileSettings,
+    architecture: &ArchitectureConfig,
 ) -> Result<()> {
-    io::save_to_file(rules, prefs, commands)
+    io::save_to_file(rules, prefs, commands, profiles, architecture)
 }
\ No newline at end of file
@@ -0,0 +1,174 @@
+// src/config/profiles.rs
+//! Governance profiles.
+//!
+//! A profile is a complete rule set. `[rules]` is the built-in
+//! `application` profile; `systems` is derived from it with relaxed
+//! structural limits and escalated safety. `[profiles.<name>]` tables
+//! define or replace profiles, `profile = "..."` picks the project
+//! default and `[path_profiles]` picks one per path glob. Naming a
+//! profile that does not exist is a configuration error.
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+
+use regex::Regex;
+
+use super::glob;
+use super::types::{RuleConfig, RuleLevel};
+
+pub const APPLICATION: &str = "application";
+pub const SYSTEMS: &str = "systems";
+
+/// The law `systems` escalates.
+const PARANOIA: &str = "LAW OF PARANOIA";
+
+/// Profile settings from `slopchop.toml`, with path globs compiled.
+#[derive(Debug, Clone, Default)]
+pub struct ProfileSettings {
+    /// Project-wide default profile (`profile = "..."`).
+    default: Option<String>,
+    /// User-defined profiles (`[profiles.<name>]`).
+    profiles: HashMap<String, RuleConfig>,
+    /// Path glob to profile name (`[path_profiles]`).
+    path_profiles: BTreeMap<String, String>,
+    /// Compiled globs, most specific (longest) first.
+    compiled: Vec<(Regex, String)>,
+}
+
+impl ProfileSettings {
+    /// Compiles the path globs. Unknown profile names are described in
+    /// the returned errors.
+    #[must_use]
+    pub fn new(
+        default: Option<String>,
+        profiles: HashMap<String, RuleConfig>,
+        path_profiles: BTreeMap<String, String>,
+    ) -> (Self, Vec<String>) {
+        let settings = Self {
+            compiled: compile_globs(&path_profiles),
+            default,
+            profiles,
+            path_profiles,
+        };
+        let default = settings.default.iter().map(|name| ("profile", name));
+        let by_path = settings.path_profiles.values();
+        let named = default.chain(by_path.map(|name| ("path_profiles", name)));
+        let errors = named
+            .filter(|(_, name)| !settings.is_known(name))
+            .map(|(key, name)| format!("{key}: unknown profile `{name}`"))
+            .collect();
+        (settings, errors)
+    }
+
+    /// Whether `name` is a built-in or `[profiles.<name>]` profile.
+    #[must_use]
+    pub fn is_known(&self, name: &str) -> bool {
+        name == APPLICATION || name == SYSTEMS || self.profiles.contains_key(name)
+    }
+
+    #[must_use]
+    pub fn default_profile(&self) -> Option<&String> {
+        self.default.as_ref()
+    }
+
+    #[must_use]
+    pub fn profiles(&self) -> &HashMap<String, RuleConfig> {
+        &self.profiles
+    }
+
+    #[must_use]
+    pub fn path_profiles(&self) -> &BTreeMap<String, String> {
+        &self.path_profiles
+    }
+
+    /// Name of the profile that judges `path`: the most specific matching
+    /// glob, then the project default, then `application`.
+    #[must_use]
+    pub fn profile_for(&self, path: &Path) -> &str {
+        let normalized = glob::normalize(path);
+        self.compiled
+            .iter()
+            .find(|(pattern, _)| pattern.is_match(&normalized))
+            .map(|(_, profile)| profile.as_str())
+            .or(self.default.as_deref())
+            .unwrap_or(APPLICATION)
+    }
+
+    /// Rule set for a profile name. Unknown names yield `None`.
+    #[must_use]
+    pub fn rules_named(&self, base: &RuleConfig, name: &str) -> Option<RuleConfig> {
+        if let Some(rules) = self.profiles.get(name) {
+            return Some(rules.clone());
+        }
+        match name {
+            APPLICATION => Some(base.clone()),
+            SYSTEMS => Some(systems_rules(base)),
+            _ => None,
+        }
+    }
+
+    /// Resolves the profile judging `path` and its rule set.
+    /// Unknown profile names, which loading reports as errors, fall back
+    /// to `base`.
+    #[must_use]
+    pub fn rules_for(&self, base: &RuleConfig, path: &Path) -> (String, RuleConfig) {
+        let name = self.profile_for(path);
+        let rules = self.rules_named(base, name).unwrap_or_else(|| base.clone());
+        (name.to_string(), rules)
+    }
+}
+
+/// The built-in systems profile: structural limits relaxed, structural
+/// metrics effectively off, and safety escalated: every `unsafe` must
+/// carry a SAFETY comment and `LAW OF PARANOIA` is an error, whatever
+/// `[rules]` relaxes.
+#[must_use]
+pub fn systems_rules(base: &RuleConfig) -> RuleConfig {
+    let mut rules = base.clone();
+    rules.max_file_tokens = 10_000;
+    rules.max_cognitive_complexity = 50;
+    rules.max_lcom4 = 100;
+    rules.max_cbo = 100;
+    rules.max_sfout = 100;
+    rules.min_ahf = 0.0;
+    rules.safety.require_safety_comment = true;
+    // Severity keys are canonical once loaded, so this replaces any
+    // override `[rules]` set.
+    rules
+        .severity
+        .insert(PARANOIA.to_string(), RuleLevel::Error);
+    rules
+}
+
+/// Suggests the systems profile for low-level code. Never applied
+/// automatically; the report surfaces it as a hint.
+#[must_use]
+pub fn suggest(source: &str) -> Option<&'static str> {
+    const SIGNALS: &[(&[&str], usize)] = &[
+        (&["#![no_std]"], 5),
+        (&["unsafe {", "unsafe fn"], 1),
+        (&["transmute"], 2),
+        (&["repr(C)", "repr(packed)"], 2),
+        (&["Atomic"], 1),
+        (&["*const", "*mut"], 1),
+        (&["Pin<Box"], 1),
+    ];
+    let score: usize = SIGNALS
+        .iter()
+        .filter(|(needles, _)| needles.iter().any(|n| source.contains(n)))
+        .map(|(_, weight)| weight)
+        .sum();
+    (score >= 3).then_some(SYSTEMS)
+}
+
+fn compile_globs(path_profiles: &BTreeMap<String, String>) -> Vec<(Regex, String)> {
+    let mut globs: Vec<(&String, &String)> = path_profiles.iter().collect();
+    globs.sort_by_key(|(pattern, _)| std::cmp::Reverse(pattern.len()));
+    globs
+        .into_iter()
+        .filter_map(|(pattern, profile)| Some((glob::to_regex(pattern)?, profile.clone())))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests;
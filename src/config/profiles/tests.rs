@@ -0,0 +1,66 @@
+// src/config/profiles/tests.rs
+//! Profile resolution and systems profile tests.
+
+use super::*;
+
+#[test]
+fn test_most_specific_glob_wins() {
+    let mut globs = BTreeMap::new();
+    globs.insert("src/core/**".to_string(), SYSTEMS.to_string());
+    globs.insert("src/core/api.rs".to_string(), "strict".to_string());
+    globs.insert("ffi".to_string(), SYSTEMS.to_string());
+    let (settings, errors) = ProfileSettings::new(None, HashMap::new(), globs);
+    assert_eq!(errors, ["path_profiles: unknown profile `strict`"]);
+    let of = |p: &str| settings.profile_for(Path::new(p)).to_string();
+
+    assert_eq!(of("src/core/alloc/pool.rs"), SYSTEMS);
+    assert_eq!(of("./src/core/api.rs"), "strict");
+    assert_eq!(of("ffi/bindings.rs"), SYSTEMS);
+    assert_eq!(of("src/api/routes.rs"), APPLICATION);
+}
+
+#[test]
+fn test_builtin_and_custom_profiles() {
+    let strict = RuleConfig {
+        max_file_tokens: 500,
+        ..RuleConfig::default()
+    };
+    let mut profiles = HashMap::new();
+    profiles.insert("strict".to_string(), strict);
+    let (settings, errors) = ProfileSettings::new(Some(SYSTEMS.into()), profiles, BTreeMap::new());
+    assert!(errors.is_empty());
+    let base = RuleConfig::default();
+
+    let (name, rules) = settings.rules_for(&base, Path::new("src/lib.rs"));
+    assert_eq!((name.as_str(), rules.max_file_tokens), (SYSTEMS, 10_000));
+    let custom = settings
+        .rules_named(&base, "strict")
+        .map(|r| r.max_file_tokens);
+    assert_eq!(custom, Some(500));
+    assert!(settings.rules_named(&base, "unknown").is_none());
+
+    let (_, errors) = ProfileSettings::new(Some("sytems".into()), HashMap::new(), BTreeMap::new());
+    assert_eq!(errors, ["profile: unknown profile `sytems`"]);
+}
+
+#[test]
+fn test_systems_escalates_safety_over_relaxed_rules() {
+    let base: RuleConfig = toml::from_str(
+        "[safety]\nrequire_safety_comment = false\n[severity]\n\"law of paranoia\" = \"warn\"\n",
+    )
+    .unwrap();
+    assert_eq!(base.severity.get(PARANOIA), Some(&RuleLevel::Warn));
+
+    let systems = systems_rules(&base);
+    assert_eq!(systems.severity.len(), 1);
+    assert!(systems.safety.require_safety_comment);
+    let level =
+        crate::rules::effective_level(PARANOIA, &systems.severity, crate::types::Severity::Warning);
+    assert_eq!(level, RuleLevel::Error);
+}
+
+#[test]
+fn test_heuristic_only_suggests() {
+    assert_eq!(suggest("unsafe { transmute(x) }"), Some(SYSTEMS));
+    assert_eq!(suggest("fn main() {}"), None);
+}
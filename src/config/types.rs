@@ -1,5 +1,12 @@
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+
+use super::architecture::{Architecture, ArchitectureConfig};
+use super::profiles::ProfileSettings;
+use crate::analysis::custom::CustomRules;
+use crate::types::Severity;
+
+pub use super::custom::CustomRuleConfig;
 
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -20,6 +27,8 @@ pub struct Preferences {
     pub write_fix_packet: bool,
     #[serde(default = "default_fix_packet_path")]
     pub fix_packet_path: String,
+    #[serde(default = "default_fix_packet_max_tokens")]
+    pub fix_packet_max_tokens: usize,
     #[serde(default)]
     pub auto_promote: bool,
 }
@@ -35,6 +44,7 @@ impl Default for Preferences {
             require_plan: false,
             write_fix_packet: false,
             fix_packet_path: default_fix_packet_path(),
+            fix_packet_max_tokens: default_fix_packet_max_tokens(),
             auto_promote: false,
         }
     }
@@ -52,6 +62,9 @@ fn default_backup_retention() -> usize {
 fn default_fix_packet_path() -> String {
     "slopchop-fixme.txt".to_string()
 }
+fn default_fix_packet_max_tokens() -> usize {
+    8000
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RuleConfig {
@@ -87,6 +100,17 @@ pub struct RuleConfig {
     pub safety: SafetyConfig,
     #[serde(default)]
     pub locality: super::locality::LocalityConfig,
+    /// Per-rule level overrides (`[rules.severity]`), keyed by canonical
+    /// rule id.
+    #[serde(
+        default,
+        deserialize_with = "crate::rules::deserialize_levels",
+        skip_serializing_if = "BTreeMap::is_empty"
+    )]
+    pub severity: BTreeMap<String, RuleLevel>,
+    /// House rules declared as tree-sitter queries (`[[rules.custom]]`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub custom: Vec<CustomRuleConfig>,
 }
 
 impl Default for RuleConfig {
@@ -107,6 +131,38 @@ impl Default for RuleConfig {
             ignore_tokens_on: default_ignore_tokens(),
             safety: SafetyConfig::default(),
             locality: super::locality::LocalityConfig::default(),
+            severity: BTreeMap::new(),
+            custom: Vec::new(),
+        }
+    }
+}
+
+/// Level a rule runs at. Only `error` fails a scan.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RuleLevel {
+    Off,
+    #[serde(alias = "warning")]
+    Warn,
+    Error,
+}
+
+impl RuleLevel {
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Off => "off",
+            Self::Warn => "warn",
+            Self::Error => "error",
+        }
+    }
+}
+
+impl From<Severity> for RuleLevel {
+    fn from(severity: Severity) -> Self {
+        match severity {
+            Severity::Warning => Self::Warn,
+            Severity::Error => Self::Error,
         }
     }
 }
@@ -191,16 +247,28 @@ impl CommandEntry {
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct SlopChopToml {
+    /// Default governance profile for the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<String>,
     #[serde(default)]
     pub rules: RuleConfig,
     #[serde(default)]
     pub preferences: Preferences,
     #[serde(default)]
     pub commands: HashMap<String, CommandEntry>,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub profiles: HashMap<String, RuleConfig>,
+    /// Path glob to profile name.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub path_profiles: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "ArchitectureConfig::is_empty")]
+    pub architecture: ArchitectureConfig,
 }
 
 #[derive(Debug, Clone, Default)]
 pub struct Config {
+    /// Project root; empty means the working directory.
+    pub root: std::path::PathBuf,
     pub include_patterns: Vec<regex::Regex>,
     pub exclude_patterns: Vec<regex::Regex>,
     pub code_only: bool,
@@ -208,4 +276,9 @@ pub struct Config {
     pub rules: RuleConfig,
     pub preferences: Preferences,
     pub commands: HashMap<String, Vec<String>>,
+    pub profile_settings: ProfileSettings,
+    pub custom_rules: CustomRules,
+    pub architecture: Architecture,
+    /// Problems found while loading `slopchop.toml`; see [`Config::validate`].
+    pub load_errors: Vec<String>,
 }
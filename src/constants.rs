@@ -18,6 +18,7 @@ pub const PRUNE_DIRS: &[&str] = &[
     "coverage",
     "vendor",
     ".slopchop_apply_backup",
+    ".slopchop",
 ];
 
 pub const PRUNE_FILES: &[&str] = &[
@@ -38,7 +39,7 @@ pub const BIN_EXT_PATTERN: &str =
 pub const SECRET_PATTERN: &str =
     r"(?i)(^\.?env(\..*)?$|/\.?env(\..*)?$|(^|/)(id_rsa|id_ed25519|.*\.(pem|p12|key|pfx))$)";
 
-pub const CODE_EXT_PATTERN: &str = r"(?i)\.(rs|go|py|js|jsx|ts|tsx|java|c|cpp|h|hpp|cs|php|rb|sh|sql|html|css|scss|json|toml|yaml|md)$";
+pub const CODE_EXT_PATTERN: &str = r"(?i)\.(rs|go|py|js|jsx|ts|tsx|java|c|cpp|h|hpp|cs|php|rb|sh|sql|html|css|scss|json|toml|yaml|md|nim|nims)$";
 
 pub const CODE_BARE_PATTERN: &str = r"(?i)(Makefile|Dockerfile|CMakeLists\.txt)$";
 
@@ -0,0 +1,194 @@
+// src/diff.rs
+//! Diff-aware scanning.
+//!
+//! `scan --since <ref>` and `scan --staged` report only files changed
+//! relative to a git ref or in the index. The whole project is still
+//! scanned, so deep analysis (LCOM4, CBO, SFOUT) sees every file; the
+//! report is narrowed afterwards. With `--changed-lines`, violations must
+//! also fall on an added or modified line. Rows always refer to the
+//! working tree.
+
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use anyhow::{bail, Context, Result};
+use serde::Serialize;
+
+use crate::types::{FileReport, ScanReport};
+
+/// What the scan is compared against.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiffBase {
+    /// Changes since a commit, branch or tag, including uncommitted and
+    /// untracked files.
+    Since(String),
+    /// Changes staged in the index.
+    Staged,
+}
+
+impl DiffBase {
+    fn describe(&self) -> String {
+        match self {
+            Self::Since(git_ref) => format!("since {git_ref}"),
+            Self::Staged => "staged".to_string(),
+        }
+    }
+}
+
+/// `scan` options narrowing the report to changed files.
+#[derive(Debug, Clone)]
+pub struct DiffFilter {
+    pub base: DiffBase,
+    /// Keep only violations on added or modified lines.
+    pub changed_lines: bool,
+}
+
+/// Records how a report was narrowed to changed files.
+#[derive(Debug, Clone, Serialize)]
+pub struct DiffOutcome {
+    /// `since <ref>` or `staged`.
+    pub base: String,
+    /// True when only violations on changed lines were kept.
+    pub changed_lines: bool,
+    /// Files scanned for deep analysis, before narrowing.
+    pub project_files: usize,
+}
+
+/// Changed files and, for each, the changed line ranges (1-based).
+#[derive(Debug, Default)]
+pub struct ChangedFiles {
+    hunks: HashMap<PathBuf, Vec<RangeInclusive<usize>>>,
+}
+
+const WHOLE_FILE: RangeInclusive<usize> = 1..=usize::MAX;
+
+impl ChangedFiles {
+    /// Asks git for the files changed relative to `base`. Paths are
+    /// relative to the current directory, matching discovery.
+    ///
+    /// # Errors
+    /// Returns error if git cannot be run or rejects the ref.
+    pub fn collect(base: &DiffBase) -> Result<Self> {
+        let mut args = vec![
+            "diff",
+            "--unified=0",
+            "--relative",
+            "--diff-filter=d",
+            "--no-ext-diff",
+            "--dst-prefix=b/",
+        ];
+        match base {
+            DiffBase::Since(git_ref) => args.extend([git_ref.as_str(), "--"]),
+            DiffBase::Staged => args.extend(["--cached", "--"]),
+        }
+        let mut changes = parse(&git(&args)?);
+        if matches!(base, DiffBase::Since(_)) {
+            let untracked = git(&["ls-files", "--others", "--exclude-standard"])?;
+            for path in untracked.lines().filter(|l| !l.is_empty()) {
+                changes.hunks.insert(PathBuf::from(path), vec![WHOLE_FILE]);
+            }
+        }
+        Ok(changes)
+    }
+
+    #[must_use]
+    pub fn contains(&self, path: &Path) -> bool {
+        self.hunks.contains_key(path)
+    }
+
+    /// Returns true if `row` of `path` was added or modified.
+    #[must_use]
+    pub fn touches(&self, path: &Path, row: usize) -> bool {
+        self.hunks
+            .get(path)
+            .is_some_and(|ranges| ranges.iter().any(|r| r.contains(&row)))
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.hunks.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.hunks.is_empty()
+    }
+}
+
+/// Drops files (and with `changed_lines`, violations) outside `changes`,
+/// then recomputes the totals. Baseline entries reported as fixed are
+/// narrowed the same way.
+pub fn narrow(report: &mut ScanReport, changes: &ChangedFiles, filter: &DiffFilter) {
+    let project_files = report.files.len();
+    report.files.retain(|f| changes.contains(&f.path));
+    if filter.changed_lines {
+        for file in &mut report.files {
+            let path = file.path.clone();
+            file.violations.retain(|v| changes.touches(&path, v.row));
+        }
+    }
+    if let Some(outcome) = &mut report.baseline {
+        outcome
+            .fixed
+            .retain(|e| changes.contains(Path::new(&e.path)));
+    }
+    report.total_violations = report.files.iter().map(FileReport::violation_count).sum();
+    report.total_tokens = report.files.iter().map(|f| f.token_count).sum();
+    report.diff = Some(DiffOutcome {
+        base: filter.base.describe(),
+        changed_lines: filter.changed_lines,
+        project_files,
+    });
+}
+
+fn git(args: &[&str]) -> Result<String> {
+    let output = Command::new("git")
+        .args(["-c", "core.quotePath=false"])
+        .args(args)
+        .output()
+        .context("Failed to run git")?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        bail!("git {} failed: {}", args.join(" "), stderr.trim());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Parses `git diff --unified=0` output into changed line ranges.
+fn parse(diff: &str) -> ChangedFiles {
+    let mut changes = ChangedFiles::default();
+    let mut current: Option<PathBuf> = None;
+    let mut prev = "";
+    for line in diff.lines() {
+        let header = prev.starts_with("--- ");
+        prev = line;
+        if let Some(path) = line.strip_prefix("+++ ").filter(|_| header) {
+            // Git ends the name with a tab when it contains a space.
+            let path = path.strip_suffix('\t').unwrap_or(path);
+            current = path.strip_prefix("b/").map(PathBuf::from);
+            if let Some(path) = &current {
+                changes.hunks.entry(path.clone()).or_default();
+            }
+        } else if let (Some(hunk), Some(path)) = (line.strip_prefix("@@ "), &current) {
+            if let Some(range) = new_range(hunk) {
+                changes.hunks.entry(path.clone()).or_default().push(range);
+            }
+        }
+    }
+    changes
+}
+
+/// Line range of the new side of a hunk header (`-a,b +c,d @@`); `None`
+/// for pure deletions.
+fn new_range(hunk: &str) -> Option<RangeInclusive<usize>> {
+    let new = hunk.split_whitespace().find_map(|p| p.strip_prefix('+'))?;
+    let (start, len) = new.split_once(',').unwrap_or((new, "1"));
+    let start: usize = start.parse().ok()?;
+    let len: usize = len.parse().ok()?;
+    (len > 0).then(|| start..=start + len - 1)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,80 @@
+// src/diff/tests.rs
+//! Diff parsing and report narrowing tests.
+
+use super::*;
+use crate::types::Violation;
+
+const DIFF: &str = "\
+diff --git a/src/lib.rs b/src/lib.rs
+index 1111111..2222222 100644
+--- a/src/lib.rs
++++ b/src/lib.rs
+@@ -3,0 +4,2 @@ fn a() {
++    let x = 1;
++    let y = 2;
+@@ -10 +12 @@ fn b() {
+-old
++new
+@@ -20,3 +21,0 @@ fn c() {
+diff --git a/src/new.rs b/src/new.rs
+new file mode 100644
+--- /dev/null
++++ b/src/new.rs
+@@ -0,0 +1,3 @@
++fn main() {}
+diff --git a/docs/my notes.md b/docs/my notes.md
+--- a/docs/my notes.md	
++++ b/docs/my notes.md	
+@@ -1 +1 @@
+-old
++new
+";
+
+#[test]
+fn test_parse_hunks() {
+    let changes = parse(DIFF);
+    let lib = Path::new("src/lib.rs");
+    assert_eq!(changes.len(), 3);
+    assert!(changes.touches(lib, 4) && changes.touches(lib, 5) && changes.touches(lib, 12));
+    assert!(!changes.touches(lib, 6) && !changes.touches(lib, 21));
+    assert!(changes.touches(Path::new("src/new.rs"), 3));
+    assert!(!changes.contains(Path::new("src/other.rs")));
+    assert!(changes.touches(Path::new("docs/my notes.md"), 1));
+}
+
+#[test]
+fn test_narrow_to_changed_lines() {
+    let file = |path: &str, rows: &[usize]| FileReport {
+        path: PathBuf::from(path),
+        token_count: 0,
+        complexity_score: 0,
+        violations: rows
+            .iter()
+            .map(|r| Violation::simple(*r, "x".into(), "P01"))
+            .collect(),
+        analysis: None,
+        suppressions: Vec::new(),
+        profile: "application".into(),
+        suggested_profile: None,
+    };
+    let mut report = ScanReport {
+        files: vec![file("src/lib.rs", &[1, 4, 12]), file("src/other.rs", &[1])],
+        ..ScanReport::default()
+    };
+    let filter = DiffFilter {
+        base: DiffBase::Staged,
+        changed_lines: true,
+    };
+    narrow(&mut report, &parse(DIFF), &filter);
+
+    assert_eq!(report.files.len(), 1);
+    let rows: Vec<usize> = report
+        .files
+        .iter()
+        .flat_map(|f| &f.violations)
+        .map(|v| v.row)
+        .collect();
+    assert_eq!(rows, vec![4, 12]);
+    assert_eq!(report.total_violations, 2);
+    assert_eq!(report.diff.map(|d| d.project_files), Some(2));
+}
@@ -11,31 +11,37 @@ use std::path::{Path, PathBuf};
 use std::sync::LazyLock;
 use walkdir::WalkDir;
 
-/// Runs the file discovery pipeline.
+/// Runs the file discovery pipeline over `config.root`. Paths are
+/// relative to the root.
 ///
 /// # Errors
 /// Returns error if regexes are invalid or file system walk fails.
 pub fn discover(config: &Config) -> Result<Vec<PathBuf>> {
-    let raw_files = walk_filesystem(config.verbose);
-    let heuristic_files = filter_heuristics(raw_files);
+    let root = if config.root.as_os_str().is_empty() {
+        Path::new(".")
+    } else {
+        config.root.as_path()
+    };
+    let raw_files = walk_filesystem(root, config.verbose);
+    let heuristic_files = filter_heuristics(root, raw_files);
     let final_files = filter_config(heuristic_files, config);
     Ok(final_files)
 }
 
-fn walk_filesystem(verbose: bool) -> Vec<PathBuf> {
-    let walker = WalkDir::new(".")
+fn walk_filesystem(root: &Path, verbose: bool) -> Vec<PathBuf> {
+    let walker = WalkDir::new(root)
         .follow_links(false)
         .into_iter()
         .filter_entry(|e| !should_prune(&e.file_name().to_string_lossy()));
 
-    let (paths, error_count) = accumulate_walker(walker);
+    let (paths, error_count) = accumulate_walker(root, walker);
     if error_count > 0 && verbose {
         eprintln!("WARN: Encountered {error_count} errors during file walk");
     }
     paths
 }
 
-fn accumulate_walker<I>(walker: I) -> (Vec<PathBuf>, usize)
+fn accumulate_walker<I>(root: &Path, walker: I) -> (Vec<PathBuf>, usize)
 where
     I: Iterator<Item = walkdir::Result<walkdir::DirEntry>>,
 {
@@ -45,7 +51,7 @@ where
         match item {
             Ok(entry) => {
                 if entry.file_type().is_file() {
-                    let p = entry.path().strip_prefix(".").unwrap_or(entry.path());
+                    let p = entry.path().strip_prefix(root).unwrap_or(entry.path());
                     paths.push(p.to_path_buf());
                 }
             }
@@ -64,11 +70,14 @@ static CODE_EXT_RE: LazyLock<Regex> =
 static CODE_BARE_RE: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(CODE_BARE_PATTERN).unwrap_or_else(|_| panic!("Invalid Regex")));
 
-fn filter_heuristics(paths: Vec<PathBuf>) -> Vec<PathBuf> {
-    paths.into_iter().filter(is_code_like).collect()
+fn filter_heuristics(root: &Path, paths: Vec<PathBuf>) -> Vec<PathBuf> {
+    paths
+        .into_iter()
+        .filter(|path| is_code_like(root, path))
+        .collect()
 }
 
-fn is_code_like(path: &PathBuf) -> bool {
+fn is_code_like(root: &Path, path: &Path) -> bool {
     let filename = path.file_name().map_or("", |f| f.to_str().unwrap_or(""));
 
     if BIN_RE.is_match(filename) {
@@ -84,7 +93,7 @@ fn is_code_like(path: &PathBuf) -> bool {
         return true;
     }
 
-    if let Ok(content) = fs::read_to_string(path) {
+    if let Ok(content) = fs::read_to_string(root.join(path)) {
         if content.starts_with("#!") {
             return true;
         }
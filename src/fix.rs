@@ -0,0 +1,164 @@
+// src/fix.rs
+//! Applies the structured fixes attached to violations.
+//!
+//! Fixes are grouped per file and applied back to front so byte offsets
+//! stay valid. A fix that overlaps one already taken is skipped; the
+//! rescan after `slopchop fix` reports it again and the next run picks it
+//! up.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use similar::TextDiff;
+
+use crate::types::{Edit, Fix, ScanReport};
+
+/// The fixed contents of one file.
+#[derive(Debug)]
+pub struct FilePatch {
+    pub path: PathBuf,
+    pub original: String,
+    pub patched: String,
+    /// Descriptions of the fixes applied, in file order.
+    pub applied: Vec<String>,
+}
+
+impl FilePatch {
+    /// Unified diff from the original to the patched contents.
+    #[must_use]
+    pub fn diff(&self) -> String {
+        let path = self.path.display();
+        TextDiff::from_lines(&self.original, &self.patched)
+            .unified_diff()
+            .header(&format!("a/{path}"), &format!("b/{path}"))
+            .to_string()
+    }
+
+    /// Writes the patched contents back to disk.
+    ///
+    /// # Errors
+    /// Returns error if the file cannot be written.
+    pub fn write(&self) -> Result<()> {
+        fs::write(&self.path, &self.patched)
+            .with_context(|| format!("Failed to write {}", self.path.display()))
+    }
+}
+
+/// Builds a patch for every file in `report` with fixable violations.
+///
+/// # Errors
+/// Returns error if a file with fixes cannot be read.
+pub fn plan(report: &ScanReport) -> Result<Vec<FilePatch>> {
+    let mut patches = Vec::new();
+    for file in &report.files {
+        let fixes: Vec<&Fix> = file
+            .violations
+            .iter()
+            .filter_map(|v| v.fix.as_ref())
+            .collect();
+        if fixes.is_empty() {
+            continue;
+        }
+        let original = fs::read_to_string(&file.path)
+            .with_context(|| format!("Failed to read {}", file.path.display()))?;
+        if let Some(patch) = patch_file(&file.path, original, &fixes) {
+            patches.push(patch);
+        }
+    }
+    Ok(patches)
+}
+
+fn patch_file(path: &Path, original: String, fixes: &[&Fix]) -> Option<FilePatch> {
+    let (edits, applied) = select(&original, fixes);
+    if edits.is_empty() {
+        return None;
+    }
+    let patched = apply(&original, edits);
+    Some(FilePatch {
+        path: path.to_path_buf(),
+        original,
+        patched,
+        applied,
+    })
+}
+
+/// Chooses the fixes to apply: every edit must lie within `source` on
+/// char boundaries and must not overlap an edit already chosen.
+fn select<'a>(source: &str, fixes: &[&'a Fix]) -> (Vec<&'a Edit>, Vec<String>) {
+    let mut ordered = fixes.to_vec();
+    ordered.sort_by_key(|f| f.edits.iter().map(|e| e.start).min());
+    let mut edits: Vec<&Edit> = Vec::new();
+    let mut applied = Vec::new();
+    for fix in ordered {
+        let valid = fix
+            .edits
+            .iter()
+            .all(|e| source.get(e.start..e.end).is_some() && !edits.iter().any(|t| overlaps(e, t)));
+        if valid {
+            edits.extend(&fix.edits);
+            applied.push(fix.description.clone());
+        }
+    }
+    (edits, applied)
+}
+
+/// Two edits conflict if their ranges intersect or both insert at the
+/// same point.
+fn overlaps(a: &Edit, b: &Edit) -> bool {
+    (a.start < b.end && b.start < a.end) || a.start == b.start
+}
+
+/// Applies non-overlapping edits.
+fn apply(source: &str, mut edits: Vec<&Edit>) -> String {
+    edits.sort_by_key(|e| std::cmp::Reverse(e.start));
+    let mut out = source.to_string();
+    for edit in edits {
+        out.replace_range(edit.start..edit.end, &edit.replacement);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fix(start: usize, end: usize, replacement: &str) -> Fix {
+        Fix {
+            description: format!("edit {start}"),
+            edits: vec![Edit {
+                start,
+                end,
+                replacement: replacement.into(),
+            }],
+        }
+    }
+
+    #[test]
+    fn test_applies_fixes_and_skips_overlaps() {
+        let source = "let a = x.unwrap();\nunsafe { f() }\n";
+        let unwrap = fix(9, 18, "?");
+        let stub = fix(20, 20, "// SAFETY: ok\n");
+        let clash = fix(10, 12, "");
+        let out_of_range = fix(90, 91, "");
+
+        let patch = patch_file(
+            Path::new("src/lib.rs"),
+            source.into(),
+            &[&stub, &clash, &unwrap, &out_of_range],
+        )
+        .unwrap();
+
+        assert_eq!(
+            patch.patched,
+            "let a = x?;\n// SAFETY: ok\nunsafe { f() }\n"
+        );
+        assert_eq!(patch.applied, vec!["edit 9", "edit 20"]);
+        assert!(patch
+            .diff()
+            .contains("--- a/src/lib.rs\n+++ b/src/lib.rs\n"));
+        assert!(patch
+            .diff()
+            .contains("-let a = x.unwrap();\n+let a = x?;\n"));
+    }
+}
@@ -0,0 +1,129 @@
+// src/graph/architecture.rs
+//! Checks every resolved import against the declared `[architecture]`.
+//!
+//! Unlike the inferred layers of the locality pass, declared layers and
+//! forbid rules are exact: each breach is reported on the importing file
+//! at the line of the offending import.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::config::architecture::{Architecture, Breach};
+use crate::types::{Violation, ViolationDetails};
+
+use super::locality::ImportGraph;
+
+/// Law reported for every architecture breach.
+pub const LAW: &str = "LAW OF ARCHITECTURE";
+
+/// Architecture violations keyed by importing file, relative to the
+/// project root. Empty when no architecture is declared.
+#[must_use]
+pub fn collect(
+    graph: &ImportGraph,
+    architecture: &Architecture,
+) -> HashMap<PathBuf, Vec<Violation>> {
+    let mut findings = HashMap::new();
+    if architecture.is_empty() {
+        return findings;
+    }
+    for (from, targets) in graph.files() {
+        let violations: Vec<Violation> = targets
+            .iter()
+            .filter(|(to, _)| to != from)
+            .filter_map(|(to, row)| {
+                let breach = architecture.check(from, to)?;
+                Some(violation(architecture, &breach, (from, to), *row))
+            })
+            .collect();
+        if !violations.is_empty() {
+            findings.insert(from.to_path_buf(), violations);
+        }
+    }
+    findings
+}
+
+fn violation(
+    architecture: &Architecture,
+    breach: &Breach,
+    (from, to): (&Path, &Path),
+    row: usize,
+) -> Violation {
+    let edge = format!("'{}' → '{}'", from.display(), to.display());
+    let (message, suggestion) = match breach {
+        Breach::Layer { from, to } => (
+            format!("Layer '{from}' may not depend on layer '{to}' ({edge})"),
+            format!("Move what '{from}' uses from '{to}' into a layer '{from}' may import"),
+        ),
+        Breach::Forbidden(index) => {
+            let rule = architecture.forbid_rule(*index);
+            let pattern = rule.map_or_else(String::new, |r| format!(" ({} → {})", r.from, r.to));
+            let reason = rule.and_then(|r| r.reason.clone());
+            (
+                format!("Forbidden dependency {edge}{pattern}"),
+                reason.unwrap_or_else(|| "Remove the import; `[architecture]` forbids it".into()),
+            )
+        }
+    };
+    let details = ViolationDetails {
+        function_name: None,
+        analysis: Vec::new(),
+        suggestion: Some(suggestion),
+    };
+    Violation::with_details(row, message, LAW, details)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::architecture::{ArchitectureConfig, ForbidConfig, LayerConfig};
+    use anyhow::Result;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_breaches_are_reported_on_the_import_line() -> Result<()> {
+        let dir = TempDir::new()?;
+        let src = dir.path().join("src");
+        fs::create_dir_all(src.join("ui"))?;
+        fs::create_dir_all(src.join("db"))?;
+        fs::write(src.join("ui/view.rs"), "// view\nuse crate::db::pool;\n")?;
+        fs::write(src.join("db/pool.rs"), "use crate::ui::view;\n")?;
+        let files = vec![src.join("ui/view.rs"), src.join("db/pool.rs")];
+
+        let config = ArchitectureConfig {
+            layers: vec![
+                LayerConfig {
+                    name: "ui".into(),
+                    paths: vec!["src/ui/**".into()],
+                    allow: None,
+                },
+                LayerConfig {
+                    name: "db".into(),
+                    paths: vec!["src/db/**".into()],
+                    allow: None,
+                },
+            ],
+            forbid: vec![ForbidConfig {
+                from: "src/ui/**".into(),
+                to: "src/db/**".into(),
+                reason: Some("UI talks to services, not the database".into()),
+            }],
+        };
+        let (architecture, errors) = Architecture::compile(config);
+        assert!(errors.is_empty());
+
+        let findings = collect(&ImportGraph::read(dir.path(), &files), &architecture);
+        let ui = &findings[Path::new("src/ui/view.rs")];
+        assert_eq!(ui.len(), 1);
+        assert_eq!((ui[0].row, ui[0].law), (2, LAW));
+        assert!(ui[0].message.starts_with("Forbidden dependency"));
+
+        let db = &findings[Path::new("src/db/pool.rs")];
+        assert_eq!(db[0].row, 1);
+        assert!(db[0]
+            .message
+            .contains("Layer 'db' may not depend on layer 'ui'"));
+        Ok(())
+    }
+}
@@ -2,9 +2,9 @@
 use std::collections::HashMap;
 use std::path::Path;
 use std::sync::LazyLock;
-use tree_sitter::{Language, Parser, Query, QueryCursor};
+use tree_sitter::{Node, Parser, QueryCursor};
 
-use super::queries::DefExtractor;
+use crate::analysis::queries::QuerySet;
 use crate::lang::Lang;
 
 /// A symbol definition found in source code.
@@ -43,6 +43,7 @@ static KIND_MAP: LazyLock<HashMap<&'static str, DefKind>> = LazyLock::new(|| {
         ("type_alias_declaration", DefKind::Type),
         ("class_definition", DefKind::Class),
         ("class_declaration", DefKind::Class),
+        ("abstract_class_declaration", DefKind::Class),
         ("interface_declaration", DefKind::Interface),
     ])
 });
@@ -56,13 +57,21 @@ pub fn extract(path: &Path, content: &str) -> Vec<Definition> {
     let Some(lang) = Lang::from_ext(ext) else {
         return Vec::new();
     };
-    
-    let (grammar, query) = DefExtractor::get_config(lang);
-    run_extraction(content, grammar, &query)
+
+    let mut parser = Parser::new();
+    if parser.set_language(lang.grammar()).is_err() {
+        return Vec::new();
+    }
+    let Some(tree) = parser.parse(content, None) else {
+        return Vec::new();
+    };
+    extract_from_tree(lang, tree.root_node(), content)
 }
 
-fn run_extraction(source: &str, lang: Language, query: &Query) -> Vec<Definition> {
-    let Some(tree) = parse_source(source, lang) else {
+/// Like [`extract`], for a file the caller has already parsed.
+#[must_use]
+pub fn extract_from_tree(lang: Lang, root: Node, source: &str) -> Vec<Definition> {
+    let Some(query) = QuerySet::for_lang(lang).get(lang.q_defs()) else {
         return Vec::new();
     };
 
@@ -72,17 +81,11 @@ fn run_extraction(source: &str, lang: Language, query: &Query) -> Vec<Definition
     let sig_idx = query.capture_index_for_name("sig").unwrap_or(0);
 
     cursor
-        .matches(query, tree.root_node(), source.as_bytes())
+        .matches(&query, root, source.as_bytes())
         .filter_map(|m| build_def(&m, name_idx, sig_idx, source, &lines))
         .collect()
 }
 
-fn parse_source(source: &str, lang: Language) -> Option<tree_sitter::Tree> {
-    let mut parser = Parser::new();
-    parser.set_language(lang).ok()?;
-    parser.parse(source, None)
-}
-
 fn build_def(
     m: &tree_sitter::QueryMatch,
     name_idx: u32,
@@ -2,6 +2,5 @@
 //! Extracts symbol DEFINITIONS from source files using tree-sitter.
 
 mod extract;
-mod queries;
 
-pub use extract::{extract, DefKind, Definition};
+pub use extract::{extract, extract_from_tree, DefKind, Definition};
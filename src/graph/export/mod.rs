@@ -0,0 +1,206 @@
+// src/graph/export/mod.rs
+//! Dependency graph export for `slopchop graph`.
+//!
+//! Builds the file-level or directory-level graph from the locality edges,
+//! with each node's inferred layer, classification and coupling metrics,
+//! and marks failing edges and cycles. Renderers for DOT and Mermaid live
+//! in [`render`]; JSON is the serialized [`DependencyGraph`].
+
+pub mod render;
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+
+use super::locality::{
+    classify, compute_coupling, find_cycles, infer_condensed_layers, module_of, validate_graph,
+    ClassifierConfig, Cycle, ValidatorConfig,
+};
+
+type Edge = (PathBuf, PathBuf);
+
+/// A dependency graph ready to render.
+#[derive(Debug, Serialize)]
+pub struct DependencyGraph {
+    pub nodes: Vec<GraphNode>,
+    pub edges: Vec<GraphEdge>,
+    /// Strongly connected components, with the edges that break them.
+    pub cycles: Vec<Cycle>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GraphNode {
+    pub path: PathBuf,
+    /// Inferred layer; 0 holds the nodes with no dependencies. Files on a
+    /// cycle share the layer of their component.
+    pub layer: usize,
+    /// Classification label, e.g. `STABLE_HUB` or `DEADWOOD`.
+    pub identity: &'static str,
+    pub afferent: usize,
+    pub efferent: usize,
+    pub instability: f64,
+    pub skew: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GraphEdge {
+    pub from: PathBuf,
+    pub to: PathBuf,
+    /// Fails locality validation.
+    pub failing: bool,
+    /// Both ends lie in the same cycle.
+    pub in_cycle: bool,
+}
+
+impl DependencyGraph {
+    /// Graph with one node per file.
+    #[must_use]
+    pub fn files(edges: &[Edge], config: &ValidatorConfig) -> Self {
+        let failing = failing_edges(edges, config);
+        assemble(edges.iter().cloned(), &failing, &config.classifier)
+    }
+
+    /// Graph with one node per directory. An edge between two directories
+    /// fails if any of the file edges it stands for fails.
+    #[must_use]
+    pub fn directories(edges: &[Edge], config: &ValidatorConfig) -> Self {
+        let failing: HashSet<Edge> = failing_edges(edges, config)
+            .iter()
+            .filter_map(directory_edge)
+            .collect();
+        let edges = edges.iter().filter_map(directory_edge);
+        assemble(edges, &failing, &config.classifier)
+    }
+}
+
+fn failing_edges(edges: &[Edge], config: &ValidatorConfig) -> HashSet<Edge> {
+    let pairs = edges.iter().map(|(a, b)| (a.as_path(), b.as_path()));
+    let report = validate_graph(pairs, config);
+    report
+        .failed()
+        .iter()
+        .map(|edge| (edge.from.clone(), edge.to.clone()))
+        .collect()
+}
+
+/// The edge between the directories of `from` and `to`, unless they are
+/// the same directory.
+fn directory_edge((from, to): &Edge) -> Option<Edge> {
+    let (from, to) = (module_of(from), module_of(to));
+    (from != to).then_some((from, to))
+}
+
+fn assemble(
+    edges: impl Iterator<Item = Edge>,
+    failing: &HashSet<Edge>,
+    classifier: &ClassifierConfig,
+) -> DependencyGraph {
+    // Cycles weigh break edges by import count, so they see every import,
+    // self-imports included; the rendered graph shows each edge once.
+    let raw: Vec<Edge> = edges.collect();
+    let cycles = find_cycles(raw.iter().map(|(a, b)| (a.as_path(), b.as_path())));
+    let edges: BTreeSet<Edge> = raw.into_iter().collect();
+    let pairs = || edges.iter().map(|(a, b)| (a.as_path(), b.as_path()));
+    let mut couplings = compute_coupling(pairs().filter(|(from, to)| from != to));
+    for (from, _) in pairs() {
+        couplings.entry(from.to_path_buf()).or_default();
+    }
+    let layers = infer_condensed_layers(pairs(), &cycles);
+    let component: HashMap<&Path, usize> = cycles
+        .iter()
+        .enumerate()
+        .flat_map(|(i, cycle)| cycle.members.iter().map(move |m| (m.as_path(), i)))
+        .collect();
+    let in_cycle = |from: &Path, to: &Path| {
+        component.contains_key(from) && component.get(from) == component.get(to)
+    };
+
+    let mut nodes: Vec<GraphNode> = couplings
+        .iter()
+        .map(|(path, coupling)| GraphNode {
+            path: path.clone(),
+            layer: layers.get(path).copied().unwrap_or_default(),
+            identity: classify(coupling, classifier).label(),
+            afferent: coupling.afferent(),
+            efferent: coupling.efferent(),
+            instability: coupling.instability(),
+            skew: coupling.skew(),
+        })
+        .collect();
+    nodes.sort_by(|a, b| a.path.cmp(&b.path));
+
+    let edges = edges
+        .iter()
+        .map(|(from, to)| GraphEdge {
+            failing: failing.contains(&(from.clone(), to.clone())),
+            in_cycle: in_cycle(from, to),
+            from: from.clone(),
+            to: to.clone(),
+        })
+        .collect();
+    DependencyGraph {
+        nodes,
+        edges,
+        cycles,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn edge(from: &str, to: &str) -> Edge {
+        (PathBuf::from(from), PathBuf::from(to))
+    }
+
+    #[test]
+    fn test_cycles_are_marked_and_directories_collapse() {
+        let edges = vec![
+            edge("src/ui/view.rs", "src/core/model.rs"),
+            edge("src/ui/view.rs", "src/ui/widget.rs"),
+            edge("src/core/model.rs", "src/core/store.rs"),
+            edge("src/core/store.rs", "src/core/model.rs"),
+        ];
+        let config = ValidatorConfig::default();
+
+        let files = DependencyGraph::files(&edges, &config);
+        assert_eq!(files.nodes.len(), 4);
+        assert_eq!(files.cycles.len(), 1);
+        let in_cycle: Vec<bool> = files.edges.iter().map(|e| e.in_cycle).collect();
+        assert_eq!(in_cycle, vec![true, true, false, false]);
+
+        let dirs = DependencyGraph::directories(&edges, &config);
+        let paths: Vec<&Path> = dirs.nodes.iter().map(|n| n.path.as_path()).collect();
+        assert_eq!(paths, vec![Path::new("src/core"), Path::new("src/ui")]);
+        assert_eq!(dirs.edges.len(), 1);
+        assert!(dirs.cycles.is_empty());
+        let ui = dirs.nodes.last().map(|n| (n.layer, n.efferent));
+        assert_eq!(ui, Some((1, 1)));
+    }
+
+    #[test]
+    fn test_cycles_weigh_repeated_imports_and_self_imports() {
+        let edges = vec![
+            edge("src/a.rs", "src/b.rs"),
+            edge("src/a.rs", "src/b.rs"),
+            edge("src/b.rs", "src/a.rs"),
+            edge("src/c.rs", "src/c.rs"),
+        ];
+        let graph = DependencyGraph::files(&edges, &ValidatorConfig::default());
+        assert_eq!(graph.edges.len(), 3);
+        assert_eq!(graph.cycles.len(), 2);
+        let weights: Vec<(&Path, usize)> = graph
+            .cycles
+            .iter()
+            .flat_map(|c| &c.break_edges)
+            .map(|e| (e.from.as_path(), e.weight))
+            .collect();
+        assert_eq!(
+            weights,
+            vec![(Path::new("src/b.rs"), 1), (Path::new("src/c.rs"), 1)]
+        );
+        let c = graph.nodes.iter().find(|n| n.path == Path::new("src/c.rs"));
+        assert_eq!(c.map(|n| (n.afferent, n.efferent)), Some((0, 0)));
+    }
+}
@@ -0,0 +1,162 @@
+// src/graph/export/render.rs
+//! DOT and Mermaid renderings of a [`DependencyGraph`].
+//!
+//! Nodes are grouped by layer, top layer first, and filled by
+//! classification. Failing edges are drawn red; edges on a cycle orange.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write;
+use std::path::Path;
+
+use super::{DependencyGraph, GraphEdge, GraphNode};
+
+const FAILING: &str = "#d62728";
+const CYCLE: &str = "#ff7f0e";
+
+/// Fill colour and Mermaid class for each classification label.
+const IDENTITY_STYLES: &[(&str, &str, &str)] = &[
+    ("STABLE_HUB", "#c6dbef", "hub"),
+    ("GOD_MODULE", "#fcbba1", "god"),
+    ("DEADWOOD", "#d9d9d9", "deadwood"),
+    ("VOLATILE_LEAF", "#fdd0a2", "leaf"),
+];
+
+/// Graphviz DOT source.
+#[must_use]
+pub fn dot(graph: &DependencyGraph) -> String {
+    let mut out = String::from("digraph dependencies {\n    rankdir=TB;\n");
+    out.push_str("    node [shape=box, style=\"rounded,filled\", fillcolor=\"#ffffff\", fontname=\"Helvetica\"];\n");
+    for (layer, nodes) in by_layer(graph).into_iter().rev() {
+        let _ = writeln!(out, "    subgraph cluster_layer_{layer} {{");
+        let _ = writeln!(out, "        label=\"layer {layer}\";");
+        for node in nodes {
+            let fill =
+                fill_of(node).map_or(String::new(), |fill| format!(", fillcolor=\"{fill}\""));
+            let label = format!("{}\\n{}", escape(&node.path), metrics(node));
+            let _ = writeln!(
+                out,
+                "        \"{}\" [label=\"{label}\"{fill}];",
+                escape(&node.path)
+            );
+        }
+        out.push_str("    }\n");
+    }
+    for edge in &graph.edges {
+        let style = edge_colour(edge).map_or(String::new(), |colour| {
+            format!(" [color=\"{colour}\", penwidth=2]")
+        });
+        let (from, to) = (escape(&edge.from), escape(&edge.to));
+        let _ = writeln!(out, "    \"{from}\" -> \"{to}\"{style};");
+    }
+    out.push_str("}\n");
+    out
+}
+
+/// Mermaid flowchart source.
+#[must_use]
+pub fn mermaid(graph: &DependencyGraph) -> String {
+    let ids: HashMap<&Path, usize> = graph
+        .nodes
+        .iter()
+        .enumerate()
+        .map(|(i, node)| (node.path.as_path(), i))
+        .collect();
+    // Every edge end is a node, so the lookup cannot miss.
+    let id = |path: &Path| ids.get(path).copied().unwrap_or_default();
+    let mut out = String::from("graph TD\n");
+    for (layer, nodes) in by_layer(graph).into_iter().rev() {
+        let _ = writeln!(out, "    subgraph layer_{layer}[\"layer {layer}\"]");
+        for node in nodes {
+            let label = mermaid_text(&format!("{}<br/>{}", node.path.display(), metrics(node)));
+            let _ = writeln!(out, "        n{}[\"{label}\"]", id(&node.path));
+        }
+        out.push_str("    end\n");
+    }
+    for (i, edge) in graph.edges.iter().enumerate() {
+        let (from, to) = (id(&edge.from), id(&edge.to));
+        let _ = writeln!(out, "    n{from} --> n{to}");
+        if let Some(colour) = edge_colour(edge) {
+            let _ = writeln!(out, "    linkStyle {i} stroke:{colour},stroke-width:2px");
+        }
+    }
+    for (label, fill, class) in IDENTITY_STYLES {
+        let members: Vec<String> = graph
+            .nodes
+            .iter()
+            .filter(|node| node.identity == *label)
+            .map(|node| format!("n{}", id(&node.path)))
+            .collect();
+        if !members.is_empty() {
+            let _ = writeln!(out, "    classDef {class} fill:{fill}");
+            let _ = writeln!(out, "    class {} {class}", members.join(","));
+        }
+    }
+    out
+}
+
+fn by_layer(graph: &DependencyGraph) -> BTreeMap<usize, Vec<&GraphNode>> {
+    let mut layers: BTreeMap<usize, Vec<&GraphNode>> = BTreeMap::new();
+    for node in &graph.nodes {
+        layers.entry(node.layer).or_default().push(node);
+    }
+    layers
+}
+
+fn metrics(node: &GraphNode) -> String {
+    format!(
+        "{} I={:.2} K={:.2}",
+        node.identity, node.instability, node.skew
+    )
+}
+
+fn fill_of(node: &GraphNode) -> Option<&'static str> {
+    IDENTITY_STYLES
+        .iter()
+        .find(|(label, _, _)| *label == node.identity)
+        .map(|(_, fill, _)| *fill)
+}
+
+fn edge_colour(edge: &GraphEdge) -> Option<&'static str> {
+    if edge.failing {
+        Some(FAILING)
+    } else if edge.in_cycle {
+        Some(CYCLE)
+    } else {
+        None
+    }
+}
+
+fn escape(path: &Path) -> String {
+    path.display().to_string().replace('"', "\\\"")
+}
+
+fn mermaid_text(text: &str) -> String {
+    text.replace('"', "#quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::locality::ValidatorConfig;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_renderings_mark_cycle_edges() {
+        let edges: Vec<(PathBuf, PathBuf)> = [("a.rs", "b.rs"), ("b.rs", "a.rs"), ("b.rs", "c.rs")]
+            .into_iter()
+            .map(|(from, to)| (from.into(), to.into()))
+            .collect();
+        let graph = DependencyGraph::files(&edges, &ValidatorConfig::default());
+
+        let dot = dot(&graph);
+        assert!(dot.contains("\"a.rs\" -> \"b.rs\" [color=\"#ff7f0e\", penwidth=2];"));
+        assert!(dot.contains("\"b.rs\" -> \"c.rs\";"));
+        assert!(dot.contains("subgraph cluster_layer_0"));
+
+        let mermaid = mermaid(&graph);
+        assert!(mermaid.starts_with("graph TD\n"));
+        assert!(mermaid.contains("    n0 --> n1\n    linkStyle 0 stroke:#ff7f0e"));
+        assert!(mermaid.contains("    n1 --> n2\n"));
+        assert!(!mermaid.contains("linkStyle 2"));
+    }
+}
@@ -1,7 +1,8 @@
 // src/graph/imports.rs
+use crate::analysis::queries::QuerySet;
 use crate::lang::Lang;
 use std::path::Path;
-use tree_sitter::{Language, Parser, Query, QueryCursor};
+use tree_sitter::{Node, Parser, Query, QueryCursor};
 
 /// Extracts raw import strings from the given file content.
 ///
@@ -10,9 +11,18 @@ use tree_sitter::{Language, Parser, Query, QueryCursor};
 /// * `content` - Source code.
 ///
 /// # Returns
-/// A list of imported module names/paths (e.g., "`std::io`", "./utils", "react").
+/// A list of imported module names/paths (e.g., "`std::io`", "./utils", "react", "std/os").
 #[must_use]
 pub fn extract(path: &Path, content: &str) -> Vec<String> {
+    extract_with_rows(path, content)
+        .into_iter()
+        .map(|(import, _)| import)
+        .collect()
+}
+
+/// Like [`extract`], paired with the 1-based line each import starts on.
+#[must_use]
+pub fn extract_with_rows(path: &Path, content: &str) -> Vec<(String, usize)> {
     let Some(ext) = path.extension().and_then(|s| s.to_str()) else {
         return Vec::new();
     };
@@ -21,30 +31,49 @@ pub fn extract(path: &Path, content: &str) -> Vec<String> {
         return Vec::new();
     };
 
-    let grammar = lang.grammar();
-    let query = compile_query(grammar, lang.q_imports());
-
-    run_query(content, grammar, &query)
-}
-
-fn run_query(source: &str, lang: Language, query: &Query) -> Vec<String> {
     let mut parser = Parser::new();
-    if parser.set_language(lang).is_err() {
+    if parser.set_language(lang.grammar()).is_err() {
         return Vec::new();
     }
 
-    let Some(tree) = parser.parse(source, None) else {
+    let Some(tree) = parser.parse(content, None) else {
+        return Vec::new();
+    };
+
+    extract_from_tree(lang, tree.root_node(), content)
+}
+
+/// Like [`extract_with_rows`], for a file the caller has already parsed.
+#[must_use]
+pub fn extract_from_tree(lang: Lang, root: Node, source: &str) -> Vec<(String, usize)> {
+    let Some(query) = QuerySet::for_lang(lang).get(lang.q_imports()) else {
         return Vec::new();
     };
+    let captures = run_query(source, root, &query);
 
+    if lang == Lang::Nim {
+        // Nim captures whole statements; one statement may import several modules.
+        return captures
+            .iter()
+            .flat_map(|(stmt, row)| nim_modules(stmt).into_iter().map(|m| (m, *row)))
+            .collect();
+    }
+    captures
+        .iter()
+        .map(|(text, row)| (clean_text(text), *row))
+        .collect()
+}
+
+fn run_query(source: &str, root: Node, query: &Query) -> Vec<(String, usize)> {
     let mut cursor = QueryCursor::new();
-    let matches = cursor.matches(query, tree.root_node(), source.as_bytes());
+    let matches = cursor.matches(query, root, source.as_bytes());
     let mut imports = Vec::new();
 
     for m in matches {
         for capture in m.captures {
             if let Ok(text) = capture.node.utf8_text(source.as_bytes()) {
-                imports.push(clean_text(text));
+                let row = capture.node.start_position().row + 1;
+                imports.push((text.to_string(), row));
             }
         }
     }
@@ -58,11 +87,73 @@ fn clean_text(text: &str) -> String {
         .to_string()
 }
 
-fn compile_query(lang: Language, pattern: &str) -> Query {
-    match Query::new(lang, pattern) {
-        Ok(q) => q,
-        Err(e) => panic!("Invalid import query: {e}"),
+/// Splits a Nim `import`/`include`/`from` statement into module paths.
+/// Handles comma lists, `a/[b, c]` groups, `as` aliases and `except` clauses.
+fn nim_modules(statement: &str) -> Vec<String> {
+    let code: Vec<&str> = statement
+        .lines()
+        .map(|line| line.split('#').next().unwrap_or(line))
+        .collect();
+    let flat = code.join(" ").split_whitespace().collect::<Vec<_>>().join(" ");
+
+    if let Some(rest) = flat.strip_prefix("from ") {
+        let module = rest.split(" import").next().unwrap_or(rest);
+        return vec![nim_module_name(module)];
     }
+
+    let list = flat
+        .strip_prefix("import ")
+        .or_else(|| flat.strip_prefix("include "))
+        .unwrap_or_default();
+    let list = list.split(" except ").next().unwrap_or(list);
+
+    split_top_level(list)
+        .iter()
+        .flat_map(|item| expand_group(item))
+        .map(|item| nim_module_name(&item))
+        .filter(|m| !m.is_empty())
+        .collect()
+}
+
+fn split_top_level(list: &str) -> Vec<&str> {
+    let mut items = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, c) in list.char_indices() {
+        match c {
+            '[' => depth += 1,
+            ']' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                items.push(&list[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    items.push(&list[start..]);
+    items
+}
+
+/// Expands `std/[os, strutils]` into `std/os` and `std/strutils`.
+fn expand_group(item: &str) -> Vec<String> {
+    let Some((prefix, group)) = item.split_once('[') else {
+        return vec![item.to_string()];
+    };
+    group
+        .trim_end()
+        .trim_end_matches(']')
+        .split(',')
+        .map(|member| format!("{}{}", prefix.trim(), member.trim()))
+        .collect()
+}
+
+fn nim_module_name(item: &str) -> String {
+    let path = item.split(" as ").next().unwrap_or(item).trim();
+    if path.starts_with('"') {
+        return path.trim_matches('"').to_string();
+    }
+    // `std / os` is the same path as `std/os`.
+    path.split_whitespace().collect()
 }
 
 #[cfg(test)]
@@ -105,4 +196,29 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_extract_reports_import_rows() {
+        let code = "use std::io;\n\nuse crate::config::Config;\n";
+        assert_eq!(
+            extract_with_rows(Path::new("lib.rs"), code),
+            vec![("std::io".to_string(), 1), ("crate::config::Config".to_string(), 3)]
+        );
+    }
+
+    #[test]
+    fn test_split_nim_statements() {
+        assert_eq!(nim_modules("import std/strutils, os"), vec!["std/strutils", "os"]);
+        assert_eq!(nim_modules("import std/[os, strutils]"), vec!["std/os", "std/strutils"]);
+        assert_eq!(
+            nim_modules("import pkg/jester as j, ./util except foo"),
+            vec!["pkg/jester", "./util"]
+        );
+        assert_eq!(nim_modules("from db / models import User, Post"), vec!["db/models"]);
+        assert_eq!(nim_modules("include helpers, \"gen/tables\""), vec!["helpers", "gen/tables"]);
+        assert_eq!(
+            nim_modules("import\n  a/b, # the core\n  c"),
+            vec!["a/b", "c"]
+        );
+    }
 }
\ No newline at end of file
@@ -0,0 +1,114 @@
+// src/graph/locality/abstractness.rs
+//! Abstractness from the `Defs` queries.
+//!
+//! Traits, interfaces, abstract classes and Python ABCs and protocols
+//! count as abstract types; structs, enums and other classes as concrete.
+//! Functions, constants, aliases and impls are not types.
+
+use serde::{Deserialize, Serialize};
+
+use crate::graph::defs::{DefKind, Definition};
+
+/// Abstract and concrete type definitions in a file or module.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TypeCounts {
+    pub abstract_types: usize,
+    pub concrete_types: usize,
+}
+
+impl TypeCounts {
+    /// Counts the types among `defs`.
+    #[must_use]
+    pub fn of(defs: &[Definition]) -> Self {
+        let mut counts = Self::default();
+        for is_abstract in defs.iter().filter_map(abstract_type) {
+            counts.add(is_abstract);
+        }
+        counts
+    }
+
+    fn add(&mut self, is_abstract: bool) {
+        if is_abstract {
+            self.abstract_types += 1;
+        } else {
+            self.concrete_types += 1;
+        }
+    }
+
+    #[must_use]
+    pub fn total(&self) -> usize {
+        self.abstract_types + self.concrete_types
+    }
+
+    /// A = abstract / total, or 0 when there are no types.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn abstractness(&self) -> f64 {
+        if self.total() == 0 {
+            return 0.0;
+        }
+        self.abstract_types as f64 / self.total() as f64
+    }
+}
+
+/// Signature fragments that mark a class as abstract.
+const ABSTRACT_MARKERS: &[&str] = &["abstract class", "ABCMeta"];
+
+/// Python base classes that make a class abstract.
+const ABSTRACT_BASES: &[&str] = &["ABC", "Protocol"];
+
+/// `Some(true)` for an abstract type, `Some(false)` for a concrete one and
+/// `None` for a definition that is not a type.
+fn abstract_type(def: &Definition) -> Option<bool> {
+    match def.kind {
+        DefKind::Trait | DefKind::Interface => Some(true),
+        DefKind::Struct | DefKind::Enum => Some(false),
+        DefKind::Class => Some(
+            ABSTRACT_MARKERS.iter().any(|m| def.signature.contains(m))
+                || base_classes(&def.signature).any(|b| ABSTRACT_BASES.contains(&b)),
+        ),
+        _ => None,
+    }
+}
+
+/// Names in a Python class's base list, without module paths or type
+/// arguments: `class A(typing.Protocol[T], Base)` gives `Protocol` and
+/// `Base`.
+fn base_classes(signature: &str) -> impl Iterator<Item = &str> {
+    let bases = signature
+        .split_once('(')
+        .and_then(|(_, rest)| rest.rsplit_once(')'))
+        .map_or("", |(bases, _)| bases);
+    bases.split(',').map(|base| {
+        let name = base.trim().split('[').next().unwrap_or_default();
+        name.rsplit('.').next().unwrap_or(name)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::defs;
+    use std::path::Path;
+
+    fn counts(path: &str, code: &str) -> (usize, usize) {
+        let counts = TypeCounts::of(&defs::extract(Path::new(path), code));
+        (counts.abstract_types, counts.concrete_types)
+    }
+
+    #[test]
+    fn test_abstract_and_concrete_types() {
+        assert_eq!(
+            counts("lib.rs", "trait Store {}\nstruct Db;\nenum E {}\nfn f() {}"),
+            (1, 2)
+        );
+        let python = "class Repo(ABC):\n    pass\n\nclass Sql(Repo):\n    pass";
+        assert_eq!(counts("repo.py", python), (1, 1));
+        let protocols = "class Port(typing.Protocol[T]):\n    pass\n\n\
+                         class HttpProtocolHandler(Base):\n    pass\n\n\
+                         class Tcp(MyProtocol, Base):\n    pass";
+        assert_eq!(counts("ports.py", protocols), (1, 2));
+        let ts = "export abstract class Base {}\nclass Impl extends Base {}\ninterface Port {}";
+        assert_eq!(counts("port.ts", ts), (2, 1));
+    }
+}
@@ -81,7 +81,7 @@ pub fn compute_module_coupling(edges: &[LocalityEdge]) -> Vec<(String, String, u
     }
 
     let mut result: Vec<_> = coupling.into_iter().map(|((a, b), c)| (a, b, c)).collect();
-    result.sort_by(|a, b| b.2.cmp(&a.2));
+    result.sort_by_key(|r| std::cmp::Reverse(r.2));
     result
 }
 
@@ -2,191 +2,185 @@
 //! Cycle detection for the Law of Locality.
 //!
 //! Simply put: dependency cycles are architectural errors.
-//! This module detects them using Depth-First Search (DFS).
+//! Every cycle is reported as a strongly connected component (see
+//! [`super::scc`]) together with a set of edges whose removal breaks it.
+//! An edge weighs as many imports as stand behind it, and the break set
+//! prefers light edges, since those are the cheapest to remove.
 
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashSet};
 use std::path::{Path, PathBuf};
 
-/// Detects cycles in a dependency graph.
-/// Returns a list of cycles, where each cycle is a list of nodes involved.
-pub fn detect_cycles<'a, I>(edges: I) -> Vec<Vec<PathBuf>>
-where
-    I: Iterator<Item = (&'a Path, &'a Path)>,
-{
-    let mut adjacency: HashMap<PathBuf, Vec<PathBuf>> = HashMap::new();
-    let mut nodes = HashSet::new();
-
-    for (from, to) in edges {
-        adjacency
-            .entry(from.to_path_buf())
-            .or_default()
-            .push(to.to_path_buf());
-        nodes.insert(from.to_path_buf());
-        nodes.insert(to.to_path_buf());
-    }
-
-    let mut state = DfsState {
-        visited: HashSet::new(),
-        recursion_stack: HashSet::new(),
-        path_stack: Vec::new(),
-        cycles: Vec::new(),
-    };
+use serde::Serialize;
 
-    // To ensure deterministic output for testing
-    let mut sorted_nodes: Vec<_> = nodes.into_iter().collect();
-    sorted_nodes.sort();
+use super::scc::{components, cyclic_edges};
 
-    for node in sorted_nodes {
-        if !state.visited.contains(&node) {
-            dfs(&node, &adjacency, &mut state);
-        }
-    }
-
-    state.cycles
+/// A strongly connected component of the dependency graph.
+#[derive(Debug, Clone, Serialize)]
+pub struct Cycle {
+    /// Files in the component, sorted.
+    pub members: Vec<PathBuf>,
+    /// Edges whose removal makes the component acyclic, lightest first.
+    pub break_edges: Vec<BreakEdge>,
 }
 
-struct DfsState {
-    visited: HashSet<PathBuf>,
-    recursion_stack: HashSet<PathBuf>,
-    path_stack: Vec<PathBuf>,
-    cycles: Vec<Vec<PathBuf>>,
-}
-
-fn dfs(
-    node: &PathBuf,
-    adjacency: &HashMap<PathBuf, Vec<PathBuf>>,
-    state: &mut DfsState,
-) {
-    state.visited.insert(node.clone());
-    state.recursion_stack.insert(node.clone());
-    state.path_stack.push(node.clone());
-
-    if let Some(neighbors) = adjacency.get(node) {
-        process_neighbors(neighbors, adjacency, state);
-    }
-
-    state.recursion_stack.remove(node);
-    state.path_stack.pop();
+/// An edge to remove to break a cycle.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BreakEdge {
+    pub from: PathBuf,
+    pub to: PathBuf,
+    /// Number of imports of `to` in `from`.
+    pub weight: usize,
+    /// Lines in `from` that import `to`. Empty until located on disk.
+    pub rows: Vec<usize>,
 }
 
-fn process_neighbors(
-    neighbors: &[PathBuf],
-    adjacency: &HashMap<PathBuf, Vec<PathBuf>>,
-    state: &mut DfsState,
-) {
-    let mut sorted_neighbors = neighbors.to_vec();
-    sorted_neighbors.sort();
+type Weights<'a> = BTreeMap<(&'a Path, &'a Path), usize>;
 
-    for neighbor in sorted_neighbors {
-        visit_neighbor(neighbor, adjacency, state);
+/// Finds every dependency cycle, as strongly connected components with
+/// more than one file or a file importing itself.
+pub fn find_cycles<'a, I>(edges: I) -> Vec<Cycle>
+where
+    I: Iterator<Item = (&'a Path, &'a Path)>,
+{
+    let mut weights = Weights::new();
+    for edge in edges {
+        *weights.entry(edge).or_default() += 1;
     }
+    components(weights.keys().copied())
+        .into_iter()
+        .map(|members| {
+            let break_edges = break_edges(&members, &weights);
+            let members = members.iter().map(|p| p.to_path_buf()).collect();
+            Cycle {
+                members,
+                break_edges,
+            }
+        })
+        .collect()
 }
 
-fn visit_neighbor(
-    neighbor: PathBuf,
-    adjacency: &HashMap<PathBuf, Vec<PathBuf>>,
-    state: &mut DfsState,
-) {
-    if !state.visited.contains(&neighbor) {
-        dfs(&neighbor, adjacency, state);
-    } else if state.recursion_stack.contains(&neighbor) {
-        record_cycle(neighbor, state);
+/// A minimal set of edges that breaks every cycle among `members`: no
+/// edge in it can be kept without leaving a cycle. Built greedily by
+/// removing the lightest edge still on a cycle, then restoring removed
+/// edges heaviest first wherever that stays acyclic.
+fn break_edges(members: &[&Path], weights: &Weights) -> Vec<BreakEdge> {
+    let inside: HashSet<&Path> = members.iter().copied().collect();
+    let mut kept: Vec<(&Path, &Path)> = weights
+        .keys()
+        .filter(|(from, to)| inside.contains(from) && inside.contains(to))
+        .copied()
+        .collect();
+    let weight = |edge: &(&Path, &Path)| weights.get(edge).copied().unwrap_or_default();
+
+    let mut removed = Vec::new();
+    while let Some(lightest) = cyclic_edges(&kept)
+        .into_iter()
+        .min_by_key(|e| (weight(e), *e))
+    {
+        kept.retain(|edge| *edge != lightest);
+        removed.push(lightest);
     }
-}
 
-#[allow(clippy::indexing_slicing)] // Guarded: pos is from position() returning Some
-fn record_cycle(
-    neighbor: PathBuf,
-    state: &mut DfsState,
-) {
-    if let Some(pos) = state.path_stack.iter().position(|x| x == &neighbor) {
-        let mut cycle = state.path_stack[pos..].to_vec();
-        cycle.push(neighbor); // Close the loop visually
-        state.cycles.push(cycle);
+    removed.sort_by_key(|edge| std::cmp::Reverse(weight(edge)));
+    let mut needed = Vec::new();
+    for edge in removed {
+        kept.push(edge);
+        if !cyclic_edges(&kept).is_empty() {
+            kept.pop();
+            needed.push(edge);
+        }
     }
+    needed.sort_by_key(|edge| (weight(edge), *edge));
+    needed
+        .into_iter()
+        .map(|edge| BreakEdge {
+            from: edge.0.to_path_buf(),
+            to: edge.1.to_path_buf(),
+            weight: weight(&edge),
+            rows: Vec::new(),
+        })
+        .collect()
 }
 
-
-
 #[cfg(test)]
 #[allow(clippy::indexing_slicing)]
 mod tests {
     use super::*;
 
-    fn p(s: &str) -> PathBuf { PathBuf::from(s) }
+    fn p(s: &str) -> PathBuf {
+        PathBuf::from(s)
+    }
     fn edges(list: &[(&str, &str)]) -> Vec<(PathBuf, PathBuf)> {
         list.iter().map(|(a, b)| (p(a), p(b))).collect()
     }
+    fn cycles_of(list: &[(&str, &str)]) -> Vec<Cycle> {
+        let edge_vec = edges(list);
+        find_cycles(edge_vec.iter().map(|(a, b)| (a.as_path(), b.as_path())))
+    }
+    fn breaks(cycle: &Cycle) -> Vec<(&str, &str, usize)> {
+        cycle
+            .break_edges
+            .iter()
+            .map(|e| {
+                (
+                    e.from.to_str().unwrap_or(""),
+                    e.to.to_str().unwrap_or(""),
+                    e.weight,
+                )
+            })
+            .collect()
+    }
 
     #[test]
     fn test_cycle_detection_logic() {
         let cases = vec![
+            (vec![("a", "b"), ("b", "c")], 0, "No cycles"),
+            (vec![("a", "b"), ("b", "a")], 1, "Simple cycle"),
             (
-                vec![("a", "b"), ("b", "c")], 
-                0, 
-                "No cycles"
-            ),
-            (
-                vec![("a", "b"), ("b", "a")], 
-                1, 
-                "Simple cycle"
+                vec![("a", "b"), ("a", "c"), ("b", "d"), ("c", "d")],
+                0,
+                "Diamond DAG (no cycle)",
             ),
+            (vec![("a", "a")], 1, "Self loop"),
             (
-                vec![("a", "b"), ("a", "c"), ("b", "d"), ("c", "d")], 
-                0, 
-                "Diamond DAG (no cycle)"
+                vec![("a", "b"), ("b", "c"), ("c", "a")],
+                1,
+                "Three node cycle",
             ),
             (
-                vec![("a", "a")], 
-                1, 
-                "Self loop"
-            ),
-            (
-                vec![("a", "b"), ("b", "c"), ("c", "a")], 
-                1, 
-                "Three node cycle"
-            ),
-            (
-                vec![("a", "b"), ("b", "a"), ("c", "d"), ("d", "c")], 
-                2, 
-                "Disjoint cycles"
+                vec![("a", "b"), ("b", "a"), ("c", "d"), ("d", "c")],
+                2,
+                "Disjoint cycles",
             ),
             (
                 vec![("a", "b"), ("b", "a"), ("b", "c"), ("c", "b")], // figure 8
-                2, 
-                "Figure-8 (shared node)"
-            ),
-            (
-                vec![("a", "b"), ("b", "c"), ("c", "d"), ("d", "e"), ("e", "a")], 
-                1, 
-                "Long cycle (5 nodes)"
-            ),
-            (
-                vec![], 
-                0, 
-                "Empty graph"
+                1,
+                "Figure-8 (shared node is one component)",
             ),
             (
-                vec![("a", "b")], 
-                0, 
-                "Single edge"
+                vec![("a", "b"), ("b", "c"), ("c", "d"), ("d", "e"), ("e", "a")],
+                1,
+                "Long cycle (5 nodes)",
             ),
+            (vec![], 0, "Empty graph"),
+            (vec![("a", "b")], 0, "Single edge"),
         ];
 
         for (edge_list, expected_count, desc) in cases {
-            // Convert to format needed by detect_cycles
-            let edge_vec = edges(&edge_list);
-            let edge_refs: Vec<(&Path, &Path)> = edge_vec.iter().map(|(a, b)| (a.as_ref(), b.as_ref())).collect();
-            
-            let cycles = detect_cycles(edge_refs.into_iter());
+            let cycles = cycles_of(&edge_list);
             assert_eq!(cycles.len(), expected_count, "Failed: {desc}");
 
             // Extra validation for specific cases to ensure correctness
             if desc == "Simple cycle" {
-                assert_eq!(cycles[0].len(), 3, "a->b->a length");
+                assert_eq!(cycles[0].members, vec![p("a"), p("b")]);
+                assert_eq!(cycles[0].break_edges.len(), 1, "one edge breaks a->b->a");
             }
             if desc == "Self loop" {
-                assert_eq!(cycles[0].len(), 2, "a->a length");
+                assert_eq!(breaks(&cycles[0]), vec![("a", "a", 1)]);
+            }
+            if desc.starts_with("Figure-8") {
+                assert_eq!(cycles[0].members.len(), 3);
+                assert_eq!(cycles[0].break_edges.len(), 2, "both loops need breaking");
             }
         }
     }
@@ -194,15 +188,42 @@ mod tests {
     #[test]
     fn test_cycle_content() {
         // Correct nodes check
-        let list = vec![("x", "y"), ("y", "z"), ("z", "x")];
-        let edge_vec = edges(&list);
-        let edge_refs: Vec<(&Path, &Path)> = edge_vec.iter().map(|(a, b)| (a.as_ref(), b.as_ref())).collect();
-        let cycles = detect_cycles(edge_refs.into_iter());
-        
+        let cycles = cycles_of(&[("x", "y"), ("y", "z"), ("z", "x"), ("z", "w")]);
+
+        assert_eq!(cycles.len(), 1);
+        assert_eq!(cycles[0].members, vec![p("x"), p("y"), p("z")]);
+    }
+
+    #[test]
+    fn test_break_edges_prefer_light_edges() {
+        // b imports a once; a imports b three times and c imports a twice.
+        let cycles = cycles_of(&[
+            ("a", "b"),
+            ("a", "b"),
+            ("a", "b"),
+            ("b", "a"),
+            ("a", "c"),
+            ("c", "a"),
+            ("c", "a"),
+        ]);
+
         assert_eq!(cycles.len(), 1);
-        let cycle = &cycles[0];
-        assert!(cycle.contains(&p("x")));
-        assert!(cycle.contains(&p("y")));
-        assert!(cycle.contains(&p("z")));
+        assert_eq!(breaks(&cycles[0]), vec![("a", "c", 1), ("b", "a", 1)]);
+    }
+
+    #[test]
+    fn test_break_set_is_minimal() {
+        // b->c and c->a are equally light and both get removed, but c->a
+        // alone breaks a->b->c->a and a->c->a, so b->c is restored.
+        let cycles = cycles_of(&[
+            ("a", "b"),
+            ("a", "b"),
+            ("b", "c"),
+            ("c", "a"),
+            ("a", "c"),
+            ("a", "c"),
+        ]);
+
+        assert_eq!(breaks(&cycles[0]), vec![("c", "a", 1)]);
     }
 }
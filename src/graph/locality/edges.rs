@@ -1,56 +1,132 @@
 // src/graph/locality/edges.rs
 //! Edge collection for locality analysis.
 //!
-//! Encapsulates import extraction and resolution so callers don't need
-//! to reach into graph internals.
+//! Encapsulates import resolution so callers don't need to reach into
+//! graph internals. Imports are resolved once per graph build into an
+//! [`ImportGraph`] that every graph check shares, along with each file's
+//! type counts, so no check re-reads or re-parses a file.
 
-use anyhow::Result;
+use std::collections::{BTreeMap, HashMap};
 use std::path::{Path, PathBuf};
 
-use crate::graph::imports;
-use crate::graph::resolver;
-
-/// Collects all dependency edges from the given source files.
-///
-/// # Arguments
-/// * `root` - Project root directory
-/// * `files` - Source files to analyze
-///
-/// # Returns
-/// Vec of (from, to) edges with paths relative to root.
-///
-/// # Errors
-/// Returns error if file reading fails.
-pub fn collect(root: &Path, files: &[PathBuf]) -> Result<Vec<(PathBuf, PathBuf)>> {
-    let mut edges = Vec::new();
-
-    for file in files {
-        let file_edges = collect_file_edges(root, file)?;
-        edges.extend(file_edges);
-    }
+use tree_sitter::Parser;
+
+use crate::graph::resolver::Resolver;
+use crate::graph::{defs, imports};
+use crate::lang::Lang;
+
+use super::abstractness::TypeCounts;
+use super::cycles::Cycle;
 
-    Ok(edges)
+/// Resolved imports per file, with the line each import is on, and each
+/// file's type counts. Paths are relative to the project root.
+#[derive(Debug, Default)]
+pub struct ImportGraph {
+    imports: BTreeMap<PathBuf, Vec<(PathBuf, usize)>>,
+    types: HashMap<PathBuf, TypeCounts>,
 }
 
-fn collect_file_edges(root: &Path, file: &Path) -> Result<Vec<(PathBuf, PathBuf)>> {
-    let content = std::fs::read_to_string(file)?;
-    let raw_imports = imports::extract(file, &content);
-
-    let edges = raw_imports
-        .iter()
-        .filter_map(|import_str| {
-            resolver::resolve(root, file, import_str).map(|resolved| {
-                let from = normalize(file, root);
-                let to = normalize(&resolved, root);
-                (from, to)
-            })
+impl ImportGraph {
+    /// Resolves already-extracted facts: each item is a file as scanned,
+    /// its raw imports with their lines and its type counts.
+    #[must_use]
+    pub fn resolve<'a, I>(root: &Path, files: I) -> Self
+    where
+        I: IntoIterator<Item = (&'a Path, &'a [(String, usize)], TypeCounts)>,
+    {
+        let resolver = Resolver::new(root);
+        let mut graph = Self::default();
+        for (file, raw, types) in files {
+            let targets = raw
+                .iter()
+                .filter_map(|(import, row)| {
+                    let target = resolver.resolve(file, import)?;
+                    Some((normalize(&target, root), *row))
+                })
+                .collect();
+            let from = normalize(file, root);
+            graph.types.insert(from.clone(), types);
+            graph.imports.insert(from, targets);
+        }
+        graph
+    }
+
+    /// Reads, parses and resolves `files`, parsing each once. Files that
+    /// cannot be read as UTF-8 are left out.
+    #[must_use]
+    pub fn read(root: &Path, files: &[PathBuf]) -> Self {
+        let facts: Vec<_> = files.iter().filter_map(|file| read_facts(file)).collect();
+        let items = facts
+            .iter()
+            .map(|(file, raw, types)| (*file, raw.as_slice(), *types));
+        Self::resolve(root, items)
+    }
+
+    /// Every dependency edge, once per import.
+    pub fn edges(&self) -> impl Iterator<Item = (&Path, &Path)> + Clone {
+        self.imports.iter().flat_map(|(from, targets)| {
+            targets
+                .iter()
+                .map(move |(to, _)| (from.as_path(), to.as_path()))
         })
-        .collect();
+    }
+
+    /// Files in the graph with their resolved imports.
+    pub fn files(&self) -> impl Iterator<Item = (&Path, &[(PathBuf, usize)])> {
+        self.imports
+            .iter()
+            .map(|(file, targets)| (file.as_path(), targets.as_slice()))
+    }
 
-    Ok(edges)
+    /// Type counts per file.
+    #[must_use]
+    pub fn types(&self) -> &HashMap<PathBuf, TypeCounts> {
+        &self.types
+    }
+
+    /// Lines in `from` that import `to`.
+    #[must_use]
+    pub fn rows(&self, from: &Path, to: &Path) -> Vec<usize> {
+        self.imports
+            .get(from)
+            .into_iter()
+            .flatten()
+            .filter(|(target, _)| target == to)
+            .map(|(_, row)| *row)
+            .collect()
+    }
+}
+
+/// Fills in the import rows of every break edge.
+pub fn locate_cycles(graph: &ImportGraph, cycles: &mut [Cycle]) {
+    for edge in cycles.iter_mut().flat_map(|c| c.break_edges.iter_mut()) {
+        edge.rows = graph.rows(&edge.from, &edge.to);
+    }
+}
+
+type Facts<'a> = (&'a Path, Vec<(String, usize)>, TypeCounts);
+
+/// Raw imports and type counts of one file from a single parse. Files in
+/// a language without a grammar have neither.
+fn read_facts(file: &Path) -> Option<Facts<'_>> {
+    let content = std::fs::read_to_string(file).ok()?;
+    let ext = file
+        .extension()
+        .and_then(|s| s.to_str())
+        .unwrap_or_default();
+    let Some(lang) = Lang::from_ext(ext) else {
+        return Some((file, Vec::new(), TypeCounts::default()));
+    };
+    let mut parser = Parser::new();
+    parser.set_language(lang.grammar()).ok()?;
+    let tree = parser.parse(&content, None)?;
+    let root = tree.root_node();
+    let raw = imports::extract_from_tree(lang, root, &content);
+    let types = TypeCounts::of(&defs::extract_from_tree(lang, root, &content));
+    Some((file, raw, types))
 }
 
 fn normalize(path: &Path, root: &Path) -> PathBuf {
     path.strip_prefix(root)
         .map_or_else(|_| path.to_path_buf(), Path::to_path_buf)
-}
\ No newline at end of file
+}
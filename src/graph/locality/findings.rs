@@ -0,0 +1,205 @@
+// src/graph/locality/findings.rs
+//! Locality results as scan violations.
+//!
+//! Failed edges, god modules and cycles are reported on the importing file
+//! at the line of the offending import, and modules in the zone of pain or
+//! uselessness on their entry file, so they reach the scan report, its
+//! JSON and the flight recorder like any other violation. Severity follows
+//! `[rules.locality].mode`: `error` fails the scan, `warn` only reports.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::config::Config;
+use crate::types::{Severity, Violation, ViolationDetails};
+
+use super::analysis::{analyze, CategorizedViolation, TopologyAnalysis, ViolationKind};
+use super::edges::ImportGraph;
+use super::modules::{compute_modules, ModuleMetrics};
+use super::{compute_coupling, validate_graph, BreakEdge, ValidationReport};
+
+/// Law reported for every locality finding.
+pub const LAW: &str = "LAW OF LOCALITY";
+
+type Findings = HashMap<PathBuf, Vec<Violation>>;
+
+/// Locality violations keyed by importing file, relative to the project
+/// root. Empty when `mode` is `off`.
+#[must_use]
+pub fn collect(graph: &ImportGraph, config: &Config) -> Findings {
+    let locality = &config.rules.locality;
+    if !locality.is_enabled() {
+        return HashMap::new();
+    }
+    let couplings = compute_coupling(graph.edges());
+    let mut report = validate_graph(graph.edges(), &config.validator_config());
+    report.locate_cycles(graph);
+    let analysis = analyze(&report, &couplings);
+
+    let mut findings = Findings::new();
+    let mut push = |from: &Path, to: &Path, violation: Violation| {
+        // 1 if the import line cannot be found.
+        let row = graph.rows(from, to).first().copied().unwrap_or(1);
+        let violation = Violation { row, ..violation };
+        findings
+            .entry(from.to_path_buf())
+            .or_default()
+            .push(violation);
+    };
+    for v in &analysis.violations {
+        push(&v.edge.from, &v.edge.to, edge_violation(v));
+    }
+    for (from, to, violation) in god_module_violations(&analysis) {
+        push(from, to, violation);
+    }
+    for (from, to, violation) in cycle_violations(&report) {
+        push(&from, &to, violation);
+    }
+    let modules = compute_modules(graph.edges(), graph.types(), &locality.to_zone_config());
+    for (file, violation) in zone_violations(&modules) {
+        findings.entry(file).or_default().push(violation);
+    }
+
+    let severity = if locality.is_error_mode() {
+        Severity::Error
+    } else {
+        Severity::Warning
+    };
+    for violation in findings.values_mut().flatten() {
+        violation.severity = severity;
+    }
+    findings
+}
+
+fn edge_violation(v: &CategorizedViolation) -> Violation {
+    let edge = &v.edge;
+    let message = format!(
+        "{}: {} ('{}' → '{}')",
+        v.kind.label(),
+        v.kind.description(),
+        edge.from.display(),
+        edge.to.display()
+    );
+    let details = ViolationDetails {
+        function_name: None,
+        analysis: vec![
+            format!("Distance {} between the files", edge.distance),
+            format!(
+                "Target is {} (skew {:.2})",
+                edge.target_identity.label(),
+                edge.target_skew
+            ),
+        ],
+        suggestion: Some(v.suggestion.clone()),
+    };
+    Violation::with_details(0, message, LAW, details)
+}
+
+/// One violation per god module, at its first failing import.
+fn god_module_violations(analysis: &TopologyAnalysis) -> Vec<(&Path, &Path, Violation)> {
+    let kind = ViolationKind::GodModule;
+    analysis
+        .god_modules
+        .iter()
+        .filter_map(|god| {
+            let first = analysis
+                .violations
+                .iter()
+                .find(|v| v.edge.from == god.path)?;
+            let message = format!(
+                "{}: {} ({} cross-boundary dependencies)",
+                kind.label(),
+                kind.description(),
+                god.outbound_violations
+            );
+            let details = ViolationDetails {
+                function_name: None,
+                analysis: god
+                    .targets
+                    .iter()
+                    .map(|t| format!("Depends on '{}'", t.display()))
+                    .collect(),
+                suggestion: Some(kind.suggest(&first.edge, 0)),
+            };
+            let violation = Violation::with_details(0, message, LAW, details);
+            Some((god.path.as_path(), first.edge.to.as_path(), violation))
+        })
+        .collect()
+}
+
+/// One violation per cycle, on the import of its lightest break edge.
+/// Every break edge is listed with its weight and import lines.
+fn cycle_violations(report: &ValidationReport) -> Vec<(PathBuf, PathBuf, Violation)> {
+    report
+        .cycles()
+        .iter()
+        .filter_map(|cycle| {
+            let lightest = cycle.break_edges.first()?;
+            let members: Vec<String> = cycle
+                .members
+                .iter()
+                .map(|p| p.display().to_string())
+                .collect();
+            let message = format!("CYCLE: Dependency cycle among {}", members.join(", "));
+            let details = ViolationDetails {
+                function_name: None,
+                analysis: cycle.break_edges.iter().map(describe_break).collect(),
+                suggestion: Some(format!(
+                    "Break the cycle: move what '{}' needs from '{}' into a module both can import",
+                    lightest.from.display(),
+                    lightest.to.display()
+                )),
+            };
+            let violation = Violation::with_details(0, message, LAW, details);
+            Some((lightest.from.clone(), lightest.to.clone(), violation))
+        })
+        .collect()
+}
+
+fn describe_break(edge: &BreakEdge) -> String {
+    let rows: Vec<String> = edge.rows.iter().map(ToString::to_string).collect();
+    format!(
+        "Remove '{}' → '{}' (weight {}, line {})",
+        edge.from.display(),
+        edge.to.display(),
+        edge.weight,
+        rows.join(", ")
+    )
+}
+
+/// One violation per module in a zone, on line 1 of its entry file.
+fn zone_violations(modules: &[ModuleMetrics]) -> Vec<(PathBuf, Violation)> {
+    modules
+        .iter()
+        .filter_map(|module| {
+            let zone = module.zone?;
+            let file = module.entry.clone()?;
+            let message = format!(
+                "{}: {} ('{}', D = {:.2})",
+                zone.label(),
+                zone.description(),
+                module.path.display(),
+                module.distance
+            );
+            let details = ViolationDetails {
+                function_name: None,
+                analysis: vec![
+                    format!(
+                        "Ca {} / Ce {}: instability {:.2}",
+                        module.coupling.afferent(),
+                        module.coupling.efferent(),
+                        module.instability()
+                    ),
+                    format!(
+                        "{} abstract / {} concrete types: abstractness {:.2}",
+                        module.types.abstract_types,
+                        module.types.concrete_types,
+                        module.abstractness()
+                    ),
+                ],
+                suggestion: Some(zone.suggestion().to_string()),
+            };
+            Some((file, Violation::with_details(1, message, LAW, details)))
+        })
+        .collect()
+}
@@ -9,6 +9,7 @@ use std::path::{Path, PathBuf};
 
 use super::types::LocalityEdge;
 use super::analysis::ViolationKind;
+use super::cycles::Cycle;
 
 /// Infers layers from a set of edges.
 /// Returns a map of Path -> Layer Index.
@@ -43,6 +44,36 @@ where
     layers
 }
 
+/// Infers layers on the condensed graph, where each cycle is a single
+/// node whose members share its layer. Unlike [`infer_layers`], one cycle
+/// does not push everything above it into a leftover layer.
+pub fn infer_condensed_layers<'a, I>(edges: I, cycles: &[Cycle]) -> HashMap<PathBuf, usize>
+where
+    I: Iterator<Item = (&'a Path, &'a Path)> + Clone,
+{
+    let representative: HashMap<&Path, &Path> = cycles
+        .iter()
+        .flat_map(|cycle| cycle.members.iter().map(move |m| (m, &cycle.members)))
+        .filter_map(|(member, members)| Some((member.as_path(), members.first()?.as_path())))
+        .collect();
+    let condense = |node: &'a Path| representative.get(node).map_or(node.to_path_buf(), |r| r.to_path_buf());
+
+    let condensed: Vec<(PathBuf, PathBuf)> = edges
+        .clone()
+        .map(|(from, to)| (condense(from), condense(to)))
+        .filter(|(from, to)| from != to)
+        .collect();
+    let layers = infer_layers(condensed.iter().map(|(a, b)| (a.as_path(), b.as_path())));
+
+    edges
+        .flat_map(|(from, to)| [from, to])
+        .map(|node| {
+            let layer = layers.get(&condense(node)).copied().unwrap_or_default();
+            (node.to_path_buf(), layer)
+        })
+        .collect()
+}
+
 fn build_dependency_map<'a, I>(edges: I) -> (HashSet<PathBuf>, HashMap<PathBuf, HashSet<PathBuf>>)
 where
     I: Iterator<Item = (&'a Path, &'a Path)>,
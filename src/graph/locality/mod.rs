@@ -4,6 +4,7 @@
 //! Implements the Universal Locality Algorithm with smart exemptions
 //! and deep analysis for actionable insights.
 
+pub mod abstractness;
 pub mod analysis;
 pub mod classifier;
 pub mod coupling;
@@ -11,18 +12,28 @@ pub mod cycles;
 pub mod distance;
 pub mod edges;
 pub mod exemptions;
+pub mod findings;
 pub mod layers;
+pub mod modules;
 pub mod report;
+pub mod scc;
 pub mod types;
 pub mod validator;
+pub mod zones;
 
+pub use abstractness::TypeCounts;
 pub use classifier::{classify, ClassifierConfig};
 pub use coupling::compute_coupling;
+pub use cycles::{find_cycles, BreakEdge, Cycle};
 pub use distance::compute_distance;
-pub use edges::collect as collect_edges;
+pub use edges::{locate_cycles, ImportGraph};
 pub use exemptions::is_structural_pattern;
+pub use findings::collect as collect_findings;
+pub use layers::infer_condensed_layers;
+pub use modules::{compute_modules, module_of, ModuleMetrics};
 pub use types::{Coupling, EdgeVerdict, LocalityEdge, NodeIdentity, PassReason};
 pub use validator::{validate_edge, validate_graph, ValidationReport, ValidatorConfig};
+pub use zones::{Zone, ZoneConfig};
 
 #[cfg(test)]
 mod tests;
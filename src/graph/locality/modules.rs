@@ -0,0 +1,178 @@
+// src/graph/locality/modules.rs
+//! Module-level Martin metrics.
+//!
+//! Files are grouped into modules by directory. Each module gets afferent
+//! and efferent coupling (distinct files outside it on either side of its
+//! imports), instability I = Cₑ / (Cₐ + Cₑ), abstractness A = abstract
+//! types / all types, and its distance from the main sequence
+//! D = |A + I − 1|. Modules far from the main sequence are flagged by
+//! [`super::zones`].
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+
+use super::abstractness::TypeCounts;
+use super::types::Coupling;
+use super::zones::{zone_of, Zone, ZoneConfig};
+
+/// Martin metrics for one module (directory).
+#[derive(Debug, Clone)]
+pub struct ModuleMetrics {
+    pub path: PathBuf,
+    /// Scanned files in the module.
+    pub files: usize,
+    /// File module-level findings are reported on: the module's `mod.rs`,
+    /// `__init__.py` or similar, else its first file.
+    pub entry: Option<PathBuf>,
+    pub coupling: Coupling,
+    pub types: TypeCounts,
+    /// D = |A + I − 1|.
+    pub distance: f64,
+    pub zone: Option<Zone>,
+}
+
+impl ModuleMetrics {
+    #[must_use]
+    pub fn instability(&self) -> f64 {
+        self.coupling.instability()
+    }
+
+    #[must_use]
+    pub fn abstractness(&self) -> f64 {
+        self.types.abstractness()
+    }
+}
+
+/// The module a file belongs to: its directory, or `.` at the root.
+#[must_use]
+pub fn module_of(path: &Path) -> PathBuf {
+    match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir.to_path_buf(),
+        _ => PathBuf::from("."),
+    }
+}
+
+/// Metrics for every module holding a file in `types` or an end of an
+/// edge, sorted by path.
+#[allow(clippy::implicit_hasher)]
+pub fn compute_modules<'a, I>(
+    edges: I,
+    types: &HashMap<PathBuf, TypeCounts>,
+    config: &ZoneConfig,
+) -> Vec<ModuleMetrics>
+where
+    I: Iterator<Item = (&'a Path, &'a Path)>,
+{
+    let mut members: BTreeMap<PathBuf, (usize, TypeCounts)> = BTreeMap::new();
+    for (path, counts) in types {
+        let (files, total) = members.entry(module_of(path)).or_default();
+        *files += 1;
+        total.abstract_types += counts.abstract_types;
+        total.concrete_types += counts.concrete_types;
+    }
+
+    let mut afferent: HashMap<PathBuf, BTreeSet<&Path>> = HashMap::new();
+    let mut efferent: HashMap<PathBuf, BTreeSet<&Path>> = HashMap::new();
+    for (from, to) in edges {
+        let (source, target) = (module_of(from), module_of(to));
+        if source == target {
+            continue;
+        }
+        members.entry(source.clone()).or_default();
+        members.entry(target.clone()).or_default();
+        efferent.entry(source).or_default().insert(to);
+        afferent.entry(target).or_default().insert(from);
+    }
+
+    let mut paths: Vec<&PathBuf> = types.keys().collect();
+    paths.sort();
+    members
+        .into_iter()
+        .map(|(path, (files, types))| {
+            let entry = entry_file(&path, &paths);
+            let ca = afferent.get(&path).map_or(0, BTreeSet::len);
+            let ce = efferent.get(&path).map_or(0, BTreeSet::len);
+            let coupling = Coupling::new(ca, ce);
+            let distance = (types.abstractness() + coupling.instability() - 1.0).abs();
+            let zone = zone_of(&coupling, &types, distance, config);
+            ModuleMetrics {
+                path,
+                files,
+                entry,
+                coupling,
+                types,
+                distance,
+                zone,
+            }
+        })
+        .collect()
+}
+
+/// Entry file names, most preferred first.
+const ENTRY_FILES: &[&str] = &["mod.rs", "lib.rs", "main.rs", "__init__.py", "index.ts"];
+
+fn entry_file(module: &Path, sorted_files: &[&PathBuf]) -> Option<PathBuf> {
+    let mut members = sorted_files.iter().filter(|f| module_of(f) == module);
+    let entry = ENTRY_FILES.iter().find_map(|name| {
+        members
+            .clone()
+            .find(|f| f.file_name().is_some_and(|n| n == *name))
+    });
+    entry.or_else(|| members.next()).map(|f| (*f).clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn counts(abstract_types: usize, concrete_types: usize) -> TypeCounts {
+        TypeCounts {
+            abstract_types,
+            concrete_types,
+        }
+    }
+
+    #[test]
+    fn test_martin_metrics_and_zones() {
+        let types = HashMap::from([
+            (PathBuf::from("src/core/model.rs"), counts(0, 4)),
+            (PathBuf::from("src/ports/store.rs"), counts(3, 0)),
+            (PathBuf::from("src/app/a.rs"), counts(0, 1)),
+            (PathBuf::from("src/app/b.rs"), counts(0, 1)),
+        ]);
+        let edges = [
+            ("src/app/a.rs", "src/core/model.rs"),
+            ("src/app/b.rs", "src/core/model.rs"),
+            ("src/ports/store.rs", "src/core/model.rs"),
+            ("src/app/a.rs", "src/app/b.rs"),
+        ]
+        .map(|(from, to)| (Path::new(from), Path::new(to)));
+
+        let config = ZoneConfig {
+            min_coupling: 1,
+            ..ZoneConfig::default()
+        };
+        let modules = compute_modules(edges.into_iter(), &types, &config);
+        let paths: Vec<&Path> = modules.iter().map(|m| m.path.as_path()).collect();
+        let expected = ["src/app", "src/core", "src/ports"].map(Path::new);
+        assert_eq!(paths, expected);
+
+        let [app, core, ports] = modules.as_slice() else {
+            panic!("expected three modules");
+        };
+        assert_eq!(
+            (app.files, app.coupling.afferent(), app.coupling.efferent()),
+            (2, 0, 1)
+        );
+        assert_eq!(app.zone, None, "app has too few types to judge");
+
+        assert_eq!((core.coupling.afferent(), core.coupling.efferent()), (3, 0));
+        assert!((core.distance - 1.0).abs() < f64::EPSILON);
+        assert_eq!(core.zone, Some(Zone::Pain));
+        assert_eq!(core.entry.as_deref(), Some(Path::new("src/core/model.rs")));
+
+        assert!((ports.abstractness() - 1.0).abs() < f64::EPSILON);
+        assert!((ports.instability() - 1.0).abs() < f64::EPSILON);
+        assert_eq!(ports.zone, Some(Zone::Uselessness));
+    }
+}
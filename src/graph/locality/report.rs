@@ -4,14 +4,22 @@
 use colored::Colorize;
 
 use super::analysis::{TopologyAnalysis, ViolationKind};
-use super::ValidationReport;
+use super::{ModuleMetrics, ValidationReport};
 
 /// Prints a comprehensive locality report.
-pub fn print_full_report(report: &ValidationReport, analysis: &TopologyAnalysis) {
+pub fn print_full_report(
+    report: &ValidationReport,
+    analysis: &TopologyAnalysis,
+    modules: &[ModuleMetrics],
+) {
     print_summary(report);
+    print_cycles(report);
+    print_modules(modules);
 
     if analysis.violations.is_empty() {
-        println!("{}", "  ✓ All dependencies respect locality.".green());
+        if report.cycles().is_empty() {
+            println!("{}", "  ✓ All dependencies respect locality.".green());
+        }
         return;
     }
 
@@ -43,6 +51,7 @@ fn print_summary(report: &ValidationReport) {
         "  Violations:   {}",
         format_count(report.failed().len())
     );
+    println!("  Cycles:       {}", format_count(report.cycles().len()));
 }
 
 fn format_count(n: usize) -> String {
@@ -53,6 +62,56 @@ fn format_count(n: usize) -> String {
     }
 }
 
+fn print_cycles(report: &ValidationReport) {
+    if report.cycles().is_empty() {
+        return;
+    }
+
+    println!("\n{}", "DEPENDENCY CYCLES".red().bold());
+    for cycle in report.cycles() {
+        println!("  {} files:", cycle.members.len());
+        for member in &cycle.members {
+            println!("    {}", member.display());
+        }
+        for edge in &cycle.break_edges {
+            let rows: Vec<String> = edge
+                .rows
+                .iter()
+                .map(|row| format!("{}:{row}", edge.from.display()))
+                .collect();
+            println!(
+                "      break {} -> {} (weight {}) {}",
+                edge.from.display(),
+                edge.to.display(),
+                edge.weight,
+                rows.join(", ").dimmed()
+            );
+        }
+    }
+}
+
+fn print_modules(modules: &[ModuleMetrics]) {
+    let coupled: Vec<&ModuleMetrics> = modules.iter().filter(|m| m.coupling.total() > 0).collect();
+    if coupled.is_empty() {
+        return;
+    }
+
+    println!("\n{}", "MODULE METRICS".cyan().bold());
+    println!("  {:<40} {:>4} {:>4} {:>5} {:>5} {:>5}", "module", "Ca", "Ce", "I", "A", "D");
+    for m in coupled {
+        let zone = m.zone.map_or(String::new(), |z| z.label().red().to_string());
+        println!(
+            "  {:<40} {:>4} {:>4} {:>5.2} {:>5.2} {:>5.2} {zone}",
+            m.path.display(),
+            m.coupling.afferent(),
+            m.coupling.efferent(),
+            m.instability(),
+            m.abstractness(),
+            m.distance
+        );
+    }
+}
+
 fn print_violations_by_category(analysis: &TopologyAnalysis) {
     let mut by_kind: std::collections::HashMap<&ViolationKind, Vec<_>> =
         std::collections::HashMap::new();
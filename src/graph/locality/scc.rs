@@ -0,0 +1,122 @@
+// src/graph/locality/scc.rs
+//! Strongly connected components, by Tarjan's algorithm.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::path::Path;
+
+type Adjacency<'a> = BTreeMap<&'a Path, BTreeSet<&'a Path>>;
+
+/// Strongly connected components that contain a cycle, each sorted.
+pub fn components<'a>(edges: impl Iterator<Item = (&'a Path, &'a Path)>) -> Vec<Vec<&'a Path>> {
+    let mut adjacency = Adjacency::new();
+    for (from, to) in edges {
+        adjacency.entry(from).or_default().insert(to);
+        adjacency.entry(to).or_default();
+    }
+    let mut tarjan = Tarjan {
+        adjacency: &adjacency,
+        index: HashMap::new(),
+        low: HashMap::new(),
+        stack: Vec::new(),
+        on_stack: HashSet::new(),
+        components: Vec::new(),
+    };
+    for node in adjacency.keys() {
+        if !tarjan.index.contains_key(node) {
+            tarjan.visit(node);
+        }
+    }
+    let is_cyclic = |members: &Vec<&Path>| match members.as_slice() {
+        [node] => adjacency.get(node).is_some_and(|next| next.contains(node)),
+        _ => true,
+    };
+    tarjan.components.into_iter().filter(is_cyclic).collect()
+}
+
+struct Tarjan<'g, 'a> {
+    adjacency: &'g Adjacency<'a>,
+    index: HashMap<&'a Path, usize>,
+    low: HashMap<&'a Path, usize>,
+    stack: Vec<&'a Path>,
+    on_stack: HashSet<&'a Path>,
+    components: Vec<Vec<&'a Path>>,
+}
+
+impl<'a> Tarjan<'_, 'a> {
+    fn visit(&mut self, node: &'a Path) {
+        let index = self.index.len();
+        self.index.insert(node, index);
+        self.low.insert(node, index);
+        self.stack.push(node);
+        self.on_stack.insert(node);
+
+        let adjacency = self.adjacency;
+        for next in adjacency.get(node).into_iter().flatten() {
+            self.follow(node, next);
+        }
+        if self.low.get(node) == Some(&index) {
+            self.pop_component(node);
+        }
+    }
+
+    fn follow(&mut self, node: &'a Path, next: &'a Path) {
+        let reached = if self.index.contains_key(next) {
+            self.on_stack
+                .contains(next)
+                .then(|| self.index.get(next).copied())
+        } else {
+            self.visit(next);
+            Some(self.low.get(next).copied())
+        };
+        if let (Some(Some(reached)), Some(low)) = (reached, self.low.get_mut(node)) {
+            *low = (*low).min(reached);
+        }
+    }
+
+    fn pop_component(&mut self, root: &'a Path) {
+        let mut members = Vec::new();
+        while let Some(top) = self.stack.pop() {
+            self.on_stack.remove(top);
+            members.push(top);
+            if top == root {
+                break;
+            }
+        }
+        members.sort();
+        self.components.push(members);
+    }
+}
+
+/// Edges that lie on some cycle: both ends in the same component.
+pub fn cyclic_edges<'a>(edges: &[(&'a Path, &'a Path)]) -> Vec<(&'a Path, &'a Path)> {
+    let component_of: HashMap<&Path, usize> = components(edges.iter().copied())
+        .into_iter()
+        .enumerate()
+        .flat_map(|(i, members)| members.into_iter().map(move |m| (m, i)))
+        .collect();
+    edges
+        .iter()
+        .filter(|(from, to)| {
+            component_of.contains_key(from) && component_of.get(from) == component_of.get(to)
+        })
+        .copied()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_components_skip_acyclic_nodes() {
+        let edges = [("a", "b"), ("b", "a"), ("b", "c"), ("d", "d")]
+            .map(|(from, to)| (Path::new(from), Path::new(to)));
+
+        let found = components(edges.iter().copied());
+        assert_eq!(
+            found,
+            vec![vec![Path::new("a"), Path::new("b")], vec![Path::new("d")]]
+        );
+        assert_eq!(cyclic_edges(&edges).len(), 3);
+    }
+}
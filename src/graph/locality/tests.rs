@@ -11,9 +11,17 @@
 mod integration {
     use super::super::analysis::violations::{categorize_violation, ViolationKind};
     use super::super::coupling::compute_coupling;
+    use super::super::edges::ImportGraph;
+    use super::super::findings::{collect, LAW};
     use super::super::layers::infer_layers;
     use super::super::validator::{validate_graph, ValidatorConfig};
+    use crate::config::architecture::{Architecture, ArchitectureConfig, LayerConfig};
+    use crate::config::Config;
+    use crate::types::Severity;
+    use anyhow::Result;
+    use std::fs;
     use std::path::{Path, PathBuf};
+    use tempfile::TempDir;
 
     /// Helper to run validation and extract failed edges' violation kinds.
     fn run_and_categorize(
@@ -245,9 +253,33 @@ mod integration {
             "Should detect the A->B->C->A cycle"
         );
         assert!(
-            report.cycles()[0].len() >= 3,
+            report.cycles()[0].members.len() >= 3,
             "Cycle should involve at least 3 nodes, got {}",
-            report.cycles()[0].len()
+            report.cycles()[0].members.len()
+        );
+    }
+
+    // ========================================================================
+    // TEST 6b: Validation Continues Past Cycles
+    // Edges outside a cycle are still judged on the condensed graph.
+    // ========================================================================
+    #[test]
+    fn test_cycle_does_not_hide_other_violations() {
+        let edges = vec![
+            (Path::new("src/a.rs"), Path::new("src/b.rs")),
+            (Path::new("src/b.rs"), Path::new("src/a.rs")),
+            (Path::new("src/a.rs"), Path::new("src/deep/x/y/z/w.rs")),
+        ];
+
+        let config = ValidatorConfig::default();
+        let report = validate_graph(edges.iter().map(|(a, b)| (*a, *b)), &config);
+
+        assert_eq!(report.cycles().len(), 1);
+        assert_eq!(report.total_edges(), 3);
+        assert_eq!(report.failed().len(), 1, "distant import still fails");
+        assert_eq!(
+            report.layers().get(Path::new("src/a.rs")),
+            report.layers().get(Path::new("src/b.rs"))
         );
     }
 
@@ -310,4 +342,115 @@ mod integration {
             report.failed().len()
         );
     }
+
+    fn with_mode(mode: &str) -> Config {
+        let mut config = Config::default();
+        config.rules.locality.mode = mode.into();
+        config
+    }
+
+    // ========================================================================
+    // TEST 9: Cycle Findings
+    // A cycle is reported once, at the import of its lightest break edge.
+    // A file that is not UTF-8 is left out rather than failing the rest.
+    // ========================================================================
+    #[test]
+    fn test_cycle_is_reported_on_lightest_break_edge() -> Result<()> {
+        let dir = TempDir::new()?;
+        let src = dir.path().join("src");
+        fs::create_dir_all(&src)?;
+        fs::write(src.join("lib.rs"), "mod a;\nmod b;\n")?;
+        fs::write(src.join("a.rs"), "use crate::b;\nuse crate::b;\n")?;
+        fs::write(src.join("b.rs"), "// b\n\nuse crate::a;\n")?;
+        fs::write(src.join("latin1.rs"), b"// caf\xe9\n")?;
+        let files: Vec<PathBuf> = ["src/lib.rs", "src/a.rs", "src/b.rs", "src/latin1.rs"]
+            .into_iter()
+            .map(|f| dir.path().join(f))
+            .collect();
+        let graph = ImportGraph::read(dir.path(), &files);
+
+        let findings = collect(&graph, &with_mode("warn"));
+        let b = &findings[Path::new("src/b.rs")];
+        assert_eq!(b.len(), 1);
+        assert_eq!(b[0].row, 3);
+        assert_eq!(b[0].law, LAW);
+        assert_eq!(b[0].severity, Severity::Warning);
+        assert!(b[0].message.contains("among src/a.rs, src/b.rs"));
+        let analysis = b[0].details.as_ref().map(|d| d.analysis.as_slice());
+        let expected = "Remove 'src/b.rs' → 'src/a.rs' (weight 1, line 3)";
+        assert_eq!(analysis, Some(&[expected.to_string()][..]));
+
+        let findings = collect(&graph, &with_mode("error"));
+        assert_eq!(findings[Path::new("src/b.rs")][0].severity, Severity::Error);
+
+        assert!(collect(&graph, &with_mode("off")).is_empty());
+        Ok(())
+    }
+
+    // ========================================================================
+    // TEST 10: Zone Findings
+    // A stable, concrete module is reported on its entry file.
+    // ========================================================================
+    #[test]
+    fn test_zone_of_pain_is_reported_on_entry_file() -> Result<()> {
+        let dir = TempDir::new()?;
+        let src = dir.path().join("src");
+        fs::create_dir_all(src.join("core"))?;
+        fs::create_dir_all(src.join("app"))?;
+        fs::write(src.join("core/mod.rs"), "pub mod model;\n")?;
+        fs::write(
+            src.join("core/model.rs"),
+            "struct A;\nstruct B;\nenum C {}\n",
+        )?;
+        let mut files = vec![src.join("core/mod.rs"), src.join("core/model.rs")];
+        for name in ["a", "b", "c", "d", "e"] {
+            let file = src.join(format!("app/{name}.rs"));
+            fs::write(&file, "use crate::core::model;\n")?;
+            files.push(file);
+        }
+
+        let graph = ImportGraph::read(dir.path(), &files);
+        let findings = collect(&graph, &Config::default());
+        let entry = &findings[Path::new("src/core/mod.rs")];
+        assert_eq!((entry.len(), entry[0].row), (1, 1));
+        assert!(entry[0].message.starts_with("ZONE_OF_PAIN"));
+        Ok(())
+    }
+
+    // ========================================================================
+    // TEST 11: Declared Layers
+    // Edges between declared `[architecture]` layers are left to the
+    // architecture check; the rest are still judged by locality.
+    // ========================================================================
+    #[test]
+    fn test_declared_layers_replace_inferred_checks() {
+        let edges = [
+            (
+                Path::new("src/ui/view.rs"),
+                Path::new("src/domain/model.rs"),
+            ),
+            (Path::new("src/ui/view.rs"), Path::new("src/util/fmt.rs")),
+        ];
+        let strict = ValidatorConfig {
+            max_distance: 0,
+            l1_threshold: 0,
+            ..ValidatorConfig::default()
+        };
+        assert_eq!(validate_graph(edges.into_iter(), &strict).failed().len(), 2);
+
+        let layer = |name: &str, glob: &str| LayerConfig {
+            name: name.into(),
+            paths: vec![glob.into()],
+            allow: None,
+        };
+        let (declared, errors) = Architecture::compile(ArchitectureConfig {
+            layers: vec![layer("ui", "src/ui/**"), layer("domain", "src/domain/**")],
+            forbid: Vec::new(),
+        });
+        assert!(errors.is_empty());
+        let config = ValidatorConfig { declared, ..strict };
+        let report = validate_graph(edges.into_iter(), &config);
+        let failed: Vec<&Path> = report.failed().iter().map(|e| e.to.as_path()).collect();
+        assert_eq!(failed, vec![Path::new("src/util/fmt.rs")]);
+    }
 }
@@ -111,4 +111,6 @@ pub enum PassReason {
     WithinDistance,
     VerticalRouting,
     Exempted,
+    /// Both ends lie in declared `[architecture]` layers.
+    Declared,
 }
\ No newline at end of file
@@ -3,11 +3,14 @@
 
 use std::path::{Path, PathBuf};
 
+use crate::config::architecture::Architecture;
+
 use super::classifier::{classify, ClassifierConfig};
 use super::coupling::compute_coupling;
-use super::cycles::detect_cycles;
-use super::layers::{infer_layers, check_layer_violation};
+use super::cycles::{find_cycles, Cycle};
+use super::layers::{infer_condensed_layers, check_layer_violation};
 use super::distance::compute_distance;
+use super::edges::{locate_cycles, ImportGraph};
 use super::exemptions::is_structural_pattern;
 use super::types::{Coupling, EdgeVerdict, LocalityEdge, NodeIdentity, PassReason};
 
@@ -18,6 +21,9 @@ pub struct ValidatorConfig {
     pub l1_threshold: usize,
     pub classifier: ClassifierConfig,
     pub exempt_patterns: Vec<String>,
+    /// Declared `[architecture]`; edges between its layers pass here and
+    /// are judged by `graph::architecture` instead.
+    pub declared: Architecture,
 }
 
 impl Default for ValidatorConfig {
@@ -27,6 +33,7 @@ impl Default for ValidatorConfig {
             l1_threshold: 2,
             classifier: ClassifierConfig::default(),
             exempt_patterns: Vec::new(),
+            declared: Architecture::default(),
         }
     }
 }
@@ -36,7 +43,7 @@ impl Default for ValidatorConfig {
 pub struct ValidationReport {
     passed: Vec<LocalityEdge>,
     failed: Vec<LocalityEdge>,
-    cycles: Vec<Vec<PathBuf>>,
+    cycles: Vec<Cycle>,
     layers: std::collections::HashMap<PathBuf, usize>,
     total_edges: usize,
     entropy: f64,
@@ -59,11 +66,16 @@ impl ValidationReport {
 
     #[must_use] pub fn passed(&self) -> &[LocalityEdge] { &self.passed }
     #[must_use] pub fn failed(&self) -> &[LocalityEdge] { &self.failed }
-    #[must_use] pub fn cycles(&self) -> &[Vec<PathBuf>] { &self.cycles }
+    #[must_use] pub fn cycles(&self) -> &[Cycle] { &self.cycles }
     #[must_use] pub fn layers(&self) -> &std::collections::HashMap<PathBuf, usize> { &self.layers }
     #[must_use] pub fn total_edges(&self) -> usize { self.total_edges }
     #[must_use] pub fn entropy(&self) -> f64 { self.entropy }
 
+    /// Fills in the import lines of each cycle's break edges.
+    pub fn locate_cycles(&mut self, graph: &ImportGraph) {
+        locate_cycles(graph, &mut self.cycles);
+    }
+
     /// Internal cohesion check to satisfy structural requirements.
     #[must_use]
     pub fn check_cohesion(&self) -> bool {
@@ -95,17 +107,15 @@ pub fn validate_edge(
         return EdgeVerdict::Pass { reason };
     }
 
+    // Inferred layers are a heuristic and `analysis` re-derives the kind
+    // from the edge alone. Declared `[architecture]` layers are exact, so
+    // edges between them are left to `graph::architecture`.
+    if config.declared.covers(from, to) {
+        return EdgeVerdict::Pass { reason: PassReason::Declared };
+    }
     if let Some(kind) = check_layer_violation(&edge, layers) {
-         let suggestion = kind.suggest(&edge, target_coupling.afferent());
-         // Overwrite the kind in EdgeVerdict? 
-         // EdgeVerdict::Fail stores the edge and suggestion. 
-         // But analysis.rs re-categorizes it using categorize_violation.
-         // We might need to handle this. For now, let it fail and let analysis derive the kind.
-         // Actually, analysis.rs needs to know about layers to categorize it as "Sideways/Upward".
-         // The current analyze function doesn't take layers map.
-         // This is a disconnect. 
-         // For now, loop this back to Fail.
-         return EdgeVerdict::Fail { edge, suggestion };
+        let suggestion = kind.suggest(&edge, target_coupling.afferent());
+        return EdgeVerdict::Fail { edge, suggestion };
     }
 
     // Default failure if not caught above (Sideways)
@@ -187,17 +197,16 @@ where
     I: Iterator<Item = (&'a Path, &'a Path)> + Clone,
 {
     let coupling_map = compute_coupling(edges.clone());
-    let mut report = ValidationReport::default();
-
-    // Phase 0: Cycle Detection (Hard Error)
-    let cycles = detect_cycles(edges.clone());
-    if !cycles.is_empty() {
-        report.cycles = cycles;
-        return report;
-    }
 
-    let layers = infer_layers(edges.clone());
-    report.layers.clone_from(&layers);
+    // Phase 0: Cycle Detection (Hard Error). Validation goes on over the
+    // condensed graph so one cycle doesn't hide every other problem.
+    let cycles = find_cycles(edges.clone());
+    let layers = infer_condensed_layers(edges.clone(), &cycles);
+    let mut report = ValidationReport {
+        cycles,
+        layers: layers.clone(),
+        ..ValidationReport::default()
+    };
 
     for (from, to) in edges {
         report.total_edges += 1;
@@ -0,0 +1,84 @@
+// src/graph/locality/zones.rs
+//! Zones off the main sequence.
+//!
+//! A module far from the main sequence sits in the zone of pain (stable
+//! and concrete, so every change ripples outward) or the zone of
+//! uselessness (abstract and unstable, so nothing relies on it).
+
+use super::abstractness::TypeCounts;
+use super::types::Coupling;
+
+/// Thresholds for flagging modules off the main sequence.
+#[derive(Debug, Clone)]
+pub struct ZoneConfig {
+    /// D above this puts a module in a zone.
+    pub max_distance: f64,
+    /// Modules with fewer type definitions are never flagged.
+    pub min_types: usize,
+    /// Modules with a smaller Cₐ + Cₑ are never flagged.
+    pub min_coupling: usize,
+}
+
+impl Default for ZoneConfig {
+    fn default() -> Self {
+        Self {
+            max_distance: 0.7,
+            min_types: 3,
+            min_coupling: 5,
+        }
+    }
+}
+
+/// Which side of the main sequence a module has strayed to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Zone {
+    /// A + I < 1: stable and concrete.
+    Pain,
+    /// A + I > 1: abstract and unstable.
+    Uselessness,
+}
+
+impl Zone {
+    #[must_use]
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Pain => "ZONE_OF_PAIN",
+            Self::Uselessness => "ZONE_OF_USELESSNESS",
+        }
+    }
+
+    #[must_use]
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::Pain => "Concrete module that many files depend on",
+            Self::Uselessness => "Abstract module that little depends on",
+        }
+    }
+
+    #[must_use]
+    pub fn suggestion(&self) -> &'static str {
+        match self {
+            Self::Pain => "Put an abstraction in front of it, or reduce what depends on it",
+            Self::Uselessness => "Drop the unused abstractions, or make them concrete",
+        }
+    }
+}
+
+/// The zone a module with these metrics is in, if it is judged at all.
+pub fn zone_of(
+    coupling: &Coupling,
+    types: &TypeCounts,
+    distance: f64,
+    config: &ZoneConfig,
+) -> Option<Zone> {
+    let judged =
+        coupling.total() >= config.min_coupling.max(1) && types.total() >= config.min_types;
+    if !judged || distance <= config.max_distance {
+        return None;
+    }
+    if types.abstractness() + coupling.instability() < 1.0 {
+        Some(Zone::Pain)
+    } else {
+        Some(Zone::Uselessness)
+    }
+}
@@ -1,7 +1,10 @@
 // src/graph/mod.rs
+pub mod architecture;
 pub mod defs;
+pub mod export;
 pub mod imports;
 pub mod locality;
+pub mod nimble;
 pub mod rank;
 pub mod resolver;
 pub mod tsconfig;
\ No newline at end of file
@@ -0,0 +1,163 @@
+// src/graph/nimble.rs
+//! Nim module search paths from `.nimble`, `nim.cfg` and `config.nims`.
+
+use std::path::{Component, Path, PathBuf};
+
+/// Module search paths for a Nim project, in lookup order.
+#[derive(Debug, Default)]
+pub struct NimbleProject {
+    pub src_dir: PathBuf,
+    pub search_paths: Vec<PathBuf>,
+    pub package_dirs: Vec<PathBuf>,
+}
+
+impl NimbleProject {
+    /// Loads the project layout rooted at `root`.
+    ///
+    /// Only paths inside `root` are kept: packages installed globally are
+    /// treated like external crates and never produce graph edges.
+    #[must_use]
+    pub fn load(root: &Path) -> Self {
+        let src_dir = find_nimble_file(root)
+            .and_then(|f| std::fs::read_to_string(f).ok())
+            .and_then(|content| parse_src_dir(&content))
+            .map_or_else(|| root.to_path_buf(), |dir| root.join(dir));
+
+        let search_paths = ["nim.cfg", "config.nims"]
+            .iter()
+            .filter_map(|name| std::fs::read_to_string(root.join(name)).ok())
+            .flat_map(|content| parse_paths(&content))
+            .map(|p| root.join(p))
+            .filter(|p| is_inside(p, root))
+            .collect();
+
+        Self {
+            src_dir,
+            search_paths,
+            package_dirs: local_packages(root),
+        }
+    }
+
+    /// Resolves a module path (`a/b`, with any `pkg/` prefix removed) to a file.
+    #[must_use]
+    pub fn resolve(&self, module: &str) -> Option<PathBuf> {
+        std::iter::once(&self.src_dir)
+            .chain(&self.search_paths)
+            .find_map(|dir| find_nim_file(&dir.join(module)))
+            .or_else(|| self.resolve_package(module))
+    }
+
+    fn resolve_package(&self, module: &str) -> Option<PathBuf> {
+        let name = module.split('/').next()?;
+        self.package_dirs
+            .iter()
+            .filter(|dir| is_package_dir(dir, name))
+            .find_map(|dir| find_nim_file(&dir.join(module)))
+    }
+}
+
+fn is_inside(path: &Path, root: &Path) -> bool {
+    path.starts_with(root) && !path.components().any(|c| c == Component::ParentDir)
+}
+
+/// Returns `path.nim` if it exists.
+#[must_use]
+pub fn find_nim_file(path: &Path) -> Option<PathBuf> {
+    let file = path.with_extension("nim");
+    file.is_file().then_some(file)
+}
+
+fn find_nimble_file(root: &Path) -> Option<PathBuf> {
+    std::fs::read_dir(root)
+        .ok()?
+        .filter_map(Result::ok)
+        .map(|e| e.path())
+        .find(|p| p.extension().is_some_and(|ext| ext == "nimble"))
+}
+
+/// Installed package directories in nimble's local deps mode (`nimble -l`).
+fn local_packages(root: &Path) -> Vec<PathBuf> {
+    ["nimbledeps/pkgs2", "nimbledeps/pkgs"]
+        .iter()
+        .filter_map(|dir| std::fs::read_dir(root.join(dir)).ok())
+        .flat_map(|entries| entries.filter_map(Result::ok).map(|e| e.path()))
+        .filter(|p| p.is_dir())
+        .collect()
+}
+
+/// Nimble names package dirs `name-version[-checksum]`.
+fn is_package_dir(dir: &Path, name: &str) -> bool {
+    dir.file_name()
+        .and_then(|n| n.to_str())
+        .is_some_and(|n| n == name || n.strip_prefix(name).is_some_and(|rest| rest.starts_with('-')))
+}
+
+fn parse_src_dir(content: &str) -> Option<String> {
+    content.lines().find_map(|line| {
+        let (key, value) = line.split_once('=')?;
+        (key.trim() == "srcDir").then(|| unquote(value))
+    })
+}
+
+/// Extracts `--path:"x"` (nim.cfg) and `switch("path", "x")` (config.nims) entries.
+fn parse_paths(content: &str) -> Vec<String> {
+    content
+        .lines()
+        .map(str::trim)
+        .filter_map(|line| {
+            let value = line
+                .strip_prefix("--path:")
+                .or_else(|| line.strip_prefix("path:"))
+                .or_else(|| line.strip_prefix("path="))
+                .or_else(|| {
+                    line.strip_prefix("switch(\"path\",")
+                        .and_then(|rest| rest.strip_suffix(')'))
+                })?;
+            Some(unquote(value))
+        })
+        .collect()
+}
+
+fn unquote(value: &str) -> String {
+    value.trim().trim_matches('"').to_string()
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_parse_nimble_layout() {
+        let nimble = "version = \"0.1.0\"\nsrcDir        = \"src\"\nbin = @[\"app\"]\n";
+        assert_eq!(parse_src_dir(nimble).as_deref(), Some("src"));
+
+        let cfg = "--path:\"vendor/lib\"\nswitch(\"path\", \"third_party\")\n--threads:on\n";
+        assert_eq!(parse_paths(cfg), vec!["vendor/lib", "third_party"]);
+    }
+
+    #[test]
+    fn test_package_dir_names() {
+        assert!(is_package_dir(Path::new("pkgs2/jester-0.6.0-abc"), "jester"));
+        assert!(is_package_dir(Path::new("pkgs/jester"), "jester"));
+        assert!(!is_package_dir(Path::new("pkgs2/jesterx-1.0"), "jester"));
+    }
+
+    #[test]
+    fn test_resolve_src_dir_and_local_packages() {
+        let tmp = tempfile::tempdir().unwrap();
+        let root = tmp.path();
+        fs::write(root.join("svc.nimble"), "srcDir = \"src\"\n").unwrap();
+        fs::create_dir_all(root.join("src/svc")).unwrap();
+        fs::write(root.join("src/svc/db.nim"), "").unwrap();
+        let pkg = root.join("nimbledeps/pkgs2/jester-0.6.0-abc");
+        fs::create_dir_all(&pkg).unwrap();
+        fs::write(pkg.join("jester.nim"), "").unwrap();
+
+        let project = NimbleProject::load(root);
+        assert_eq!(project.resolve("svc/db"), Some(root.join("src/svc/db.nim")));
+        assert_eq!(project.resolve("jester"), Some(pkg.join("jester.nim")));
+        assert_eq!(project.resolve("strutils"), None);
+    }
+}
@@ -75,6 +75,11 @@ fn extract_defs(
     tags: &mut Vec<Tag>,
     defines: &mut HashMap<String, HashSet<PathBuf>>,
 ) {
+    // Nim modules are namespaces: `import a/b` refers to the module `b` itself.
+    if let Some(module) = nim_module_name(path) {
+        defines.entry(module).or_default().insert(path.to_path_buf());
+    }
+
     for def in defs::extract(path, content) {
         defines
             .entry(def.name.clone())
@@ -97,11 +102,7 @@ fn extract_refs(
     references: &mut HashMap<String, HashSet<PathBuf>>,
 ) {
     for ref_name in imports::extract(path, content) {
-        let symbol = ref_name
-            .split("::")
-            .last()
-            .unwrap_or(&ref_name)
-            .to_string();
+        let symbol = ref_symbol(path, &ref_name);
         
         references
             .entry(symbol.clone())
@@ -118,6 +119,22 @@ fn extract_refs(
     }
 }
 
+fn ref_symbol(path: &Path, ref_name: &str) -> String {
+    let symbol = ref_name.split("::").last().unwrap_or(ref_name);
+    if nim_module_name(path).is_some() {
+        return symbol.rsplit('/').next().unwrap_or(symbol).to_string();
+    }
+    symbol.to_string()
+}
+
+fn nim_module_name(path: &Path) -> Option<String> {
+    let ext = path.extension()?.to_str()?;
+    if !matches!(ext, "nim" | "nims") {
+        return None;
+    }
+    path.file_stem().and_then(|s| s.to_str()).map(String::from)
+}
+
 fn build_edges(
     defines: &HashMap<String, HashSet<PathBuf>>,
     references: &HashMap<String, HashSet<PathBuf>>,
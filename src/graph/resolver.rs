@@ -1,17 +1,72 @@
 // src/graph/resolver.rs
+use crate::graph::nimble::{self, NimbleProject};
 use crate::graph::tsconfig::TsConfig;
+use std::cell::OnceCell;
 use std::path::{Path, PathBuf};
 
-/// Resolves an import string to a likely file path on disk.
-#[must_use]
-pub fn resolve(project_root: &Path, current_file: &Path, import_str: &str) -> Option<PathBuf> {
-    let ext = current_file.extension().and_then(|s| s.to_str())?;
+/// Resolves imports for one graph build. Project configuration
+/// (`tsconfig.json`, `.nimble`, `nim.cfg`) is loaded on first use and kept
+/// for the rest of the build rather than re-read for every import.
+pub struct Resolver<'a> {
+    root: &'a Path,
+    tsconfig: OnceCell<Option<TsConfig>>,
+    nimble: OnceCell<NimbleProject>,
+}
+
+impl<'a> Resolver<'a> {
+    #[must_use]
+    pub fn new(root: &'a Path) -> Self {
+        Self {
+            root,
+            tsconfig: OnceCell::new(),
+            nimble: OnceCell::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn root(&self) -> &'a Path {
+        self.root
+    }
+
+    /// Resolves an import string to a likely file path on disk.
+    #[must_use]
+    pub fn resolve(&self, current_file: &Path, import_str: &str) -> Option<PathBuf> {
+        let ext = current_file.extension().and_then(|s| s.to_str())?;
+
+        match ext {
+            "rs" => resolve_rust(self.root, current_file, import_str),
+            "ts" | "tsx" | "js" | "jsx" => self.resolve_ts(current_file, import_str),
+            "py" => resolve_python(self.root, import_str),
+            "nim" | "nims" => self.resolve_nim(current_file, import_str),
+            _ => None,
+        }
+    }
 
-    match ext {
-        "rs" => resolve_rust(project_root, current_file, import_str),
-        "ts" | "tsx" | "js" | "jsx" => resolve_ts(project_root, current_file, import_str),
-        "py" => resolve_python(project_root, import_str),
-        _ => None,
+    fn resolve_ts(&self, current: &Path, import: &str) -> Option<PathBuf> {
+        if import.starts_with('.') {
+            return resolve_relative_ts(current, import);
+        }
+        if is_node_module(import) {
+            return None;
+        }
+        let config = self.tsconfig.get_or_init(|| TsConfig::load(self.root));
+        config.as_ref().and_then(|cfg| cfg.resolve(import))
+    }
+
+    fn resolve_nim(&self, current: &Path, import: &str) -> Option<PathBuf> {
+        if import.starts_with("std/") {
+            return None;
+        }
+        let dir = current.parent()?;
+        if import.starts_with('.') {
+            return nimble::find_nim_file(&dir.join(import));
+        }
+        let project = || self.nimble.get_or_init(|| NimbleProject::load(self.root));
+        // `pkg/` restricts lookup to the search path, skipping the importing directory.
+        if let Some(rest) = import.strip_prefix("pkg/") {
+            return project().resolve(rest);
+        }
+        nimble::find_nim_file(&dir.join(import)).or_else(|| project().resolve(import))
     }
 }
 
@@ -73,16 +128,6 @@ fn check_rust_variations(base: &Path, parts: &[&str]) -> Option<PathBuf> {
     None
 }
 
-fn resolve_ts(root: &Path, current: &Path, import: &str) -> Option<PathBuf> {
-    if import.starts_with('.') {
-        return resolve_relative_ts(current, import);
-    }
-    if is_node_module(import) {
-        return None;
-    }
-    TsConfig::load(root).and_then(|cfg| cfg.resolve(import))
-}
-
 fn resolve_relative_ts(current: &Path, import: &str) -> Option<PathBuf> {
     let parent = current.parent()?;
     let path = parent.join(import);
@@ -133,4 +178,4 @@ fn resolve_python(root: &Path, import: &str) -> Option<PathBuf> {
     if init_path.exists() { return Some(init_path); }
 
     None
-}
\ No newline at end of file
+}
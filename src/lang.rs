@@ -1,10 +1,15 @@
 use tree_sitter::Language;
 
+mod queries;
+
+use queries::QUERIES;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Lang {
     Rust,
     Python,
     TypeScript,
+    Nim,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -24,6 +29,19 @@ impl Lang {
             "rs" => Some(Self::Rust),
             "py" => Some(Self::Python),
             "ts" | "tsx" | "js" | "jsx" => Some(Self::TypeScript),
+            "nim" | "nims" => Some(Self::Nim),
+            _ => None,
+        }
+    }
+
+    /// Parses a language name as written in config (`rust`, `py`, `ts`, ...).
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "rust" | "rs" => Some(Self::Rust),
+            "python" | "py" => Some(Self::Python),
+            "typescript" | "ts" | "javascript" | "js" => Some(Self::TypeScript),
+            "nim" => Some(Self::Nim),
             _ => None,
         }
     }
@@ -34,6 +52,7 @@ impl Lang {
             Self::Rust => tree_sitter_rust::language(),
             Self::Python => tree_sitter_python::language(),
             Self::TypeScript => tree_sitter_typescript::language_typescript(),
+            Self::Nim => tree_sitter_nim::language(),
         }
     }
 
@@ -74,126 +93,29 @@ impl Lang {
     }
     #[must_use]
     pub fn skeleton_replacement(self) -> &'static str {
-        if self == Self::Python {
-            "\n    ..."
-        } else {
-            "{ ... }"
+        match self {
+            Self::Python => "\n    ...",
+            // Nim bodies are indentation-based; `discard` is the idiomatic empty body
+            Self::Nim => "\n  discard",
+            Self::Rust | Self::TypeScript => "{ ... }",
+        }
+    }
+    #[must_use]
+    pub fn line_comment(self) -> &'static str {
+        match self {
+            Self::Rust | Self::TypeScript => "//",
+            Self::Python | Self::Nim => "#",
         }
     }
 }
 
-// [Rust, Python, TypeScript] x [Naming, Complexity, Imports, Defs, Exports, Skeleton]
-const QUERIES: [[&str; 6]; 3] = [
-    // Rust
-    [
-        "(function_item name: (identifier) @name)", // Naming
-        r"
-            (if_expression) @branch
-            (match_arm) @branch
-            (while_expression) @branch
-            (for_expression) @branch
-            (binary_expression) @branch
-        ", // Complexity
-        r"
-            (use_declaration argument: (_) @import)
-            (mod_item name: (identifier) @mod)
-        ", // Imports
-        r"
-            (function_item name: (identifier) @name) @sig
-            (struct_item name: (type_identifier) @name) @sig
-            (enum_item name: (type_identifier) @name) @sig
-            (trait_item name: (type_identifier) @name) @sig
-            (impl_item type: (type_identifier) @name) @sig
-            (const_item name: (identifier) @name) @sig
-            (static_item name: (identifier) @name) @sig
-            (type_item name: (type_identifier) @name) @sig
-            (mod_item name: (identifier) @name) @sig
-        ", // Defs
-        r"
-            (function_item (visibility_modifier)) @export
-            (struct_item (visibility_modifier)) @export
-            (enum_item (visibility_modifier)) @export
-            (trait_item (visibility_modifier)) @export
-            (const_item (visibility_modifier)) @export
-            (static_item (visibility_modifier)) @export
-            (type_item (visibility_modifier)) @export
-            (impl_item) @export
-            (mod_item (visibility_modifier)) @export
-        ", // Exports
-        "(function_item body: (block) @body) (impl_item body: (declaration_list) @body)", // Skeleton
-    ],
-    // Python
-    [
-        "(function_definition name: (identifier) @name)", // Naming
-        r"
-            (if_statement) @branch
-            (for_statement) @branch
-            (while_statement) @branch
-            (except_clause) @branch
-            (boolean_operator) @branch
-        ", // Complexity
-        r"
-            (import_statement name: (dotted_name) @import)
-            (aliased_import name: (dotted_name) @import)
-            (import_from_statement module_name: (dotted_name) @import)
-        ", // Imports
-        r"
-            (function_definition name: (identifier) @name) @sig
-            (class_definition name: (identifier) @name) @sig
-        ", // Defs
-        r"
-            (function_definition) @export
-            (class_definition) @export
-        ", // Exports
-        "(function_definition body: (block) @body)",      // Skeleton
-    ],
-    // TypeScript
-    [
-        r"
-            (function_declaration name: (identifier) @name)
-            (method_definition name: (property_identifier) @name)
-            (variable_declarator name: (identifier) @name value: [(arrow_function) (function_expression)])
-        ", // Naming
-        r#"
-            (if_statement) @branch
-            (for_statement) @branch
-            (for_in_statement) @branch
-            (while_statement) @branch
-            (do_statement) @branch
-            (switch_case) @branch
-            (catch_clause) @branch
-            (ternary_expression) @branch
-            (binary_expression operator: ["&&" "||" "??"]) @branch
-        "#, // Complexity
-        r#"
-            (import_statement source: (string) @import)
-            (export_statement source: (string) @import)
-            (call_expression
-              function: (identifier) @func
-              arguments: (arguments (string) @import)
-              (#eq? @func "require"))
-        "#, // Imports
-        r"
-            (function_declaration name: (identifier) @name) @sig
-            (class_declaration name: (type_identifier) @name) @sig
-            (interface_declaration name: (type_identifier) @name) @sig
-            (type_alias_declaration name: (type_identifier) @name) @sig
-        ", // Defs
-        r"
-            (export_statement) @export
-        ", // Exports
-        r"
-            (function_declaration body: (statement_block) @body)
-            (method_definition body: (statement_block) @body)
-            (arrow_function body: (statement_block) @body)
-        ", // Skeleton
-    ],
-];
 #[cfg(test)]
 mod tests {
     use super::*;
     #[test]
     fn test_from_ext() {
         assert_eq!(Lang::from_ext("rs"), Some(Lang::Rust));
+        assert_eq!(Lang::from_ext("nim"), Some(Lang::Nim));
+        assert_eq!(Lang::from_ext("nims"), Some(Lang::Nim));
     }
 }
@@ -0,0 +1,155 @@
+// src/lang/queries.rs
+//! Tree-sitter query table, indexed by `Lang` and `QueryKind`.
+
+// [Rust, Python, TypeScript, Nim] x [Naming, Complexity, Imports, Defs, Exports, Skeleton]
+pub(super) const QUERIES: [[&str; 6]; 4] = [
+    // Rust
+    [
+        "(function_item name: (identifier) @name)", // Naming
+        r"
+            (if_expression) @branch
+            (match_arm) @branch
+            (while_expression) @branch
+            (for_expression) @branch
+            (binary_expression) @branch
+        ", // Complexity
+        r"
+            (use_declaration argument: (_) @import)
+            (mod_item name: (identifier) @mod)
+        ", // Imports
+        r"
+            (function_item name: (identifier) @name) @sig
+            (struct_item name: (type_identifier) @name) @sig
+            (enum_item name: (type_identifier) @name) @sig
+            (trait_item name: (type_identifier) @name) @sig
+            (impl_item type: (type_identifier) @name) @sig
+            (const_item name: (identifier) @name) @sig
+            (static_item name: (identifier) @name) @sig
+            (type_item name: (type_identifier) @name) @sig
+            (mod_item name: (identifier) @name) @sig
+        ", // Defs
+        r"
+            (function_item (visibility_modifier)) @export
+            (struct_item (visibility_modifier)) @export
+            (enum_item (visibility_modifier)) @export
+            (trait_item (visibility_modifier)) @export
+            (const_item (visibility_modifier)) @export
+            (static_item (visibility_modifier)) @export
+            (type_item (visibility_modifier)) @export
+            (impl_item) @export
+            (mod_item (visibility_modifier)) @export
+        ", // Exports
+        "(function_item body: (block) @body) (impl_item body: (declaration_list) @body)", // Skeleton
+    ],
+    // Python
+    [
+        "(function_definition name: (identifier) @name)", // Naming
+        r"
+            (if_statement) @branch
+            (for_statement) @branch
+            (while_statement) @branch
+            (except_clause) @branch
+            (boolean_operator) @branch
+        ", // Complexity
+        r"
+            (import_statement name: (dotted_name) @import)
+            (aliased_import name: (dotted_name) @import)
+            (import_from_statement module_name: (dotted_name) @import)
+        ", // Imports
+        r"
+            (function_definition name: (identifier) @name) @sig
+            (class_definition name: (identifier) @name) @sig
+        ", // Defs
+        r"
+            (function_definition) @export
+            (class_definition) @export
+        ", // Exports
+        "(function_definition body: (block) @body)",      // Skeleton
+    ],
+    // TypeScript
+    [
+        r"
+            (function_declaration name: (identifier) @name)
+            (method_definition name: (property_identifier) @name)
+            (variable_declarator name: (identifier) @name value: [(arrow_function) (function_expression)])
+        ", // Naming
+        r#"
+            (if_statement) @branch
+            (for_statement) @branch
+            (for_in_statement) @branch
+            (while_statement) @branch
+            (do_statement) @branch
+            (switch_case) @branch
+            (catch_clause) @branch
+            (ternary_expression) @branch
+            (binary_expression operator: ["&&" "||" "??"]) @branch
+        "#, // Complexity
+        r#"
+            (import_statement source: (string) @import)
+            (export_statement source: (string) @import)
+            (call_expression
+              function: (identifier) @func
+              arguments: (arguments (string) @import)
+              (#eq? @func "require"))
+        "#, // Imports
+        r"
+            (function_declaration name: (identifier) @name) @sig
+            (class_declaration name: (type_identifier) @name) @sig
+            (abstract_class_declaration name: (type_identifier) @name) @sig
+            (interface_declaration name: (type_identifier) @name) @sig
+            (type_alias_declaration name: (type_identifier) @name) @sig
+        ", // Defs
+        r"
+            (export_statement) @export
+        ", // Exports
+        r"
+            (function_declaration body: (statement_block) @body)
+            (method_definition body: (statement_block) @body)
+            (arrow_function body: (statement_block) @body)
+        ", // Skeleton
+    ],
+    // Nim
+    [
+        r"
+            (proc_declaration name: (_) @name)
+            (func_declaration name: (_) @name)
+            (method_declaration name: (_) @name)
+            (iterator_declaration name: (_) @name)
+            (converter_declaration name: (_) @name)
+        ", // Naming
+        r"
+            (if) @branch
+            (when) @branch
+            (elif_branch) @branch
+            (of_branch) @branch
+            (for) @branch
+            (while) @branch
+            (except_branch) @branch
+        ", // Complexity
+        r"
+            (import_statement) @import
+            (import_from_statement) @import
+            (include_statement) @import
+        ", // Imports
+        r"
+            (proc_declaration name: (_) @name) @sig
+            (func_declaration name: (_) @name) @sig
+            (method_declaration name: (_) @name) @sig
+            (iterator_declaration name: (_) @name) @sig
+            (converter_declaration name: (_) @name) @sig
+            (template_declaration name: (_) @name) @sig
+            (macro_declaration name: (_) @name) @sig
+            (type_declaration (type_symbol_declaration name: (_) @name)) @sig
+        ", // Defs
+        r"
+            (exported_symbol) @export
+        ", // Exports
+        r"
+            (proc_declaration body: (statement_list) @body)
+            (func_declaration body: (statement_list) @body)
+            (method_declaration body: (statement_list) @body)
+            (iterator_declaration body: (statement_list) @body)
+            (converter_declaration body: (statement_list) @body)
+        ", // Skeleton
+    ],
+];
@@ -1,22 +1,28 @@
 pub mod analysis;
+pub mod baseline;
 pub mod branch;
 pub mod clean;
 pub mod cli;
 pub mod config;
 pub mod constants;
 pub mod detection;
+pub mod diff;
 pub mod discovery;
 pub mod error;
 pub mod events;
 pub mod exit;
+pub mod fix;
 pub mod graph;
 pub mod lang;
+pub mod lsp;
 pub mod mutate;
 pub mod project;
 pub mod reporting;
+pub mod rules;
 pub mod skeleton;
 pub mod spinner;
 pub mod tokens;
 pub mod types;
 pub mod utils;
 pub mod verification;
+pub mod watch;
@@ -0,0 +1,79 @@
+// src/lsp.rs
+//! `slopchop lsp`: governance diagnostics over the Language Server Protocol.
+//!
+//! Speaks JSON-RPC over stdio with `Content-Length` framing. Buffers are
+//! analyzed in memory with `worker::scan_source` when opened and saved, so
+//! problems show up before anyone runs `scan`. Deep analysis (LCOM4, CBO,
+//! SFOUT, AHF, locality) needs the whole project; it runs on a background thread at
+//! startup and after each save, and its results are merged into the open
+//! documents' diagnostics when they arrive. Code actions offer the attached
+//! fix, if any, and an inline suppression.
+
+mod convert;
+mod document;
+mod request;
+mod server;
+mod transport;
+mod workspace;
+
+use std::collections::HashMap;
+use std::io::{self, BufReader};
+use std::path::PathBuf;
+use std::sync::mpsc;
+use std::thread;
+
+use anyhow::Result;
+
+use crate::types::Violation;
+pub use server::Server;
+
+/// Input to the server loop.
+pub enum Event {
+    /// A message body from the client.
+    Message(String),
+    /// Deep violations from a finished background run.
+    Deep(HashMap<PathBuf, Vec<Violation>>),
+    /// The client closed stdin.
+    Closed,
+}
+
+/// Serves LSP over stdin/stdout until the client exits.
+///
+/// # Errors
+/// Returns error if stdout cannot be written.
+pub fn run() -> Result<()> {
+    let (events, inbox) = mpsc::channel();
+    let reader_events = events.clone();
+    thread::spawn(move || {
+        let mut stdin = BufReader::new(io::stdin());
+        while let Ok(Some(body)) = transport::read(&mut stdin) {
+            if reader_events.send(Event::Message(body)).is_err() {
+                return;
+            }
+        }
+        let _ = reader_events.send(Event::Closed);
+    });
+
+    let mut server = Server::new(events);
+    let mut stdout = io::stdout().lock();
+    for event in inbox {
+        let outgoing = match event {
+            // Bodies that are not JSON cannot be answered; skip them.
+            Event::Message(body) => serde_json::from_str(&body)
+                .map(|message| server.handle(&message))
+                .unwrap_or_default(),
+            Event::Deep(deep) => server.deep_done(deep),
+            Event::Closed => break,
+        };
+        for message in &outgoing {
+            transport::write(&mut stdout, message)?;
+        }
+        if server.exited() {
+            break;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests;
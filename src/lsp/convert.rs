@@ -0,0 +1,137 @@
+// src/lsp/convert.rs
+//! Conversions between violations and LSP diagnostics and code actions.
+//!
+//! Violations carry 1-based rows and byte offsets; LSP positions are
+//! 0-based lines with UTF-16 columns.
+
+use std::path::PathBuf;
+
+use serde_json::{json, Value};
+
+use crate::lang::Lang;
+use crate::types::{Edit, Severity, Violation};
+
+/// A diagnostic spanning the violation's line, without indentation.
+#[must_use]
+pub fn diagnostic(text: &str, v: &Violation) -> Value {
+    let line = v.row.saturating_sub(1);
+    let content = text.lines().nth(line).unwrap_or("");
+    let indent = content.len() - content.trim_start().len();
+    let mut message = v.message.clone();
+    if let Some(suggestion) = v.details.as_ref().and_then(|d| d.suggestion.as_ref()) {
+        message = format!("{message}\n{suggestion}");
+    }
+    json!({
+        "range": {
+            "start": { "line": line, "character": utf16_len(&content[..indent]) },
+            "end": { "line": line, "character": utf16_len(content) },
+        },
+        "severity": match v.severity {
+            Severity::Error => 1,
+            Severity::Warning => 2,
+        },
+        "code": v.law,
+        "source": "slopchop",
+        "message": message,
+    })
+}
+
+/// Quick fixes for the violations on lines `first..=last` (0-based): the
+/// attached fix, if any, and an inline suppression.
+#[must_use]
+pub fn code_actions(
+    uri: &str,
+    text: &str,
+    lang: Option<Lang>,
+    violations: &[Violation],
+    lines: (usize, usize),
+) -> Vec<Value> {
+    let mut actions = Vec::new();
+    let in_range = |v: &&Violation| (lines.0..=lines.1).contains(&v.row.saturating_sub(1));
+    for v in violations.iter().filter(in_range) {
+        let diagnostics = json!([diagnostic(text, v)]);
+        if let Some(fix) = &v.fix {
+            let edits: Vec<Value> = fix.edits.iter().map(|e| text_edit(text, e)).collect();
+            actions.push(quick_fix(&fix.description, uri, &edits, &diagnostics, true));
+        }
+        if let Some(lang) = lang {
+            let title = format!("Suppress {} on this line", v.law);
+            let edit = suppression(text, v, lang);
+            actions.push(quick_fix(&title, uri, &[edit], &diagnostics, false));
+        }
+    }
+    actions
+}
+
+fn quick_fix(
+    title: &str,
+    uri: &str,
+    edits: &[Value],
+    diagnostics: &Value,
+    preferred: bool,
+) -> Value {
+    json!({
+        "title": title,
+        "kind": "quickfix",
+        "diagnostics": diagnostics,
+        "isPreferred": preferred,
+        "edit": { "changes": { uri: edits } },
+    })
+}
+
+/// Inserts `slopchop:allow` above the violation with an empty reason, which
+/// `LAW OF JUSTIFICATION` rejects until someone writes a real one.
+fn suppression(text: &str, v: &Violation, lang: Lang) -> Value {
+    let line = v.row.saturating_sub(1);
+    let content = text.lines().nth(line).unwrap_or("");
+    let indent = &content[..content.len() - content.trim_start().len()];
+    let comment = lang.line_comment();
+    let new_text = format!(
+        "{indent}{comment} slopchop:allow({}, reason = \"\")\n",
+        v.law
+    );
+    let at = json!({ "line": line, "character": 0 });
+    json!({ "range": { "start": at, "end": at }, "newText": new_text })
+}
+
+fn text_edit(text: &str, edit: &Edit) -> Value {
+    json!({
+        "range": { "start": position(text, edit.start), "end": position(text, edit.end) },
+        "newText": edit.replacement,
+    })
+}
+
+/// LSP position of byte offset `offset` in `text`.
+#[must_use]
+pub fn position(text: &str, offset: usize) -> Value {
+    let before = text.get(..offset).unwrap_or(text);
+    let line = before.matches('\n').count();
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    json!({ "line": line, "character": utf16_len(&before[line_start..]) })
+}
+
+fn utf16_len(s: &str) -> usize {
+    s.encode_utf16().count()
+}
+
+/// Filesystem path of a `file://` URI, percent-decoded.
+#[must_use]
+pub fn uri_to_path(uri: &str) -> Option<PathBuf> {
+    let raw = uri.strip_prefix("file://")?;
+    let mut bytes = Vec::with_capacity(raw.len());
+    let mut rest = raw.as_bytes();
+    while let Some((&b, tail)) = rest.split_first() {
+        let hex = tail.get(..2).and_then(|h| std::str::from_utf8(h).ok());
+        match hex.and_then(|h| u8::from_str_radix(h, 16).ok()) {
+            Some(decoded) if b == b'%' => {
+                bytes.push(decoded);
+                rest = tail.get(2..).unwrap_or_default();
+            }
+            _ => {
+                bytes.push(b);
+                rest = tail;
+            }
+        }
+    }
+    String::from_utf8(bytes).ok().map(PathBuf::from)
+}
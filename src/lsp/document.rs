@@ -0,0 +1,92 @@
+// src/lsp/document.rs
+//! An open buffer and the local violations of its last analysis.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use serde_json::Value;
+
+use crate::analysis::worker;
+use crate::config::Config;
+use crate::lang::Lang;
+use crate::types::Violation;
+
+use super::convert;
+
+/// Deep violations by path, from the last background run.
+pub type DeepResults = HashMap<PathBuf, Vec<Violation>>;
+
+pub struct Document {
+    path: PathBuf,
+    text: String,
+    violations: Vec<Violation>,
+}
+
+impl Document {
+    /// Analyzes a buffer opened from `uri`. `None` unless it is a `file://` URI.
+    #[must_use]
+    pub fn open(uri: &str, text: String, config: &Config) -> Option<Self> {
+        let path = document_path(uri, &config.root)?;
+        Some(Self::analyze(path, text, config))
+    }
+
+    /// Re-analyzes the buffer with new text.
+    pub fn update(&mut self, text: String, config: &Config) {
+        *self = Self::analyze(self.path.clone(), text, config);
+    }
+
+    #[must_use]
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Diagnostics for the local violations and this file's entry in `deep`.
+    #[must_use]
+    pub fn diagnostics(&self, deep: &DeepResults) -> Vec<Value> {
+        self.violations(deep)
+            .iter()
+            .map(|v| convert::diagnostic(&self.text, v))
+            .collect()
+    }
+
+    /// Quick fixes for the violations on `lines`, local and deep.
+    #[must_use]
+    pub fn code_actions(&self, uri: &str, lines: (usize, usize), deep: &DeepResults) -> Value {
+        let all = self.violations(deep);
+        let lang = lang_of(&self.path);
+        Value::from(convert::code_actions(uri, &self.text, lang, &all, lines))
+    }
+
+    /// Analyzes `text` in memory as if it were the file at `path`.
+    fn analyze(path: PathBuf, text: String, config: &Config) -> Self {
+        let violations = worker::scan_source(&path, &text, config).violations;
+        Self {
+            path,
+            text,
+            violations,
+        }
+    }
+
+    fn violations(&self, deep: &DeepResults) -> Vec<Violation> {
+        let deep = deep_for(deep, &self.path);
+        self.violations.iter().chain(deep).cloned().collect()
+    }
+}
+
+fn deep_for<'a>(deep: &'a DeepResults, path: &Path) -> &'a [Violation] {
+    deep.get(path).map_or(&[], Vec::as_slice)
+}
+
+fn lang_of(path: &Path) -> Option<Lang> {
+    Lang::from_ext(path.extension()?.to_str()?)
+}
+
+/// Path of a `file://` URI relative to the workspace root, as discovery
+/// reports it.
+fn document_path(uri: &str, root: &Path) -> Option<PathBuf> {
+    let path = convert::uri_to_path(uri)?;
+    Some(
+        path.strip_prefix(root)
+            .map_or_else(|_| path.clone(), Path::to_path_buf),
+    )
+}
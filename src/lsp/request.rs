@@ -0,0 +1,107 @@
+// src/lsp/request.rs
+//! Decoding of the client messages the server understands.
+
+use std::path::PathBuf;
+
+use serde_json::Value;
+
+use super::convert;
+
+/// A client message, reduced to the fields the server uses.
+pub enum Request {
+    Initialize {
+        id: Value,
+        root: Option<PathBuf>,
+    },
+    Initialized,
+    Shutdown {
+        id: Value,
+    },
+    Exit,
+    Open {
+        uri: String,
+        text: String,
+    },
+    /// Full-document sync: the latest text, if the change carried one.
+    Change {
+        uri: String,
+        text: Option<String>,
+    },
+    /// `text` is present when the client honours `includeText`.
+    Save {
+        uri: String,
+        text: Option<String>,
+    },
+    Close {
+        uri: String,
+    },
+    /// Code actions for the 0-based lines `first..=last`.
+    CodeAction {
+        id: Value,
+        uri: String,
+        lines: (usize, usize),
+    },
+    /// Anything else. Requests (with an id) get a method-not-found error.
+    Other {
+        id: Option<Value>,
+    },
+}
+
+/// Decodes a JSON-RPC message.
+#[must_use]
+pub fn parse(message: &Value) -> Request {
+    let id = message.get("id").cloned();
+    let params = message.get("params").unwrap_or(message);
+    let uri = string(params, "/textDocument/uri").unwrap_or_default();
+    match message.get("method").and_then(Value::as_str).unwrap_or_default() {
+        "initialize" => Request::Initialize {
+            id: id.unwrap_or_default(),
+            root: params
+                .get("rootUri")
+                .and_then(Value::as_str)
+                .and_then(convert::uri_to_path),
+        },
+        "initialized" => Request::Initialized,
+        "shutdown" => Request::Shutdown {
+            id: id.unwrap_or_default(),
+        },
+        "exit" => Request::Exit,
+        "textDocument/didOpen" => Request::Open {
+            uri,
+            text: string(params, "/textDocument/text").unwrap_or_default(),
+        },
+        "textDocument/didChange" => Request::Change {
+            uri,
+            text: last_change(params),
+        },
+        "textDocument/didSave" => Request::Save {
+            uri,
+            text: string(params, "/text"),
+        },
+        "textDocument/didClose" => Request::Close { uri },
+        "textDocument/codeAction" => Request::CodeAction {
+            id: id.unwrap_or_default(),
+            uri,
+            lines: (line(params, "start"), line(params, "end")),
+        },
+        _ => Request::Other { id },
+    }
+}
+
+/// The string at JSON `pointer` inside `value`.
+fn string(value: &Value, pointer: &str) -> Option<String> {
+    value.pointer(pointer).and_then(Value::as_str).map(str::to_string)
+}
+
+fn last_change(params: &Value) -> Option<String> {
+    let changes = params.get("contentChanges")?.as_array()?;
+    string(changes.last()?, "/text")
+}
+
+fn line(params: &Value, key: &str) -> usize {
+    let line = params
+        .pointer(&format!("/range/{key}/line"))
+        .and_then(Value::as_u64)
+        .unwrap_or(0);
+    usize::try_from(line).unwrap_or(usize::MAX)
+}
@@ -0,0 +1,137 @@
+// src/lsp/server.rs
+//! LSP request and notification handling.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::mpsc::Sender;
+use std::thread;
+
+use serde_json::{json, Value};
+
+use crate::analysis::Engine;
+use crate::config::Config;
+use crate::discovery;
+use crate::types::Violation;
+
+use super::request::{self, Request};
+use super::transport::{self, METHOD_NOT_FOUND};
+use super::workspace::Workspace;
+use super::Event;
+
+pub struct Server {
+    workspace: Workspace,
+    deep_running: bool,
+    deep_stale: bool,
+    events: Sender<Event>,
+    exited: bool,
+}
+
+impl Server {
+    /// A server for the current directory. Deep results are sent to `events`.
+    #[must_use]
+    pub fn new(events: Sender<Event>) -> Self {
+        Self {
+            workspace: Workspace::load(None),
+            deep_running: false,
+            deep_stale: false,
+            events,
+            exited: false,
+        }
+    }
+
+    /// True once the client has sent `exit`.
+    #[must_use]
+    pub fn exited(&self) -> bool {
+        self.exited
+    }
+
+    /// Handles one client message and returns the messages to send back.
+    pub fn handle(&mut self, message: &Value) -> Vec<Value> {
+        match request::parse(message) {
+            Request::Initialize { id, root } => {
+                self.workspace = Workspace::load(root);
+                let mut out = vec![transport::response(&id, &capabilities())];
+                out.extend(self.workspace.config_error().as_deref().map(show_message));
+                out
+            }
+            Request::Initialized => self.schedule_deep(),
+            Request::Shutdown { id } => vec![transport::response(&id, &Value::Null)],
+            Request::Exit => {
+                self.exited = true;
+                vec![]
+            }
+            Request::Open { uri, text } => self.workspace.open(uri, text).into_iter().collect(),
+            Request::Change { uri, text } => {
+                self.workspace.change(&uri, text);
+                vec![]
+            }
+            Request::Save { uri, text } => {
+                let mut out: Vec<Value> = self.workspace.save(uri, text).into_iter().collect();
+                out.extend(self.schedule_deep());
+                out
+            }
+            Request::Close { uri } => vec![self.workspace.close(&uri)],
+            Request::CodeAction { id, uri, lines } => {
+                vec![transport::response(
+                    &id,
+                    &self.workspace.code_actions(&uri, lines),
+                )]
+            }
+            Request::Other { id } => not_found(id),
+        }
+    }
+
+    /// Stores finished deep analysis and republishes open documents.
+    pub fn deep_done(&mut self, deep: HashMap<PathBuf, Vec<Violation>>) -> Vec<Value> {
+        self.deep_running = false;
+        let mut out = if self.deep_stale {
+            self.schedule_deep()
+        } else {
+            vec![]
+        };
+        out.extend(self.workspace.set_deep(deep));
+        out
+    }
+
+    /// Runs deep analysis on a background thread, or marks it stale if a
+    /// run is already in progress.
+    fn schedule_deep(&mut self) -> Vec<Value> {
+        if self.deep_running {
+            self.deep_stale = true;
+            return vec![];
+        }
+        self.deep_running = true;
+        self.deep_stale = false;
+        let config = self.workspace.config().clone();
+        let events = self.events.clone();
+        thread::spawn(move || deep_scan(config, &events));
+        vec![]
+    }
+}
+
+fn deep_scan(config: Config, events: &Sender<Event>) {
+    let files = discovery::discover(&config).unwrap_or_default();
+    let deep = Engine::new(config).scan_deep(&files);
+    let _ = events.send(Event::Deep(deep));
+}
+
+fn capabilities() -> Value {
+    json!({
+        "capabilities": {
+            "textDocumentSync": { "openClose": true, "change": 1, "save": { "includeText": true } },
+            "codeActionProvider": { "codeActionKinds": ["quickfix"] },
+        },
+        "serverInfo": { "name": "slopchop", "version": env!("CARGO_PKG_VERSION") },
+    })
+}
+
+fn show_message(message: &str) -> Value {
+    let params = json!({ "type": 1, "message": message });
+    transport::notification("window/showMessage", &params)
+}
+
+fn not_found(id: Option<Value>) -> Vec<Value> {
+    id.map(|id| transport::error(&id, METHOD_NOT_FOUND, "method not found"))
+        .into_iter()
+        .collect()
+}
@@ -0,0 +1,132 @@
+// src/lsp/tests.rs
+//! Framing, conversion and server message tests.
+
+use std::io::Cursor;
+
+use serde_json::{json, Value};
+
+use super::*;
+
+const SOURCE: &str = "\
+fn load(path: &str) -> Result<String, std::io::Error> {
+    let text = std::fs::read_to_string(path).unwrap();
+    Ok(text)
+}
+";
+
+fn server() -> Server {
+    let (events, _inbox) = mpsc::channel();
+    Server::new(events)
+}
+
+fn uri() -> String {
+    let path = std::env::current_dir().unwrap().join("src/demo.rs");
+    format!("file://{}", path.display())
+}
+
+#[test]
+fn test_framing_round_trip() {
+    let mut buffer = Vec::new();
+    let message = json!({ "jsonrpc": "2.0", "method": "exit" });
+    transport::write(&mut buffer, &message).unwrap();
+    transport::write(&mut buffer, &message).unwrap();
+
+    let mut reader = Cursor::new(buffer);
+    let first = transport::read(&mut reader).unwrap().unwrap();
+    assert_eq!(serde_json::from_str::<Value>(&first).unwrap(), message);
+    assert!(transport::read(&mut reader).unwrap().is_some());
+    assert!(transport::read(&mut reader).unwrap().is_none());
+}
+
+#[test]
+fn test_uri_is_percent_decoded() {
+    let path = convert::uri_to_path("file:///tmp/my%20project/lib.rs").unwrap();
+    assert_eq!(path, PathBuf::from("/tmp/my project/lib.rs"));
+    assert!(convert::uri_to_path("untitled:1").is_none());
+}
+
+#[test]
+fn test_open_publishes_diagnostics_and_offers_actions() {
+    let mut server = server();
+    let uri = uri();
+    let open = json!({
+        "method": "textDocument/didOpen",
+        "params": { "textDocument": { "uri": uri, "languageId": "rust", "version": 1, "text": SOURCE } },
+    });
+    let out = server.handle(&open);
+
+    assert_eq!(out[0]["method"], "textDocument/publishDiagnostics");
+    let diagnostics = out[0]["params"]["diagnostics"].as_array().unwrap();
+    let unwrap = diagnostics.iter().find(|d| d["code"] == "L04").unwrap();
+    assert_eq!(
+        unwrap["range"]["start"],
+        json!({ "line": 1, "character": 4 })
+    );
+    assert_eq!(unwrap["severity"], 2);
+
+    let request = json!({
+        "id": 7,
+        "method": "textDocument/codeAction",
+        "params": {
+            "textDocument": { "uri": uri },
+            "range": { "start": { "line": 1, "character": 0 }, "end": { "line": 1, "character": 0 } },
+            "context": { "diagnostics": [] },
+        },
+    });
+    let out = server.handle(&request);
+    let actions = out[0]["result"].as_array().unwrap();
+    let titles: Vec<&str> = actions.iter().filter_map(|a| a["title"].as_str()).collect();
+    assert_eq!(
+        titles,
+        vec![
+            "Replace `.unwrap()` with `?`",
+            "Suppress L04 on this line"
+        ]
+    );
+    let edit = &actions[0]["edit"]["changes"][uri.as_str()][0];
+    assert_eq!(edit["newText"], "?");
+    assert_eq!(
+        edit["range"]["start"],
+        json!({ "line": 1, "character": 44 })
+    );
+    let suppress = &actions[1]["edit"]["changes"][uri.as_str()][0]["newText"];
+    assert_eq!(
+        suppress,
+        "    // slopchop:allow(L04, reason = \"\")\n"
+    );
+}
+
+#[test]
+fn test_initialize_loads_the_root_config_in_place() {
+    let tmp = tempfile::tempdir().unwrap();
+    std::fs::write(tmp.path().join("slopchop.toml"), "profile = \"nope\"\n").unwrap();
+    let cwd = std::env::current_dir().unwrap();
+
+    let mut server = server();
+    let initialize = json!({
+        "id": 1,
+        "method": "initialize",
+        "params": { "rootUri": format!("file://{}", tmp.path().display()) },
+    });
+    let out = server.handle(&initialize);
+
+    assert_eq!(out[1]["method"], "window/showMessage");
+    let message = out[1]["params"]["message"].as_str().unwrap();
+    assert!(message.contains("unknown profile `nope`"), "{message}");
+    assert_eq!(std::env::current_dir().unwrap(), cwd);
+}
+
+#[test]
+fn test_unknown_requests_get_errors() {
+    let mut server = server();
+    let out = server.handle(&json!({ "id": 1, "method": "workspace/symbol" }));
+    assert_eq!(out[0]["error"]["code"], transport::METHOD_NOT_FOUND);
+    assert!(server
+        .handle(&json!({ "method": "$/cancelRequest" }))
+        .is_empty());
+
+    let out = server.handle(&json!({ "id": 2, "method": "shutdown" }));
+    assert_eq!(out[0]["result"], Value::Null);
+    server.handle(&json!({ "method": "exit" }));
+    assert!(server.exited());
+}
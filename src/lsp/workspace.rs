@@ -0,0 +1,112 @@
+// src/lsp/workspace.rs
+//! Open documents, the project config and the latest deep results.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use serde_json::{json, Value};
+
+use crate::config::Config;
+
+use super::document::{DeepResults, Document};
+use super::transport;
+
+pub struct Workspace {
+    config: Config,
+    documents: HashMap<String, Document>,
+    deep: DeepResults,
+}
+
+impl Workspace {
+    /// Loads the config of `root`, or of the current directory.
+    #[must_use]
+    pub fn load(root: Option<PathBuf>) -> Self {
+        let root = root
+            .or_else(|| std::env::current_dir().ok())
+            .unwrap_or_default();
+        Self {
+            config: Config::load_in(&root),
+            documents: HashMap::new(),
+            deep: HashMap::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    /// The message to show the user if the config is invalid.
+    #[must_use]
+    pub fn config_error(&self) -> Option<String> {
+        self.config
+            .validate()
+            .err()
+            .map(|e| format!("slopchop: {e}"))
+    }
+
+    /// Analyzes a newly opened buffer and returns its diagnostics.
+    pub fn open(&mut self, uri: String, text: String) -> Option<Value> {
+        let document = Document::open(&uri, text, &self.config)?;
+        let diagnostics = publish(&uri, &document.diagnostics(&self.deep));
+        self.documents.insert(uri, document);
+        Some(diagnostics)
+    }
+
+    /// Re-analyzes an edited buffer without publishing, so quick fix
+    /// offsets stay in step with the text the client holds.
+    pub fn change(&mut self, uri: &str, text: Option<String>) {
+        if let (Some(document), Some(text)) = (self.documents.get_mut(uri), text) {
+            document.update(text, &self.config);
+        }
+    }
+
+    /// Re-analyzes a saved buffer, from the saved text if the client sent it.
+    pub fn save(&mut self, uri: String, text: Option<String>) -> Option<Value> {
+        if !self.documents.contains_key(&uri) {
+            return None;
+        }
+        let text = match text {
+            Some(text) => text,
+            None => self.documents.get(&uri)?.text().to_string(),
+        };
+        self.open(uri, text)
+    }
+
+    /// Forgets a buffer and clears its diagnostics.
+    pub fn close(&mut self, uri: &str) -> Value {
+        self.documents.remove(uri);
+        publish(uri, &[])
+    }
+
+    /// Stores finished deep results and republishes every open buffer.
+    pub fn set_deep(&mut self, deep: DeepResults) -> Vec<Value> {
+        self.deep = deep;
+        self.documents
+            .keys()
+            .map(|uri| self.diagnostics(uri))
+            .collect()
+    }
+
+    #[must_use]
+    pub fn code_actions(&self, uri: &str, lines: (usize, usize)) -> Value {
+        self.documents.get(uri).map_or_else(
+            || json!([]),
+            |document| document.code_actions(uri, lines, &self.deep),
+        )
+    }
+
+    fn diagnostics(&self, uri: &str) -> Value {
+        let diagnostics = self
+            .documents
+            .get(uri)
+            .map(|document| document.diagnostics(&self.deep))
+            .unwrap_or_default();
+        publish(uri, &diagnostics)
+    }
+}
+
+fn publish(uri: &str, diagnostics: &[Value]) -> Value {
+    let params = json!({ "uri": uri, "diagnostics": diagnostics });
+    transport::notification("textDocument/publishDiagnostics", &params)
+}
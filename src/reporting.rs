@@ -1,7 +1,10 @@
 // src/reporting.rs
 //! Console output formatting for scan results.
 
-use crate::types::{FileReport, ScanReport, Violation};
+pub mod fix_packet;
+pub mod sarif;
+
+use crate::types::{FileReport, ScanReport, Severity, Violation};
 use anyhow::Result;
 use colored::Colorize;
 use std::fmt::Write;
@@ -14,7 +17,7 @@ use std::time::Duration;
 /// # Errors
 /// Returns error if formatting fails.
 pub fn print_report(report: &ScanReport) -> Result<()> {
-    if report.has_errors() {
+    if report.total_violations > 0 {
         print_violations(report);
     }
     print_summary(report);
@@ -29,17 +32,17 @@ fn print_violations(report: &ScanReport) {
 
 fn print_file_violations(file: &FileReport) {
     for v in &file.violations {
-        print_violation(&file.path, v);
+        print_violation(&file.path, v, &file.profile);
     }
 }
 
-fn print_violation(path: &Path, v: &Violation) {
+fn print_violation(path: &Path, v: &Violation, profile: &str) {
     let path_str = path.display().to_string();
-    println!(
-        "{} {}",
-        "error:".red().bold(),
-        v.message
-    );
+    let label = match v.severity {
+        Severity::Error => "error:".red().bold(),
+        Severity::Warning => "warning:".yellow().bold(),
+    };
+    println!("{label} {}", v.message);
     println!(
         "  {} {}:{}",
         "-->".blue(),
@@ -51,15 +54,26 @@ fn print_violation(path: &Path, v: &Violation) {
     print_snippet(path, v.row);
 
     println!(
-        "   {} {}: Action required",
+        "   {} {}: Action required {}",
         "=".blue(),
-        v.law.yellow()
+        v.law.yellow(),
+        format!("(profile: {profile})").dimmed()
     );
 
     if let Some(ref details) = v.details {
         print_violation_details(details);
     }
 
+    if let Some(ref fix) = v.fix {
+        println!(
+            "   {} {} {} {}",
+            "=".blue(),
+            "FIX:".green(),
+            fix.description,
+            "(run `slopchop fix`)".dimmed()
+        );
+    }
+
     println!();
 }
 
@@ -127,12 +141,23 @@ fn print_summary(report: &ScanReport) {
     #[allow(clippy::cast_possible_truncation)]
     let duration = Duration::from_millis(report.duration_ms as u64);
 
+    let warnings = report.warning_count();
     if report.has_errors() {
         println!(
-            "{} SlopChop found {} {} in {:?}.",
+            "{} SlopChop found {} {} and {} {} in {:?}.",
             "X".red().bold(),
-            report.total_violations,
-            pluralize("violation", report.total_violations),
+            report.error_count(),
+            pluralize("error", report.error_count()),
+            warnings,
+            pluralize("warning", warnings),
+            duration
+        );
+    } else if warnings > 0 {
+        println!(
+            "{} No errors; {} {} in {:?}.",
+            "OK".green().bold(),
+            warnings,
+            pluralize("warning", warnings).yellow(),
             duration
         );
     } else {
@@ -163,11 +188,12 @@ pub fn format_report_string(report: &ScanReport) -> Result<String> {
         for v in &file.violations {
             writeln!(
                 out,
-                "FILE: {} | LAW: {} | LINE: {} | {}",
+                "FILE: {} | LAW: {} | LINE: {} | {} | PROFILE: {}",
                 file.path.display(),
                 v.law,
                 v.row,
-                v.message
+                v.message,
+                file.profile
             )?;
         }
     }
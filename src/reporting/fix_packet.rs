@@ -0,0 +1,212 @@
+// src/reporting/fix_packet.rs
+//! Fix packet: a self-contained brief for an agent to repair a failing run.
+//!
+//! With `write_fix_packet` enabled, a failing `scan` or `check` writes
+//! `fix_packet_path`. Compressed compiler output from failed commands
+//! comes first, then violations ordered by priority: errors before
+//! warnings, security and correctness before structure and style. Each
+//! violation carries the rule rationale, the configured limit, the
+//! suggestion and the source of the offending function. Entries are
+//! expanded until `fix_packet_max_tokens` is spent; the rest are listed
+//! by location only. A passing run removes a stale packet.
+
+mod source;
+
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+
+use crate::config::{Config, RuleConfig};
+use crate::rules;
+use crate::tokens::Tokenizer;
+use crate::types::{ScanReport, Severity, Violation};
+use crate::verification::CommandResult;
+use source::SourceCache;
+
+/// Rule categories in fix order; custom rules come last.
+const CATEGORY_ORDER: &[&str] = &[
+    "Security",
+    "Logic",
+    "Concurrency",
+    "Resource",
+    "State",
+    "Law",
+    "Semantic",
+    "Performance",
+    "Structure",
+    "Idiomatic",
+];
+
+/// Writes the packet if the run failed, or removes a stale one if it
+/// passed. Returns the path written, if any.
+///
+/// # Errors
+/// Returns error if the packet cannot be written or removed.
+pub fn record(
+    config: &Config,
+    failed: bool,
+    scan: Option<&ScanReport>,
+    commands: &[CommandResult],
+) -> Result<Option<PathBuf>> {
+    let prefs = &config.preferences;
+    if !prefs.write_fix_packet {
+        return Ok(None);
+    }
+    let path = PathBuf::from(&prefs.fix_packet_path);
+    if !failed {
+        if path.exists() {
+            fs::remove_file(&path)?;
+        }
+        return Ok(None);
+    }
+    fs::write(&path, build(config, scan, commands))?;
+    Ok(Some(path))
+}
+
+/// Renders the packet within the configured token budget.
+#[must_use]
+pub fn build(config: &Config, scan: Option<&ScanReport>, commands: &[CommandResult]) -> String {
+    let mut budget = config.preferences.fix_packet_max_tokens;
+    let mut out = String::from("# SlopChop fix packet\n");
+    let failed: Vec<_> = commands.iter().filter(|c| !c.passed).collect();
+    let entries = scan.map(prioritized).unwrap_or_default();
+    let _ = writeln!(
+        out,
+        "{} failing commands, {} violations. Fix in order, then re-run slopchop.\n",
+        failed.len(),
+        entries.len()
+    );
+
+    let mut omitted = Vec::new();
+    for cmd in failed {
+        let section = format!(
+            "## Command failed: {}\n{}\n\n",
+            cmd.command,
+            cmd.compressed.trim()
+        );
+        if !push_within(&mut out, &mut budget, &section) {
+            omitted.push(format!("output of `{}`", cmd.command));
+        }
+    }
+
+    // Once an entry misses the budget, lower-priority ones are not expanded either.
+    let mut sources = SourceCache::default();
+    let mut spent = false;
+    for (i, (path, v)) in entries.into_iter().enumerate() {
+        if !spent {
+            let entry = render(i + 1, path, v, config, &mut sources);
+            spent = !push_within(&mut out, &mut budget, &entry);
+        }
+        if spent {
+            omitted.push(format!("{}:{} {}", path.display(), v.row, v.law));
+        }
+    }
+
+    if !omitted.is_empty() {
+        out.push_str("## Not expanded (token budget)\n");
+        for location in omitted {
+            let _ = writeln!(out, "- {location}");
+        }
+    }
+    out
+}
+
+/// Appends `section` if it fits the remaining budget.
+fn push_within(out: &mut String, budget: &mut usize, section: &str) -> bool {
+    let cost = Tokenizer::count(section);
+    if cost > *budget {
+        return false;
+    }
+    *budget -= cost;
+    out.push_str(section);
+    true
+}
+
+/// Violations in fix order: errors first, then by rule category.
+fn prioritized(scan: &ScanReport) -> Vec<(&Path, &Violation)> {
+    let mut entries: Vec<_> = scan
+        .files
+        .iter()
+        .flat_map(|f| f.violations.iter().map(move |v| (f.path.as_path(), v)))
+        .collect();
+    entries.sort_by_key(|(path, v)| (v.severity == Severity::Warning, rank(v.law), *path, v.row));
+    entries
+}
+
+fn rank(law: &str) -> usize {
+    rules::find(law)
+        .and_then(|r| CATEGORY_ORDER.iter().position(|c| *c == r.category))
+        .unwrap_or(CATEGORY_ORDER.len())
+}
+
+fn render(
+    n: usize,
+    path: &Path,
+    v: &Violation,
+    config: &Config,
+    sources: &mut SourceCache,
+) -> String {
+    let mut out = format!(
+        "## {n}. [{}] {} at {}:{}\n{}\n",
+        v.severity.as_str(),
+        v.law,
+        path.display(),
+        v.row,
+        v.message
+    );
+    let (profile, rules) = config.rules_for(path);
+    if let Some(limit) = limit(v, &rules) {
+        let _ = writeln!(out, "Limit: {limit} (profile: {profile})");
+    }
+    if let Some(rule) = rules::find(v.law) {
+        let _ = writeln!(out, "Why: {}", rule.rationale);
+    }
+    if let Some(details) = &v.details {
+        for line in &details.analysis {
+            let _ = writeln!(out, "Analysis: {line}");
+        }
+        if let Some(suggestion) = &details.suggestion {
+            let _ = writeln!(out, "Fix: {suggestion}");
+        }
+    }
+    // A whole-file violation has no function to quote.
+    if v.law != "LAW OF ATOMICITY" {
+        out.push_str(&sources.snippet(n, path, v.row).unwrap_or_default());
+    }
+    out.push('\n');
+    out
+}
+
+/// The configured threshold a metric violation crossed.
+fn limit(v: &Violation, rules: &RuleConfig) -> Option<String> {
+    let (key, value) = match v.law {
+        "LAW OF ATOMICITY" => ("max_file_tokens", rules.max_file_tokens.to_string()),
+        "LCOM4" => ("max_lcom4", rules.max_lcom4.to_string()),
+        "AHF" => ("min_ahf", rules.min_ahf.to_string()),
+        "CBO" => ("max_cbo", rules.max_cbo.to_string()),
+        "SFOUT" => ("max_sfout", rules.max_sfout.to_string()),
+        "LAW OF COMPLEXITY" => complexity_limit(&v.message, rules)?,
+        _ => return None,
+    };
+    Some(format!("{key} = {value}"))
+}
+
+fn complexity_limit(message: &str, rules: &RuleConfig) -> Option<(&'static str, String)> {
+    let (key, value) = if message.contains("cognitive complexity") {
+        ("max_cognitive_complexity", rules.max_cognitive_complexity)
+    } else if message.contains("nesting depth") {
+        ("max_nesting_depth", rules.max_nesting_depth)
+    } else if message.contains(" args ") {
+        ("max_function_args", rules.max_function_args)
+    } else if message.contains(" words ") {
+        ("max_function_words", rules.max_function_words)
+    } else {
+        return None;
+    };
+    Some((key, value.to_string()))
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,78 @@
+// src/reporting/fix_packet/source.rs
+//! Source quoted in fix packet entries.
+
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use tree_sitter::Parser;
+
+use crate::analysis::checks::collect_functions;
+use crate::lang::Lang;
+
+/// Longest function body quoted in full.
+const MAX_SOURCE_LINES: usize = 60;
+
+/// File contents read once per packet, and the spans already quoted.
+#[derive(Default)]
+pub(super) struct SourceCache {
+    files: HashMap<PathBuf, Option<String>>,
+    quoted: HashMap<(PathBuf, usize), usize>,
+}
+
+impl SourceCache {
+    /// Source around `row` of `path` for entry `n`. A span quoted by an
+    /// earlier entry is referenced instead of repeated.
+    pub(super) fn snippet(&mut self, n: usize, path: &Path, row: usize) -> Option<String> {
+        let (start, text) = quote(path, self.source(path)?, row);
+        let first = *self.quoted.entry((path.to_path_buf(), start)).or_insert(n);
+        Some(if first == n {
+            text
+        } else {
+            format!("Source: see {first}.\n")
+        })
+    }
+
+    fn source(&mut self, path: &Path) -> Option<&str> {
+        self.files
+            .entry(path.to_path_buf())
+            .or_insert_with(|| fs::read_to_string(path).ok())
+            .as_deref()
+    }
+}
+
+/// Numbered source of the innermost function around `row` (1-based), or
+/// a few lines of context when it is not inside one. Returns the first
+/// line quoted and the fenced text.
+fn quote(path: &Path, source: &str, row: usize) -> (usize, String) {
+    let row = row.saturating_sub(1);
+    let (start, end) =
+        enclosing_function(path, source, row).unwrap_or((row.saturating_sub(2), row + 2));
+    (start, format!("```\n{}```\n", numbered(source, start, end)))
+}
+
+/// Zero-based line span of the innermost function containing `row`.
+fn enclosing_function(path: &Path, source: &str, row: usize) -> Option<(usize, usize)> {
+    let lang = Lang::from_ext(path.extension()?.to_str()?)?;
+    let mut parser = Parser::new();
+    parser.set_language(lang.grammar()).ok()?;
+    let tree = parser.parse(source, None)?;
+    collect_functions(tree.root_node())
+        .into_iter()
+        .filter(|f| f.start_position().row <= row && row <= f.end_position().row)
+        .min_by_key(|f| f.end_byte() - f.start_byte())
+        .map(|f| (f.start_position().row, f.end_position().row))
+}
+
+fn numbered(source: &str, start: usize, end: usize) -> String {
+    let shown = end.min(start + MAX_SOURCE_LINES - 1);
+    let mut out = String::new();
+    for (i, line) in source.lines().enumerate().take(shown + 1).skip(start) {
+        let _ = writeln!(out, "{:>4} | {line}", i + 1);
+    }
+    if end > shown {
+        let _ = writeln!(out, "     | ... {} more lines", end - shown);
+    }
+    out
+}
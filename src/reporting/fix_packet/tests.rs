@@ -0,0 +1,116 @@
+// src/reporting/fix_packet/tests.rs
+//! Fix packet ordering, content and budget tests.
+
+use super::*;
+use crate::types::FileReport;
+use tempfile::TempDir;
+
+const SOURCE: &str = "\
+fn small() {}
+
+fn deep(items: &[u32]) {
+    for a in items {
+        for b in items {
+            if a == b {
+                run(a);
+            }
+        }
+    }
+}
+";
+
+fn report(dir: &TempDir) -> ScanReport {
+    let path = dir.path().join("lib.rs");
+    fs::write(&path, SOURCE).unwrap();
+    let mut nested = Violation::simple(5, "Nested loop".into(), "P04");
+    nested.severity = Severity::Warning;
+    let violations = vec![
+        nested,
+        Violation::simple(
+            3,
+            "Function 'deep' has nesting depth 4 (Max: 3)".into(),
+            "LAW OF COMPLEXITY",
+        ),
+        Violation::simple(7, "SQL built from strings".into(), "X01"),
+    ];
+    ScanReport {
+        files: vec![FileReport {
+            path,
+            token_count: 0,
+            complexity_score: 0,
+            violations,
+            analysis: None,
+            suppressions: Vec::new(),
+            profile: "application".into(),
+            suggested_profile: None,
+        }],
+        ..ScanReport::default()
+    }
+}
+
+#[test]
+fn test_orders_by_priority_with_context() {
+    let dir = TempDir::new().unwrap();
+    let scan = report(&dir);
+    let packet = build(&Config::default(), Some(&scan), &[]);
+
+    let x01 = packet.find("[error] X01").unwrap();
+    let complexity = packet.find("[error] LAW OF COMPLEXITY").unwrap();
+    let p04 = packet.find("[warning] P04").unwrap();
+    assert!(x01 < complexity && complexity < p04, "{packet}");
+
+    assert!(packet.contains("Limit: max_nesting_depth = 3 (profile: application)"));
+    assert!(packet.contains(rules::find("X01").unwrap().rationale));
+    assert!(packet.contains("   3 | fn deep(items: &[u32]) {"));
+    assert!(packet.contains("  11 | }"));
+    assert!(
+        !packet.contains("fn small"),
+        "only the enclosing function is quoted"
+    );
+    assert_eq!(packet.matches("fn deep").count(), 1, "{packet}");
+    assert!(packet.contains("Source: see 1."));
+}
+
+#[test]
+fn test_budget_lists_the_rest_by_location() {
+    let dir = TempDir::new().unwrap();
+    let scan = report(&dir);
+    let mut config = Config::default();
+    config.preferences.fix_packet_max_tokens = 250;
+    let cmd = CommandResult::new(
+        "cargo test".into(),
+        false,
+        Some(101),
+        "error[E0308]: mismatched types\n --> src/main.rs:3:9\n".into(),
+        0,
+    );
+
+    let packet = build(&config, Some(&scan), &[cmd]);
+
+    assert!(packet.contains("## Command failed: cargo test\nerror[E0308]: mismatched types"));
+    assert!(packet.contains("## Not expanded (token budget)"));
+    assert!(packet.contains(":3 LAW OF COMPLEXITY\n"), "{packet}");
+    assert!(
+        packet.contains(":5 P04\n"),
+        "lower priorities are not expanded either"
+    );
+}
+
+#[test]
+fn test_record_writes_on_failure_and_clears_on_pass() {
+    let dir = TempDir::new().unwrap();
+    let scan = report(&dir);
+    let mut config = Config::default();
+    let path = dir.path().join("fixme.txt");
+    config.preferences.fix_packet_path = path.display().to_string();
+
+    assert!(record(&config, true, Some(&scan), &[]).unwrap().is_none());
+    config.preferences.write_fix_packet = true;
+    assert_eq!(
+        record(&config, true, Some(&scan), &[]).unwrap(),
+        Some(path.clone())
+    );
+    assert!(path.exists());
+    assert!(record(&config, false, None, &[]).unwrap().is_none());
+    assert!(!path.exists());
+}
@@ -0,0 +1,208 @@
+//! SARIF 2.1.0 output for code scanning integrations.
+//!
+//! Each distinct `law` becomes a rule; each violation becomes a result
+//! located at its file and row. `check` adds a second run carrying the
+//! cargo diagnostics parsed from the verification commands.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+use serde_json::{json, Value};
+
+use crate::types::{ScanReport, Violation};
+use crate::verification::cargo_json::Diagnostic;
+use crate::verification::VerificationReport;
+
+const SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const VERSION: &str = "2.1.0";
+
+/// Builds a SARIF log for a structural scan.
+#[must_use]
+pub fn scan_log(report: &ScanReport) -> Value {
+    log(&[scan_run(report)])
+}
+
+/// Builds a SARIF log for a check run: the structural scan plus any
+/// cargo diagnostics collected from the verification commands.
+#[must_use]
+pub fn check_log(report: &VerificationReport) -> Value {
+    let mut runs = Vec::new();
+    if let Some(scan) = &report.scan {
+        runs.push(scan_run(scan));
+    }
+    let diagnostics: Vec<&Diagnostic> = report
+        .commands
+        .iter()
+        .filter_map(|c| c.diagnostics.as_ref())
+        .flatten()
+        .collect();
+    if !diagnostics.is_empty() {
+        runs.push(cargo_run(&diagnostics));
+    }
+    log(&runs)
+}
+
+fn log(runs: &[Value]) -> Value {
+    json!({
+        "$schema": SCHEMA,
+        "version": VERSION,
+        "runs": runs,
+    })
+}
+
+fn scan_run(report: &ScanReport) -> Value {
+    let violations: Vec<(&Path, &Violation)> = report
+        .files
+        .iter()
+        .flat_map(|f| f.violations.iter().map(move |v| (f.path.as_path(), v)))
+        .collect();
+
+    // First suggestion seen for a law becomes the rule's help text.
+    let mut rules: BTreeMap<&str, Option<&str>> = BTreeMap::new();
+    for (_, v) in &violations {
+        let suggestion = v.details.as_ref().and_then(|d| d.suggestion.as_deref());
+        let help = rules.entry(v.law).or_default();
+        if help.is_none() {
+            *help = suggestion;
+        }
+    }
+
+    let results: Vec<Value> = violations
+        .iter()
+        .map(|(path, v)| {
+            let location = location(&path.to_string_lossy(), v.row, 1);
+            result(v.law, v.severity.as_str(), &v.message, &location)
+        })
+        .collect();
+
+    json!({
+        "tool": { "driver": {
+            "name": "slopchop",
+            "version": env!("CARGO_PKG_VERSION"),
+            "informationUri": env!("CARGO_PKG_REPOSITORY"),
+            "rules": rules.into_iter().map(|(law, help)| rule(law, help)).collect::<Vec<_>>(),
+        }},
+        "results": results,
+    })
+}
+
+fn cargo_run(diagnostics: &[&Diagnostic]) -> Value {
+    let mut rules: BTreeSet<&str> = BTreeSet::new();
+    let mut results = Vec::new();
+
+    for d in diagnostics {
+        let Some(span) = d.primary_span() else {
+            continue;
+        };
+        let id = d.code.as_deref().unwrap_or(d.level.as_str());
+        rules.insert(id);
+        let level = if d.level == "error" {
+            "error"
+        } else {
+            "warning"
+        };
+        let location = location(&span.file, span.line, span.column);
+        results.push(result(id, level, &d.message, &location));
+    }
+
+    json!({
+        "tool": { "driver": {
+            "name": "cargo",
+            "rules": rules.into_iter().map(|id| rule(id, None)).collect::<Vec<_>>(),
+        }},
+        "results": results,
+    })
+}
+
+fn rule(id: &str, help: Option<&str>) -> Value {
+    let mut rule = json!({
+        "id": id,
+        "name": id,
+        "shortDescription": { "text": id },
+    });
+    if let (Some(text), Some(fields)) = (help, rule.as_object_mut()) {
+        fields.insert("help".into(), json!({ "text": text }));
+    }
+    rule
+}
+
+fn result(rule_id: &str, level: &str, message: &str, location: &Value) -> Value {
+    json!({
+        "ruleId": rule_id,
+        "level": level,
+        "message": { "text": message },
+        "locations": [location],
+    })
+}
+
+fn location(file: &str, line: usize, column: usize) -> Value {
+    json!({
+        "physicalLocation": {
+            "artifactLocation": {
+                "uri": file.replace('\\', "/"),
+                "uriBaseId": "%SRCROOT%",
+            },
+            // SARIF lines and columns are 1-based.
+            "region": { "startLine": line.max(1), "startColumn": column.max(1) },
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{FileReport, ViolationDetails};
+    use std::path::PathBuf;
+
+    fn report() -> ScanReport {
+        let details = ViolationDetails {
+            suggestion: Some("Split the file.".into()),
+            ..ViolationDetails::default()
+        };
+        ScanReport {
+            files: vec![FileReport {
+                path: PathBuf::from("src/lib.rs"),
+                token_count: 0,
+                complexity_score: 0,
+                violations: vec![
+                    Violation::simple(0, "too big".into(), "LAW OF ATOMICITY"),
+                    Violation::with_details(7, "still too big".into(), "LAW OF ATOMICITY", details),
+                    Violation::simple(3, "clone in loop".into(), "P01"),
+                ],
+                analysis: None,
+                suppressions: Vec::new(),
+                profile: "application".into(),
+                suggested_profile: None,
+            }],
+            total_violations: 3,
+            ..ScanReport::default()
+        }
+    }
+
+    #[test]
+    fn test_scan_log_maps_laws_to_rules() {
+        let log = scan_log(&report());
+        let run = &log["runs"][0];
+
+        assert_eq!(log["version"], "2.1.0");
+        assert_eq!(
+            run["tool"]["driver"]["rules"].as_array().map(Vec::len),
+            Some(2)
+        );
+        assert_eq!(run["tool"]["driver"]["rules"][0]["id"], "LAW OF ATOMICITY");
+        assert_eq!(
+            run["tool"]["driver"]["rules"][0]["help"]["text"],
+            "Split the file."
+        );
+        assert!(run["tool"]["driver"]["rules"][1].get("help").is_none());
+
+        let location = &run["results"][1]["locations"][0]["physicalLocation"];
+        assert_eq!(run["results"].as_array().map(Vec::len), Some(3));
+        assert_eq!(location["artifactLocation"]["uri"], "src/lib.rs");
+        assert_eq!(location["region"]["startLine"], 7);
+        assert_eq!(
+            run["results"][0]["locations"][0]["physicalLocation"]["region"]["startLine"],
+            1
+        );
+    }
+}
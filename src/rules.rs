@@ -0,0 +1,178 @@
+// src/rules.rs
+//! Rule registry.
+//!
+//! Every law and pattern code `SlopChop` can report is described here with
+//! its category, default severity, languages and documentation. Config can
+//! override the level of any rule under `[rules.severity]`; only violations
+//! at `error` fail a scan.
+
+mod correctness;
+mod idioms;
+mod laws;
+mod metrics;
+mod performance;
+mod security;
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::types::RuleLevel;
+use crate::types::{Severity, Violation};
+
+/// Documentation and defaults for a single rule.
+#[derive(Debug, Serialize)]
+pub struct RuleInfo {
+    pub id: &'static str,
+    pub category: &'static str,
+    pub severity: Severity,
+    pub languages: &'static [&'static str],
+    pub summary: &'static str,
+    pub rationale: &'static str,
+    pub bad: &'static str,
+    pub good: &'static str,
+}
+
+const ALL: &[&str] = &["rust", "python", "typescript", "nim"];
+const RUST: &[&str] = &["rust"];
+const PORTED: &[&str] = &["rust", "python", "typescript"];
+
+/// Every registered rule, in catalog order.
+pub fn all() -> impl Iterator<Item = &'static RuleInfo> {
+    laws::RULES
+        .iter()
+        .chain(metrics::RULES)
+        .chain(correctness::RULES)
+        .chain(idioms::RULES)
+        .chain(performance::RULES)
+        .chain(security::RULES)
+}
+
+/// Looks a rule up by id, case-insensitively. Laws can also be named by
+/// their last word (`atomicity` for `LAW OF ATOMICITY`).
+#[must_use]
+pub fn find(id: &str) -> Option<&'static RuleInfo> {
+    let id = id.trim();
+    all().find(|r| {
+        r.id.eq_ignore_ascii_case(id)
+            || r.id
+                .strip_prefix("LAW OF ")
+                .is_some_and(|law| law.eq_ignore_ascii_case(id))
+    })
+}
+
+/// Registry id of the rule `id` names, as [`find`] resolves it. Unregistered
+/// ids (custom rules) are returned as written.
+#[must_use]
+pub fn canonical_id(id: &str) -> String {
+    find(id).map_or_else(|| id.trim().to_string(), |r| r.id.to_string())
+}
+
+/// Keys `[rules.severity]` by canonical rule id, so each rule has one entry
+/// whatever casing the config used.
+///
+/// # Errors
+/// Fails when two keys name the same rule.
+pub fn deserialize_levels<'de, D>(deserializer: D) -> Result<BTreeMap<String, RuleLevel>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let mut levels = BTreeMap::new();
+    for (key, level) in BTreeMap::<String, RuleLevel>::deserialize(deserializer)? {
+        let id = canonical_id(&key);
+        if levels.insert(id.clone(), level).is_some() {
+            let message = format!("severity: `{id}` is listed more than once");
+            return Err(serde::de::Error::custom(message));
+        }
+    }
+    Ok(levels)
+}
+
+/// Level a rule runs at: the configured override, else its default.
+/// Unregistered ids (custom rules) keep `fallback`.
+#[must_use]
+pub fn effective_level(
+    id: &str,
+    levels: &BTreeMap<String, RuleLevel>,
+    fallback: Severity,
+) -> RuleLevel {
+    let configured = levels
+        .iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case(id))
+        .map(|(_, level)| *level);
+    configured.unwrap_or_else(|| RuleLevel::from(find(id).map_or(fallback, |r| r.severity)))
+}
+
+/// Drops violations of rules turned off and sets each survivor's severity.
+pub fn apply_levels(violations: &mut Vec<Violation>, levels: &BTreeMap<String, RuleLevel>) {
+    violations.retain_mut(|v| match effective_level(v.law, levels, v.severity) {
+        RuleLevel::Off => false,
+        RuleLevel::Warn => {
+            v.severity = Severity::Warning;
+            true
+        }
+        RuleLevel::Error => {
+            v.severity = Severity::Error;
+            true
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::types::RuleConfig;
+
+    #[test]
+    fn test_registry_ids_are_unique_and_documented() {
+        let mut ids: Vec<&str> = all().map(|r| r.id).collect();
+        let count = ids.len();
+        ids.sort_unstable();
+        ids.dedup();
+        assert_eq!(ids.len(), count);
+        assert!(all().all(|r| !r.rationale.is_empty() && !r.bad.is_empty() && !r.good.is_empty()));
+    }
+
+    #[test]
+    fn test_find_accepts_codes_and_law_names() {
+        assert_eq!(find("p04").map(|r| r.id), Some("P04"));
+        assert_eq!(find("atomicity").map(|r| r.id), Some("LAW OF ATOMICITY"));
+        assert!(find("Z99").is_none());
+    }
+
+    #[test]
+    fn test_levels_override_defaults() {
+        let mut levels = BTreeMap::new();
+        levels.insert("p01".to_string(), RuleLevel::Off);
+        levels.insert("X03".to_string(), RuleLevel::Warn);
+        let mut violations = vec![
+            Violation::simple(1, "clone".into(), "P01"),
+            Violation::simple(2, "secret".into(), "X03"),
+            Violation::simple(3, "nested".into(), "P04"),
+            Violation::simple(4, "house rule".into(), "my-rule").with_severity(Severity::Warning),
+        ];
+        apply_levels(&mut violations, &levels);
+
+        let got: Vec<(&str, Severity)> = violations.iter().map(|v| (v.law, v.severity)).collect();
+        assert_eq!(
+            got,
+            vec![
+                ("X03", Severity::Warning),
+                ("P04", Severity::Warning),
+                ("my-rule", Severity::Warning),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_severity_keys_load_canonical() {
+        let parse = |toml: &str| toml::from_str::<RuleConfig>(toml).map(|r| r.severity);
+        let levels =
+            parse("[severity]\nx03 = \"off\"\natomicity = \"warn\"\nmy-rule = \"warn\"\n").unwrap();
+        let keys: Vec<&str> = levels.keys().map(String::as_str).collect();
+        assert_eq!(keys, ["LAW OF ATOMICITY", "X03", "my-rule"]);
+
+        let twice = parse("[severity]\nP01 = \"off\"\np01 = \"error\"\n");
+        assert!(twice.is_err_and(|e| e.to_string().contains("`P01` is listed more than once")));
+    }
+}
@@ -0,0 +1,74 @@
+// src/rules/correctness.rs
+//! State, concurrency and resource rules.
+
+use super::{RuleInfo, RUST};
+use crate::types::Severity;
+
+pub(super) const RULES: &[RuleInfo] = &[
+    RuleInfo {
+        id: "S01",
+        category: "State",
+        severity: Severity::Error,
+        languages: RUST,
+        summary: "`static mut`",
+        rationale: "Every access to a `static mut` is a potential data race and needs \
+            `unsafe`. Use atomics, `OnceLock` or pass state explicitly.",
+        bad: "static mut COUNTER: u32 = 0;",
+        good: "static COUNTER: AtomicU32 = AtomicU32::new(0);",
+    },
+    RuleInfo {
+        id: "S02",
+        category: "State",
+        severity: Severity::Error,
+        languages: RUST,
+        summary: "Exported non-const static",
+        rationale: "A `pub static` holding interior mutability is global state any \
+            module can change. Keep it private behind functions.",
+        bad: "pub static REGISTRY: Mutex<Vec<Item>> = Mutex::new(Vec::new());",
+        good: "static REGISTRY: Mutex<Vec<Item>> = ..;\npub fn register(item: Item) { .. }",
+    },
+    RuleInfo {
+        id: "S03",
+        category: "State",
+        severity: Severity::Error,
+        languages: RUST,
+        summary: "Global `Mutex` in `lazy_static!`",
+        rationale: "A lazily initialised global lock hides shared mutable state behind \
+            a macro. Own the state and pass it where it is needed.",
+        bad: "lazy_static! { static ref CACHE: Mutex<HashMap<K, V>> = ..; }",
+        good: "struct App { cache: HashMap<K, V> }",
+    },
+    RuleInfo {
+        id: "C03",
+        category: "Concurrency",
+        severity: Severity::Error,
+        languages: RUST,
+        summary: "`MutexGuard` held across `.await`",
+        rationale: "A blocking guard held over an await point stalls the executor \
+            thread and can deadlock other tasks waiting for the lock.",
+        bad: "let guard = state.lock().unwrap();\nfetch().await;\nguard.push(x);",
+        good: "let x = fetch().await;\nstate.lock().unwrap().push(x);",
+    },
+    RuleInfo {
+        id: "C04",
+        category: "Concurrency",
+        severity: Severity::Warning,
+        languages: RUST,
+        summary: "Undocumented `Arc<Mutex<..>>`",
+        rationale: "Shared locks need a stated invariant: what they protect and who \
+            takes them. Without one, lock ordering bugs creep in.",
+        bad: "pending: Arc<Mutex<Vec<Job>>>,",
+        good: "/// Jobs queued by the API; drained only by the worker loop.\npending: Arc<Mutex<Vec<Job>>>,",
+    },
+    RuleInfo {
+        id: "R07",
+        category: "Resource",
+        severity: Severity::Error,
+        languages: RUST,
+        summary: "`BufWriter` never flushed",
+        rationale: "Dropping a `BufWriter` swallows write errors from the final flush. \
+            Flush explicitly so failures surface.",
+        bad: "let mut w = BufWriter::new(file);\nwrite!(w, \"{data}\")?;",
+        good: "let mut w = BufWriter::new(file);\nwrite!(w, \"{data}\")?;\nw.flush()?;",
+    },
+];
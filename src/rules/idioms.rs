@@ -0,0 +1,97 @@
+// src/rules/idioms.rs
+//! Semantic, idiomatic and logic rules.
+
+use super::{RuleInfo, RUST};
+use crate::types::Severity;
+
+pub(super) const RULES: &[RuleInfo] = &[
+    RuleInfo {
+        id: "M03",
+        category: "Semantic",
+        severity: Severity::Error,
+        languages: RUST,
+        summary: "Getter takes `&mut self`",
+        rationale: "A `get_*` name promises a read. Mutating behind it surprises callers \
+            and forces exclusive borrows where shared ones would do.",
+        bad: "fn get_name(&mut self) -> &str { self.reads += 1; &self.name }",
+        good: "fn name(&self) -> &str { &self.name }",
+    },
+    RuleInfo {
+        id: "M04",
+        category: "Semantic",
+        severity: Severity::Error,
+        languages: RUST,
+        summary: "`is_*`/`has_*` does not return `bool`",
+        rationale: "Predicate names are read as yes/no questions. Returning anything \
+            else misleads every call site.",
+        bad: "fn is_ready(&self) -> Option<Handle> { .. }",
+        good: "fn is_ready(&self) -> bool { .. }\nfn handle(&self) -> Option<Handle> { .. }",
+    },
+    RuleInfo {
+        id: "M05",
+        category: "Semantic",
+        severity: Severity::Error,
+        languages: RUST,
+        summary: "`calculate_*`/`compute_*` takes `&mut self`",
+        rationale: "Calculations should be pure. Side effects hidden in a compute \
+            function make results depend on call order.",
+        bad: "fn calculate_total(&mut self) -> u64 { self.cached = ..; self.cached }",
+        good: "fn calculate_total(&self) -> u64 { self.items.iter().sum() }",
+    },
+    RuleInfo {
+        id: "I01",
+        category: "Idiomatic",
+        severity: Severity::Error,
+        languages: RUST,
+        summary: "Manual `From` impl that could be derived",
+        rationale: "Field-by-field `From` boilerplate drifts out of sync with the types \
+            it converts. Prefer a derive or a named constructor.",
+        bad: "impl From<Error> for AppError { fn from(e: Error) -> Self { Self::Io(e) } }",
+        good:
+            "#[derive(thiserror::Error)]\nenum AppError { #[error(transparent)] Io(#[from] Error) }",
+    },
+    RuleInfo {
+        id: "I02",
+        category: "Idiomatic",
+        severity: Severity::Warning,
+        languages: RUST,
+        summary: "Duplicate match arm bodies",
+        rationale: "Identical arms are usually a copy-paste that should be merged with \
+            `|`, or a bug where one arm was meant to differ.",
+        bad: "match k { A => run(), B => run(), C => stop() }",
+        good: "match k { A | B => run(), C => stop() }",
+    },
+    RuleInfo {
+        id: "L02",
+        category: "Logic",
+        severity: Severity::Warning,
+        languages: RUST,
+        summary: "`<=`/`>=` compared against `.len()`",
+        rationale: "Inclusive comparisons with a length are the classic off-by-one \
+            when the result feeds an index.",
+        bad: "if i <= items.len() { items[i] }",
+        good: "if i < items.len() { items[i] }\n// or: items.get(i)",
+    },
+    RuleInfo {
+        id: "L03",
+        category: "Logic",
+        severity: Severity::Error,
+        languages: RUST,
+        summary: "Unchecked `[0]` or `.first().unwrap()`",
+        rationale: "Indexing the first element panics on empty input, which is exactly \
+            the input tests forget.",
+        bad: "let head = items[0];",
+        good: "let Some(head) = items.first() else { return };",
+    },
+    RuleInfo {
+        id: "L04",
+        category: "Logic",
+        severity: Severity::Warning,
+        languages: RUST,
+        summary: "`.unwrap()` or `.expect()` outside test code",
+        rationale: "Panicking shortcuts turn recoverable errors into crashes. \
+            Propagate the error with `?` or handle the `None` case.",
+        bad: "let cfg = std::fs::read_to_string(path).unwrap();",
+        good: "let cfg = std::fs::read_to_string(path)?;",
+    },
+];
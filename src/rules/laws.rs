@@ -0,0 +1,65 @@
+// src/rules/laws.rs
+//! The core laws: size, complexity, syntax, paranoia and justification.
+
+use super::{RuleInfo, ALL};
+use crate::types::Severity;
+
+pub(super) const RULES: &[RuleInfo] = &[
+    RuleInfo {
+        id: "LAW OF ATOMICITY",
+        category: "Law",
+        severity: Severity::Error,
+        languages: ALL,
+        summary: "File exceeds `max_file_tokens`",
+        rationale: "A file has to fit in a single edit. Large files get truncated or \
+            half-rewritten by models and are slow to review. Split by responsibility \
+            before the file becomes the place everything lands.",
+        bad: "// src/app.rs: 4,800 tokens of parsing, IO and rendering",
+        good: "// src/app/parse.rs, src/app/io.rs, src/app/render.rs",
+    },
+    RuleInfo {
+        id: "LAW OF COMPLEXITY",
+        category: "Law",
+        severity: Severity::Error,
+        languages: ALL,
+        summary: "Function too complex, too nested, too many arguments or too long a name",
+        rationale: "Cognitive complexity, nesting depth, argument count and name length \
+            all measure how much a reader must hold in their head at once. Past the \
+            limits, bugs hide in branches nobody traces.",
+        bad: "fn handle(a: A, b: B, c: C, d: D, e: E, f: F) {\n    if x { for y in ys { if z { match w { .. } } } }\n}",
+        good: "fn handle(req: &Request) {\n    let Some(item) = find(req) else { return };\n    apply(item);\n}",
+    },
+    RuleInfo {
+        id: "LAW OF INTEGRITY",
+        category: "Law",
+        severity: Severity::Error,
+        languages: ALL,
+        summary: "File does not parse",
+        rationale: "Syntax errors mean every other check ran on a broken tree. \
+            Truncated model output usually shows up here first.",
+        bad: "fn main() {\n    println!(\"hi\");",
+        good: "fn main() {\n    println!(\"hi\");\n}",
+    },
+    RuleInfo {
+        id: "LAW OF PARANOIA",
+        category: "Law",
+        severity: Severity::Error,
+        languages: &["rust", "nim"],
+        summary: "`unsafe` without a `// SAFETY:` comment",
+        rationale: "Unsafe code without a written invariant cannot be reviewed. \
+            Justify every unsafe block, or forbid them with `ban_unsafe`.",
+        bad: "unsafe { ptr.read() }",
+        good: "// SAFETY: `ptr` is valid and aligned for the lifetime of `buf`.\nunsafe { ptr.read() }",
+    },
+    RuleInfo {
+        id: "LAW OF JUSTIFICATION",
+        category: "Law",
+        severity: Severity::Error,
+        languages: ALL,
+        summary: "Suppression without a reason",
+        rationale: "`slopchop:allow` is an audit trail. A suppression that does not say \
+            why is indistinguishable from one added to make the build pass.",
+        bad: "// slopchop:allow(P01)",
+        good: "// slopchop:allow(P01, reason = \"snapshot per request is intended\")",
+    },
+];
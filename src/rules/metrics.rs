@@ -0,0 +1,76 @@
+// src/rules/metrics.rs
+//! Structural metrics computed across the whole codebase.
+
+use super::{RuleInfo, ALL, RUST};
+use crate::types::Severity;
+
+pub(super) const RULES: &[RuleInfo] = &[
+    RuleInfo {
+        id: "LCOM4",
+        category: "Structure",
+        severity: Severity::Error,
+        languages: RUST,
+        summary: "Type's methods split into unrelated groups (`max_lcom4`)",
+        rationale: "Lack of cohesion counts groups of methods that share no fields. \
+            More than one group means the type is several types glued together.",
+        bad: "impl Session {\n    fn login(&mut self) { self.user = .. }\n    fn render(&self) { self.theme.. }\n}",
+        good: "impl Auth { fn login(&mut self) { .. } }\nimpl View { fn render(&self) { .. } }",
+    },
+    RuleInfo {
+        id: "CBO",
+        category: "Structure",
+        severity: Severity::Error,
+        languages: RUST,
+        summary: "Type coupled to too many others (`max_cbo`)",
+        rationale: "Coupling between objects counts distinct external calls made by a \
+            type's methods. High coupling means any change elsewhere can break it.",
+        bad: "impl Engine { /* calls into fs, net, db, cache, log, ui, .. */ }",
+        good: "impl Engine { /* delegates to free functions per concern */ }",
+    },
+    RuleInfo {
+        id: "SFOUT",
+        category: "Structure",
+        severity: Severity::Error,
+        languages: RUST,
+        summary: "Method fans out to too many calls (`max_sfout`)",
+        rationale: "Structural fan-out is the number of distinct functions one method \
+            calls. A method orchestrating everything is hard to test in isolation.",
+        bad: "fn run(&self) { a(); b(); c(); d(); e(); f(); g(); h(); }",
+        good: "fn run(&self) { self.prepare(); self.execute(); }",
+    },
+    RuleInfo {
+        id: "AHF",
+        category: "Structure",
+        severity: Severity::Error,
+        languages: RUST,
+        summary: "Too few fields hidden (`min_ahf`)",
+        rationale: "Attribute hiding factor is the share of private fields. Public \
+            fields let every caller depend on representation.",
+        bad: "pub struct Cache { pub map: HashMap<K, V>, pub hits: u64 }",
+        good: "pub struct Cache { map: HashMap<K, V>, hits: u64 }\nimpl Cache { pub fn hits(&self) -> u64 { self.hits } }",
+    },
+    RuleInfo {
+        id: "LAW OF ARCHITECTURE",
+        category: "Structure",
+        severity: Severity::Error,
+        languages: ALL,
+        summary: "Import breaks a declared layer or `forbid` rule (`[architecture]`)",
+        rationale: "Layers declared in slopchop.toml say which way dependencies may \
+            flow. An import against that direction, or one a `forbid` rule names, \
+            erodes the boundary a little more with each change until it is gone.",
+        bad: "# [[architecture.forbid]] from = \"src/ui/**\", to = \"src/db/**\"\n// src/ui/button.rs\nuse crate::db::pool;",
+        good: "// src/ui/button.rs\nuse crate::services::Accounts;",
+    },
+    RuleInfo {
+        id: "LAW OF LOCALITY",
+        category: "Structure",
+        severity: Severity::Warning,
+        languages: ALL,
+        summary: "Import reaches too far, breaks layering, or closes a cycle (`[rules.locality]`)",
+        rationale: "Dependencies should stay close or route through stable hubs. Far \
+            sideways imports, upward imports and cycles tie distant modules together, \
+            so a change in one breaks the other. Severity follows `[rules.locality].mode`.",
+        bad: "// src/ui/button.rs\nuse crate::db::pool::internal::Conn;",
+        good: "// src/ui/button.rs\nuse crate::services::Accounts;",
+    },
+];
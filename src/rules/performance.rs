@@ -0,0 +1,63 @@
+// src/rules/performance.rs
+//! Performance patterns, mostly work repeated inside loops.
+
+use super::{RuleInfo, PORTED, RUST};
+use crate::types::Severity;
+
+pub(super) const RULES: &[RuleInfo] = &[
+    RuleInfo {
+        id: "P01",
+        category: "Performance",
+        severity: Severity::Error,
+        languages: PORTED,
+        summary: "Clone of loop-invariant data inside a loop",
+        rationale: "Cloning the same value every iteration multiplies allocation by the \
+            loop count. Hoist the clone or borrow instead.",
+        bad: "for row in &rows {\n    let cfg = defaults.clone();\n    apply(&cfg, row);\n}",
+        good: "for row in &rows {\n    apply(&defaults, row);\n}",
+    },
+    RuleInfo {
+        id: "P02",
+        category: "Performance",
+        severity: Severity::Error,
+        languages: PORTED,
+        summary: "Allocation inside a loop",
+        rationale: "`to_string`, `format!` or collection copies per iteration churn the \
+            allocator for values that could be built once.",
+        bad: "for id in &ids {\n    let prefix = \"user-\".to_string();\n    keys.push(prefix + id);\n}",
+        good: "let mut key = String::new();\nfor id in &ids {\n    key.clear();\n    key.push_str(\"user-\");\n    key.push_str(id);\n}",
+    },
+    RuleInfo {
+        id: "P03",
+        category: "Performance",
+        severity: Severity::Error,
+        languages: PORTED,
+        summary: "Query per loop iteration (N+1)",
+        rationale: "One database round trip per item scales with data size. Fetch the \
+            batch with a single query.",
+        bad: "for id in ids {\n    let user = db.query_one(\"SELECT .. WHERE id = $1\", &[&id])?;\n}",
+        good: "let users = db.query(\"SELECT .. WHERE id = ANY($1)\", &[&ids])?;",
+    },
+    RuleInfo {
+        id: "P04",
+        category: "Performance",
+        severity: Severity::Warning,
+        languages: RUST,
+        summary: "Nested loop over collections",
+        rationale: "A loop inside a loop is quadratic. Often one side can become a \
+            `HashMap` or `HashSet` lookup.",
+        bad: "for a in &left {\n    for b in &right {\n        if a.id == b.id { .. }\n    }\n}",
+        good: "let index: HashMap<_, _> = right.iter().map(|b| (b.id, b)).collect();\nfor a in &left {\n    if let Some(b) = index.get(&a.id) { .. }\n}",
+    },
+    RuleInfo {
+        id: "P06",
+        category: "Performance",
+        severity: Severity::Warning,
+        languages: RUST,
+        summary: "Linear search inside a loop",
+        rationale: "`contains`, `find` or `position` on a `Vec` inside a loop is a hidden \
+            nested loop. Build a set once.",
+        bad: "for name in &names {\n    if banned.contains(name) { .. }\n}",
+        good: "let banned: HashSet<_> = banned.iter().collect();\nfor name in &names {\n    if banned.contains(name) { .. }\n}",
+    },
+];
@@ -0,0 +1,41 @@
+// src/rules/security.rs
+//! Security patterns.
+
+use super::{RuleInfo, PORTED};
+use crate::types::Severity;
+
+pub(super) const RULES: &[RuleInfo] = &[
+    RuleInfo {
+        id: "X01",
+        category: "Security",
+        severity: Severity::Error,
+        languages: PORTED,
+        summary: "SQL built by string formatting",
+        rationale: "Interpolating values into SQL lets input rewrite the query. \
+            Parameters keep data and code apart.",
+        bad: "let q = format!(\"SELECT * FROM users WHERE name = '{name}'\");",
+        good: "client.query(\"SELECT * FROM users WHERE name = $1\", &[&name])?;",
+    },
+    RuleInfo {
+        id: "X02",
+        category: "Security",
+        severity: Severity::Error,
+        languages: PORTED,
+        summary: "Shell command built from variables",
+        rationale: "A command string passed to a shell is parsed again, so input can \
+            add commands. Pass an argument list and skip the shell.",
+        bad: "Command::new(\"sh\").arg(\"-c\").arg(format!(\"git checkout {branch}\"))",
+        good: "Command::new(\"git\").args([\"checkout\", branch])",
+    },
+    RuleInfo {
+        id: "X03",
+        category: "Security",
+        severity: Severity::Error,
+        languages: PORTED,
+        summary: "Hardcoded secret",
+        rationale: "Secrets in source end up in history, logs and every clone. Read \
+            them from the environment or a secret store.",
+        bad: "const API_KEY: &str = \"sk-live-4f9a8b7c\";",
+        good: "let api_key = std::env::var(\"API_KEY\")?;",
+    },
+];
@@ -1,20 +1,64 @@
 // src/types.rs
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
 use crate::analysis::aggregator::FileAnalysis;
+use crate::analysis::suppress::Suppression;
+use crate::baseline::BaselineOutcome;
+use crate::diff::DiffOutcome;
 
 /// A single violation detected during analysis.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Violation {
     pub row: usize,
     pub message: String,
-    pub law: &'static str,
+    #[serde(deserialize_with = "deserialize_law")]
+    pub law: Law,
     pub details: Option<ViolationDetails>,
+    pub severity: Severity,
+    /// Machine-applicable edit, applied by `slopchop fix`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fix: Option<Fix>,
+}
+
+/// How a violation is reported.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    #[serde(alias = "warn")]
+    Warning,
+    #[default]
+    Error,
+}
+
+impl Severity {
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Warning => "warning",
+            Self::Error => "error",
+        }
+    }
+}
+
+/// A structured fix: edits that resolve the violation when applied together.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Fix {
+    /// What the fix does, e.g. "Replace `.unwrap()` with `?`".
+    pub description: String,
+    pub edits: Vec<Edit>,
+}
+
+/// Replaces the bytes `start..end` of the file with `replacement`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Edit {
+    pub start: usize,
+    pub end: usize,
+    pub replacement: String,
 }
 
 /// Rich details for prescriptive violation reporting.
-#[derive(Debug, Clone, Default, Serialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ViolationDetails {
     pub function_name: Option<String>,
     pub analysis: Vec<String>,
@@ -30,6 +74,8 @@ impl Violation {
             message,
             law,
             details: None,
+            severity: Severity::Error,
+            fix: None,
         }
     }
 
@@ -46,12 +92,40 @@ impl Violation {
             message,
             law,
             details: Some(details),
+            severity: Severity::Error,
+            fix: None,
         }
     }
+
+    /// Attaches a machine-applicable fix.
+    #[must_use]
+    pub fn with_fix(mut self, fix: Fix) -> Self {
+        self.fix = Some(fix);
+        self
+    }
+
+    /// Overrides the severity.
+    #[must_use]
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+}
+
+/// Spelled through an alias so serde does not treat the field as borrowed
+/// from the input; deserialized laws are interned instead.
+type Law = &'static str;
+
+fn deserialize_law<'de, D>(deserializer: D) -> Result<Law, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let law = String::deserialize(deserializer)?;
+    Ok(crate::utils::intern(&law))
 }
 
 /// Analysis results for a single file.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FileReport {
     pub path: PathBuf,
     pub token_count: usize,
@@ -59,6 +133,14 @@ pub struct FileReport {
     pub violations: Vec<Violation>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub analysis: Option<FileAnalysis>,
+    /// Inline suppressions found in the file, kept for auditing.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub suppressions: Vec<Suppression>,
+    /// Governance profile whose rules judged this file.
+    pub profile: String,
+    /// Profile the heuristics suggest when it differs from `profile`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suggested_profile: Option<String>,
 }
 
 impl FileReport {
@@ -82,13 +164,36 @@ pub struct ScanReport {
     pub total_tokens: usize,
     pub total_violations: usize,
     pub duration_ms: u128,
+    /// Set when a baseline was applied to this scan.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub baseline: Option<BaselineOutcome>,
+    /// Set when the report was narrowed to changed files.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub diff: Option<DiffOutcome>,
 }
 
 impl ScanReport {
-    /// Returns true if any violations were found.
+    /// Returns true if any error-level violations were found. Warnings
+    /// are reported but never fail a scan.
     #[must_use]
     pub fn has_errors(&self) -> bool {
-        self.total_violations > 0
+        self.error_count() > 0
+    }
+
+    /// Returns the number of error-level violations.
+    #[must_use]
+    pub fn error_count(&self) -> usize {
+        self.files
+            .iter()
+            .flat_map(|f| &f.violations)
+            .filter(|v| v.severity == Severity::Error)
+            .count()
+    }
+
+    /// Returns the number of warning-level violations.
+    #[must_use]
+    pub fn warning_count(&self) -> usize {
+        self.total_violations.saturating_sub(self.error_count())
     }
 
     /// Returns the number of clean files.
@@ -1,5 +1,7 @@
 // src/utils.rs
 use sha2::{Digest, Sha256};
+use std::collections::HashSet;
+use std::sync::{LazyLock, Mutex, PoisonError};
 
 /// Computes SHA256 hash of content with normalized line endings.
 /// Always normalizes CRLF/CR to LF before hashing to ensure consistent
@@ -10,4 +12,18 @@ pub fn compute_sha256(content: &str) -> String {
     let mut hasher = Sha256::new();
     hasher.update(normalized.as_bytes());
     format!("{:x}", hasher.finalize())
-}
\ No newline at end of file
+}
+
+/// Returns a `&'static str` equal to `s`. Violations carry `&'static str`
+/// laws; ids from config or the scan cache are interned so repeated loads
+/// do not leak a copy each time.
+pub fn intern(s: &str) -> &'static str {
+    static STRINGS: LazyLock<Mutex<HashSet<&'static str>>> = LazyLock::new(Mutex::default);
+    let mut strings = STRINGS.lock().unwrap_or_else(PoisonError::into_inner);
+    if let Some(existing) = strings.get(s) {
+        return existing;
+    }
+    let leaked: &'static str = Box::leak(s.to_string().into_boxed_str());
+    strings.insert(leaked);
+    leaked
+}
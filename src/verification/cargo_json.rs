@@ -0,0 +1,245 @@
+//! Structured diagnostics from cargo's JSON message format.
+//!
+//! Cargo-based check commands are rewritten to emit `--message-format=json`
+//! so that error and warning counts come from typed records rather than
+//! substring matching on rendered text.
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+/// Cargo subcommands that accept `--message-format`.
+const JSON_SUBCOMMANDS: &[&str] = &["build", "check", "clippy", "test"];
+
+/// A compiler diagnostic reported by cargo.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Diagnostic {
+    /// Severity as reported by rustc ("error", "warning", ...).
+    pub level: String,
+    /// Error or lint code, e.g. `E0308` or `clippy::unwrap_used`.
+    pub code: Option<String>,
+    /// Primary message without location or notes.
+    pub message: String,
+    /// Source locations attached to the diagnostic.
+    pub spans: Vec<DiagnosticSpan>,
+    /// Human-readable rendering, as rustc would print it.
+    pub rendered: String,
+}
+
+/// A source location attached to a diagnostic.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DiagnosticSpan {
+    pub file: String,
+    pub line: usize,
+    pub column: usize,
+    pub is_primary: bool,
+}
+
+impl Diagnostic {
+    /// The span rustc points at, falling back to the first span.
+    #[must_use]
+    pub fn primary_span(&self) -> Option<&DiagnosticSpan> {
+        self.spans
+            .iter()
+            .find(|s| s.is_primary)
+            .or_else(|| self.spans.first())
+    }
+
+    /// `file:line` of the primary span.
+    #[must_use]
+    pub fn location(&self) -> Option<String> {
+        self.primary_span()
+            .map(|s| format!("{}:{}", s.file, s.line))
+    }
+
+    fn is_summary(&self) -> bool {
+        self.spans.is_empty()
+            && (self.message.starts_with("aborting due to") || self.message.ends_with("emitted"))
+    }
+}
+
+/// Cargo output split into typed diagnostics and everything else.
+#[derive(Debug, Default)]
+pub struct CargoOutput {
+    /// Deduplicated diagnostics in emission order.
+    pub diagnostics: Vec<Diagnostic>,
+    /// Human-readable output with each diagnostic rendered in place.
+    pub text: String,
+    /// Output lines that were not diagnostics (progress, test results).
+    pub other: String,
+}
+
+#[derive(Deserialize)]
+struct RawMessage {
+    reason: String,
+    message: Option<RawDiagnostic>,
+}
+
+#[derive(Deserialize)]
+struct RawDiagnostic {
+    message: String,
+    level: String,
+    code: Option<RawCode>,
+    #[serde(default)]
+    spans: Vec<RawSpan>,
+    rendered: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct RawCode {
+    code: String,
+}
+
+#[derive(Deserialize)]
+struct RawSpan {
+    file_name: String,
+    line_start: usize,
+    column_start: usize,
+    is_primary: bool,
+}
+
+impl RawDiagnostic {
+    fn into_diagnostic(self) -> Diagnostic {
+        Diagnostic {
+            level: self.level,
+            code: self.code.map(|c| c.code),
+            message: self.message,
+            spans: self
+                .spans
+                .into_iter()
+                .map(|s| DiagnosticSpan {
+                    file: s.file_name,
+                    line: s.line_start,
+                    column: s.column_start,
+                    is_primary: s.is_primary,
+                })
+                .collect(),
+            rendered: self.rendered.unwrap_or_default(),
+        }
+    }
+}
+
+/// Rewrites a cargo command to emit JSON messages.
+/// Returns `None` for non-cargo commands or ones that already pick a format.
+#[must_use]
+pub fn json_command(cmd: &str) -> Option<String> {
+    let parts: Vec<&str> = cmd.split_whitespace().collect();
+    if parts.first() != Some(&"cargo") || parts.iter().any(|p| p.starts_with("--message-format")) {
+        return None;
+    }
+    let subcommand = parts.iter().skip(1).find(|p| !p.starts_with('+'))?;
+    if !JSON_SUBCOMMANDS.contains(subcommand) {
+        return None;
+    }
+
+    // Flags after `--` belong to rustc or the test harness, not cargo.
+    let split = parts.iter().position(|p| *p == "--").unwrap_or(parts.len());
+    let (cargo_args, rest) = parts.split_at(split);
+    let mut rewritten: Vec<&str> = cargo_args.to_vec();
+    rewritten.push("--message-format=json");
+    rewritten.extend(rest);
+    Some(rewritten.join(" "))
+}
+
+/// Parses mixed cargo output. JSON lines other than compiler messages
+/// (artifacts, build-finished) are dropped.
+#[must_use]
+pub fn parse(output: &str) -> CargoOutput {
+    let mut parsed = CargoOutput::default();
+    let mut seen = HashSet::new();
+
+    for line in output.lines() {
+        let raw = line
+            .starts_with('{')
+            .then(|| serde_json::from_str::<RawMessage>(line).ok())
+            .flatten();
+        let Some(raw) = raw else {
+            push_line(&mut parsed.text, line);
+            push_line(&mut parsed.other, line);
+            continue;
+        };
+        let Some(diagnostic) = raw
+            .message
+            .filter(|_| raw.reason == "compiler-message")
+            .map(RawDiagnostic::into_diagnostic)
+        else {
+            continue;
+        };
+        if diagnostic.is_summary() || !seen.insert(dedup_key(&diagnostic)) {
+            continue;
+        }
+        parsed.text.push_str(&diagnostic.rendered);
+        parsed.diagnostics.push(diagnostic);
+    }
+    parsed
+}
+
+/// `--all-targets` reports the same lint once per target.
+fn dedup_key(d: &Diagnostic) -> (String, Option<String>, String, Option<String>) {
+    (
+        d.level.clone(),
+        d.code.clone(),
+        d.message.clone(),
+        d.location(),
+    )
+}
+
+fn push_line(out: &mut String, line: &str) {
+    out.push_str(line);
+    out.push('\n');
+}
+
+/// Number of diagnostics at the given level.
+#[must_use]
+pub fn count_level(diagnostics: &[Diagnostic], level: &str) -> usize {
+    diagnostics.iter().filter(|d| d.level == level).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MESSAGE: &str = r#"{"reason":"compiler-message","package_id":"demo","message":{"message":"mismatched types","code":{"code":"E0308","explanation":null},"level":"error","spans":[{"file_name":"src/main.rs","line_start":3,"column_start":17,"is_primary":true}],"children":[],"rendered":"error[E0308]: mismatched types\n --> src/main.rs:3:17\n\n"}}"#;
+    const SUMMARY: &str = r#"{"reason":"compiler-message","message":{"message":"aborting due to 1 previous error","code":null,"level":"error","spans":[],"rendered":"error: aborting due to 1 previous error\n"}}"#;
+    const ARTIFACT: &str = r#"{"reason":"build-finished","success":false}"#;
+
+    #[test]
+    fn test_json_command_rewrites_cargo() {
+        assert_eq!(
+            json_command("cargo clippy --all-targets -- -D warnings").as_deref(),
+            Some("cargo clippy --all-targets --message-format=json -- -D warnings")
+        );
+        assert_eq!(
+            json_command("cargo +nightly test").as_deref(),
+            Some("cargo +nightly test --message-format=json")
+        );
+        assert_eq!(json_command("cargo fmt"), None);
+        assert_eq!(json_command("cargo check --message-format=short"), None);
+        assert_eq!(json_command("npm test"), None);
+    }
+
+    #[test]
+    fn test_parse_extracts_diagnostics() {
+        let raw = format!(
+            "   Compiling demo\n{MESSAGE}\n{MESSAGE}\n{SUMMARY}\n{ARTIFACT}\n[exit code: 101]\n"
+        );
+        let parsed = parse(&raw);
+
+        assert_eq!(
+            parsed.diagnostics.len(),
+            1,
+            "duplicates and summaries dropped"
+        );
+        let first = parsed.diagnostics.first();
+        assert_eq!(first.and_then(|d| d.code.as_deref()), Some("E0308"));
+        assert_eq!(
+            first.and_then(Diagnostic::location).as_deref(),
+            Some("src/main.rs:3")
+        );
+        assert_eq!(count_level(&parsed.diagnostics, "error"), 1);
+        assert!(parsed
+            .text
+            .contains("error[E0308]: mismatched types\n --> src/main.rs:3:17"));
+        assert_eq!(parsed.other, "   Compiling demo\n[exit code: 101]\n");
+    }
+}
@@ -0,0 +1,73 @@
+//! Output compression for the flight recorder.
+//!
+//! A lint repeated on hundreds of call sites should cost a few blocks of
+//! context, not hundreds. rustc/clippy diagnostics are grouped by code and
+//! message template; anything else has runs of repeated lines collapsed.
+
+mod generic;
+mod rustc;
+
+use super::cargo_json;
+
+/// Diagnostics shown in full per group; the rest are listed as `file:line`.
+pub const SHOWN_PER_GROUP: usize = 3;
+
+/// Compresses raw command output for the flight recorder.
+#[must_use]
+pub fn compress(output: &str) -> String {
+    rustc::compress(output).unwrap_or_else(|| generic::compress(output))
+}
+
+/// Compresses cargo output from its structured diagnostics.
+/// `other` holds the lines that were not compiler messages.
+#[must_use]
+pub fn compress_diagnostics(other: &str, diagnostics: &[cargo_json::Diagnostic]) -> String {
+    rustc::compress_records(other, diagnostics)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unwrap_lint(file: &str, line: usize) -> String {
+        format!(
+            "warning: used `unwrap()` on a `Result` value\n  \
+             --> {file}:{line}:5\n   |\n{line} |     x.unwrap();\n   |     ^^^^^^^^^^\n   |\n   \
+             = help: for further information visit https://rust-lang.github.io/rust-clippy/master/index.html#unwrap_used\n\n"
+        )
+    }
+
+    #[test]
+    fn test_groups_repeated_clippy_lints() {
+        let mut raw = String::from("    Checking demo v0.1.0\n");
+        for line in 1..=5 {
+            raw.push_str(&unwrap_lint("src/lib.rs", line * 10));
+        }
+        raw.push_str("error[E0308]: mismatched types\n --> src/main.rs:3:9\n  |\n3 |     let x: u8 = \"a\";\n\n");
+        raw.push_str("warning: `demo` (lib) generated 5 warnings\n");
+
+        let out = compress(&raw);
+
+        assert!(out.starts_with("    Checking demo v0.1.0\n"));
+        assert!(out.contains("error[E0308]: mismatched types (1 occurrence)"));
+        assert!(
+            out.contains("warning[clippy::unwrap_used]: used `_` on a `_` value (5 occurrences)")
+        );
+        assert!(
+            out.find("E0308") < out.find("clippy::unwrap_used"),
+            "errors come first"
+        );
+        assert_eq!(out.matches("x.unwrap();").count(), SHOWN_PER_GROUP);
+        assert!(out.contains("... 2 more:\n    src/lib.rs:40\n    src/lib.rs:50\n"));
+        assert!(out.contains("generated 5 warnings"));
+    }
+
+    #[test]
+    fn test_unknown_tool_collapses_consecutive_repeats() {
+        let raw = "FAIL parse\nretrying\nretrying\nretrying\nFAIL parse\n\n\ndone\n";
+        assert_eq!(
+            compress(raw),
+            "FAIL parse\nretrying (x3)\nFAIL parse\n\ndone\n"
+        );
+    }
+}
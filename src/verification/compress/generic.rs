@@ -0,0 +1,54 @@
+//! Fallback compression for tools without a dedicated parser.
+
+use std::fmt::Write;
+
+/// Lines kept from the head and tail once collapsed output is still too long.
+const HEAD_LINES: usize = 300;
+const TAIL_LINES: usize = 50;
+
+/// Collapses runs of a repeated line into one line with a count, then
+/// truncates the middle of very long output. Order is preserved: a line
+/// that recurs after something else is kept where it recurs.
+#[must_use]
+pub fn compress(output: &str) -> String {
+    let mut runs: Vec<(&str, usize)> = Vec::new();
+
+    for line in output.lines().map(str::trim_end) {
+        match runs.last_mut() {
+            Some((last, count)) if *last == line => *count += 1,
+            // Keep single blank lines as separators, but never lead with one.
+            None if line.is_empty() => {}
+            _ => runs.push((line, 1)),
+        }
+    }
+
+    let lines: Vec<String> = runs
+        .iter()
+        .map(|&(line, n)| match n {
+            n if n > 1 && !line.is_empty() => format!("{line} (x{n})"),
+            _ => line.to_string(),
+        })
+        .collect();
+
+    truncate(&lines)
+}
+
+fn truncate(lines: &[String]) -> String {
+    let mut out = String::new();
+    if lines.len() <= HEAD_LINES + TAIL_LINES {
+        for line in lines {
+            let _ = writeln!(out, "{line}");
+        }
+        return out;
+    }
+
+    let omitted = lines.len() - HEAD_LINES - TAIL_LINES;
+    for line in lines.iter().take(HEAD_LINES) {
+        let _ = writeln!(out, "{line}");
+    }
+    let _ = writeln!(out, "... {omitted} lines omitted ...");
+    for line in lines.iter().skip(HEAD_LINES + omitted) {
+        let _ = writeln!(out, "{line}");
+    }
+    out
+}
@@ -0,0 +1,170 @@
+//! Grouping of rendered rustc/clippy diagnostics.
+
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::sync::LazyLock;
+
+use regex::{Captures, Regex};
+
+use super::{generic, SHOWN_PER_GROUP};
+use crate::verification::cargo_json;
+
+static HEADER: LazyLock<Regex> =
+    LazyLock::new(|| compile(r"^(error|warning)(?:\[([^\]]+)\])?: (.+)$"));
+static LOCATION: LazyLock<Regex> = LazyLock::new(|| compile(r"^\s*--> (.+?):(\d+):\d+"));
+static LINT_ATTR: LazyLock<Regex> =
+    LazyLock::new(|| compile(r"#\[(?:warn|deny|forbid)\(([\w:]+)\)\]|-D ([\w:-]+)"));
+static CLIPPY_URL: LazyLock<Regex> = LazyLock::new(|| compile(r"rust-clippy/[^#\s]*#(\w+)"));
+static QUOTED: LazyLock<Regex> = LazyLock::new(|| compile(r"`[^`]*`"));
+
+fn compile(pattern: &str) -> Regex {
+    Regex::new(pattern).unwrap_or_else(|_| panic!("Invalid Regex"))
+}
+
+struct Diagnostic {
+    key: (String, Option<String>, String),
+    location: String,
+    text: String,
+}
+
+/// Groups located diagnostics by level, code and message template.
+/// Returns `None` when the output contains no rustc-style diagnostics.
+#[must_use]
+pub fn compress(output: &str) -> Option<String> {
+    let (passthrough, diagnostics) = split(output);
+    if diagnostics.is_empty() {
+        return None;
+    }
+
+    Some(render(&passthrough.join("\n"), diagnostics))
+}
+
+/// Groups diagnostics parsed from cargo's JSON messages.
+#[must_use]
+pub fn compress_records(other: &str, records: &[cargo_json::Diagnostic]) -> String {
+    let diagnostics = records
+        .iter()
+        .map(|record| Diagnostic {
+            key: (
+                record.level.clone(),
+                record.code.clone(),
+                QUOTED.replace_all(&record.message, "`_`").into_owned(),
+            ),
+            location: record.location().unwrap_or_default(),
+            text: record.rendered.trim_end().to_string(),
+        })
+        .collect();
+    render(other, diagnostics)
+}
+
+fn render(passthrough: &str, diagnostics: Vec<Diagnostic>) -> String {
+    let mut out = generic::compress(passthrough);
+    for group in group(diagnostics) {
+        render_group(&group, &mut out);
+    }
+    out
+}
+
+fn split(output: &str) -> (Vec<&str>, Vec<Diagnostic>) {
+    let mut passthrough = Vec::new();
+    let mut diagnostics = Vec::new();
+    let mut lines = output.lines().peekable();
+
+    while let Some(line) = lines.next() {
+        let Some(caps) = HEADER.captures(line) else {
+            passthrough.push(line);
+            continue;
+        };
+        let mut body = vec![line];
+        while let Some(next) = lines.next_if(|l| !l.trim().is_empty() && !HEADER.is_match(l)) {
+            body.push(next);
+        }
+        match parse_block(&caps, &body) {
+            Some(diagnostic) => diagnostics.push(diagnostic),
+            // Summaries such as "generated 5 warnings" have no location.
+            None => passthrough.extend(body),
+        }
+    }
+    (passthrough, diagnostics)
+}
+
+fn parse_block(header: &Captures, body: &[&str]) -> Option<Diagnostic> {
+    let location = body.iter().find_map(|l| LOCATION.captures(l))?;
+    let level = header.get(1).map_or("", |m| m.as_str());
+    let message = header.get(3).map_or("", |m| m.as_str());
+    let code = header
+        .get(2)
+        .map(|m| m.as_str().to_string())
+        .or_else(|| lint_name(body));
+
+    Some(Diagnostic {
+        key: (
+            level.to_string(),
+            code,
+            QUOTED.replace_all(message, "`_`").into_owned(),
+        ),
+        location: format!("{}:{}", &location[1], &location[2]),
+        text: body.join("\n"),
+    })
+}
+
+/// Clippy and lint warnings carry their name in a note rather than the header.
+fn lint_name(body: &[&str]) -> Option<String> {
+    body.iter().find_map(|line| {
+        if let Some(caps) = LINT_ATTR.captures(line) {
+            let name = caps.get(1).or_else(|| caps.get(2))?.as_str();
+            return Some(name.replace('-', "_"));
+        }
+        CLIPPY_URL
+            .captures(line)
+            .map(|caps| format!("clippy::{}", &caps[1]))
+    })
+}
+
+fn group(diagnostics: Vec<Diagnostic>) -> Vec<Vec<Diagnostic>> {
+    let mut index: HashMap<(String, Option<String>, String), usize> = HashMap::new();
+    let mut groups: Vec<Vec<Diagnostic>> = Vec::new();
+
+    for diagnostic in diagnostics {
+        if let Some(&i) = index.get(&diagnostic.key) {
+            if let Some(group) = groups.get_mut(i) {
+                group.push(diagnostic);
+            }
+            continue;
+        }
+        index.insert(diagnostic.key.clone(), groups.len());
+        groups.push(vec![diagnostic]);
+    }
+
+    // Errors first; otherwise keep first-appearance order.
+    groups.sort_by_key(|g| g.first().map_or(1, |d| u8::from(d.key.0 != "error")));
+    groups
+}
+
+fn render_group(group: &[Diagnostic], out: &mut String) {
+    let Some(first) = group.first() else {
+        return;
+    };
+    let (level, code, template) = &first.key;
+    let code = code.as_ref().map(|c| format!("[{c}]")).unwrap_or_default();
+    let count = group.len();
+    let noun = if count == 1 {
+        "occurrence"
+    } else {
+        "occurrences"
+    };
+
+    let _ = writeln!(out, "\n{level}{code}: {template} ({count} {noun})");
+    for diagnostic in group.iter().take(SHOWN_PER_GROUP) {
+        let _ = writeln!(out, "{}\n", diagnostic.text);
+    }
+
+    let rest = group.get(SHOWN_PER_GROUP..).unwrap_or_default();
+    if rest.is_empty() {
+        return;
+    }
+    let _ = writeln!(out, "... {} more:", rest.len());
+    for diagnostic in rest {
+        let _ = writeln!(out, "    {}", diagnostic.location);
+    }
+}
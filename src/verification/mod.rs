@@ -1,13 +1,19 @@
 //! External command verification pipeline.
 //!
 //! Runs commands defined in `[commands]` section of slopchop.toml
-//! and captures output to `slopchop-report.txt`.
+//! and captures output to `slopchop-report.txt`. The structural scan,
+//! locality included, is merged into the same report as a phase.
 
+pub mod cargo_json;
+pub mod compress;
+mod phases;
 mod runner;
 
 use std::path::Path;
 
 use crate::config::Config;
+use crate::types::ScanReport;
+pub use phases::scan_phase;
 pub use runner::run_commands;
 
 /// Result of a single command execution.
@@ -19,8 +25,13 @@ pub struct CommandResult {
     pub passed: bool,
     /// Exit code, if available.
     pub exit_code: Option<i32>,
-    /// Combined stdout and stderr output.
+    /// Combined stdout and stderr output, as produced by the command.
     pub output: String,
+    /// Grouped, deduplicated output written to the flight recorder.
+    pub compressed: String,
+    /// Typed diagnostics, when the command emitted cargo JSON messages.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub diagnostics: Option<Vec<cargo_json::Diagnostic>>,
     /// Execution time in milliseconds.
     pub duration_ms: u64,
 }
@@ -34,18 +45,38 @@ impl CommandResult {
         output: String,
         duration_ms: u64,
     ) -> Self {
+        let compressed = compress::compress(&output);
         Self {
             command,
             passed,
             exit_code,
             output,
+            compressed,
+            diagnostics: None,
             duration_ms,
         }
     }
 
-    /// Count error lines in output.
+    /// Attaches parsed cargo diagnostics, which then drive the counts
+    /// and the compressed report. `other` holds the non-diagnostic lines.
+    #[must_use]
+    pub fn with_diagnostics(
+        mut self,
+        diagnostics: Vec<cargo_json::Diagnostic>,
+        other: &str,
+    ) -> Self {
+        self.compressed = compress::compress_diagnostics(other, &diagnostics);
+        self.diagnostics = Some(diagnostics);
+        self
+    }
+
+    /// Number of errors: exact for cargo JSON output, otherwise
+    /// counted from error lines.
     #[must_use]
     pub fn error_count(&self) -> usize {
+        if let Some(diagnostics) = &self.diagnostics {
+            return cargo_json::count_level(diagnostics, "error");
+        }
         self.output
             .lines()
             .filter(|line| {
@@ -55,9 +86,13 @@ impl CommandResult {
             .count()
     }
 
-    /// Count warning lines in output.
+    /// Number of warnings: exact for cargo JSON output, otherwise
+    /// counted from warning lines.
     #[must_use]
     pub fn warning_count(&self) -> usize {
+        if let Some(diagnostics) = &self.diagnostics {
+            return cargo_json::count_level(diagnostics, "warning");
+        }
         self.output
             .lines()
             .filter(|line| {
@@ -70,15 +105,35 @@ impl CommandResult {
     }
 }
 
+/// Result of a governance phase run alongside the external commands.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct PhaseResult {
+    /// Phase name (e.g. "scan").
+    pub name: String,
+    /// Whether the phase passed.
+    pub passed: bool,
+    /// Number of violations found.
+    pub violations: usize,
+    /// Plain-text findings written to the flight recorder.
+    pub output: String,
+    /// Execution time in milliseconds.
+    pub duration_ms: u64,
+}
+
 /// Result of running the verification pipeline.
 #[derive(Debug, serde::Serialize)]
 pub struct VerificationReport {
-    /// Whether all commands passed.
+    /// Whether all commands and phases passed.
     pub passed: bool,
-    /// Combined output from all commands.
+    /// Combined output from all commands and phases.
     pub output: String,
     /// Individual command results.
     pub commands: Vec<CommandResult>,
+    /// Governance phase results.
+    pub phases: Vec<PhaseResult>,
+    /// Full structural scan results, if the scan phase ran.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scan: Option<ScanReport>,
     /// Total execution time in milliseconds.
     pub duration_ms: u64,
 }
@@ -95,10 +150,44 @@ impl VerificationReport {
             passed,
             output,
             commands,
+            phases: Vec::new(),
+            scan: None,
             duration_ms,
         }
     }
 
+    /// Merges a governance phase into the report.
+    /// A failing phase fails the whole report.
+    pub fn add_phase(&mut self, phase: PhaseResult) {
+        use std::fmt::Write;
+
+        let _ = writeln!(self.output, "$ slopchop {}", phase.name);
+        self.output.push_str(&phase.output);
+        self.output.push('\n');
+
+        self.passed &= phase.passed;
+        self.duration_ms += phase.duration_ms;
+        self.phases.push(phase);
+    }
+
+    /// Merges the structural scan into the report.
+    pub fn add_scan(&mut self, scan: ScanReport) {
+        self.add_phase(scan_phase(&scan));
+        self.scan = Some(scan);
+    }
+
+    /// Number of phases that failed.
+    #[must_use]
+    pub fn failed_phase_count(&self) -> usize {
+        self.phases.iter().filter(|p| !p.passed).count()
+    }
+
+    /// Total governance violations across all phases.
+    #[must_use]
+    pub fn total_violations(&self) -> usize {
+        self.phases.iter().map(|p| p.violations).sum()
+    }
+
     /// Total number of commands run.
     #[must_use]
     pub fn total_commands(&self) -> usize {
@@ -0,0 +1,118 @@
+//! Governance phases merged into the verification report.
+
+use std::fmt::Write;
+
+use crate::reporting;
+use crate::types::ScanReport;
+
+use super::PhaseResult;
+
+/// Builds the phase result for a structural scan.
+#[must_use]
+pub fn scan_phase(report: &ScanReport) -> PhaseResult {
+    let mut output = String::new();
+    let _ = writeln!(
+        output,
+        "Files scanned: {} | Total tokens: {} | Violations: {}",
+        report.files.len(),
+        report.total_tokens,
+        report.total_violations
+    );
+    if let Ok(details) = reporting::format_report_string(report) {
+        output.push_str(&details);
+    }
+    for file in &report.files {
+        if let Some(suggested) = &file.suggested_profile {
+            let _ = writeln!(
+                output,
+                "PROFILE HINT: {} | JUDGED BY: {} | SUGGESTED: {}",
+                file.path.display(),
+                file.profile,
+                suggested
+            );
+        }
+        for s in &file.suppressions {
+            let _ = writeln!(
+                output,
+                "SUPPRESSED: {}:{} | LAWS: {} | SILENCED: {} | REASON: {}",
+                file.path.display(),
+                s.row,
+                s.laws.join(", "),
+                s.used,
+                s.reason
+            );
+        }
+    }
+    if let Some(outcome) = &report.baseline {
+        let _ = writeln!(
+            output,
+            "Baselined: {} | Fixed since baseline: {}",
+            outcome.baselined,
+            outcome.fixed.len()
+        );
+        for entry in &outcome.fixed {
+            let _ = writeln!(output, "FIXED: {} | LAW: {}", entry.path, entry.law);
+        }
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    let duration_ms = report.duration_ms as u64;
+
+    PhaseResult {
+        name: "scan".to_string(),
+        passed: !report.has_errors(),
+        violations: report.total_violations,
+        output,
+        duration_ms,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{FileReport, Violation};
+    use crate::verification::VerificationReport;
+    use std::path::PathBuf;
+
+    fn dirty_scan() -> ScanReport {
+        ScanReport {
+            files: vec![FileReport {
+                path: PathBuf::from("src/lib.rs"),
+                token_count: 10,
+                complexity_score: 0,
+                violations: vec![Violation::simple(3, "Too big".into(), "LAW OF ATOMICITY")],
+                analysis: None,
+                suppressions: Vec::new(),
+                profile: "application".into(),
+                suggested_profile: None,
+            }],
+            total_tokens: 10,
+            total_violations: 1,
+            duration_ms: 5,
+            baseline: None,
+            diff: None,
+        }
+    }
+
+    #[test]
+    fn test_scan_phase_fails_report() {
+        let mut report = VerificationReport::new(true, String::new(), Vec::new(), 0);
+        report.add_scan(dirty_scan());
+
+        assert!(!report.passed);
+        assert_eq!(report.total_violations(), 1);
+        assert_eq!(report.failed_phase_count(), 1);
+        assert!(report.output.contains("$ slopchop scan"));
+        assert!(report.output.contains("FILE: src/lib.rs | LAW: LAW OF ATOMICITY | LINE: 3"));
+    }
+
+    #[test]
+    fn test_clean_scan_keeps_report_green() {
+        let mut report = VerificationReport::new(true, String::new(), Vec::new(), 0);
+        report.add_scan(ScanReport::default());
+
+        assert!(report.passed);
+        assert_eq!(report.phases.len(), 1);
+        assert_eq!(report.total_violations(), 0);
+    }
+}
@@ -5,7 +5,7 @@ use std::path::Path;
 use std::process::Command;
 use std::time::Instant;
 
-use super::{CommandResult, VerificationReport};
+use super::{cargo_json, CommandResult, VerificationReport};
 
 /// Runs a list of commands and captures combined output.
 ///
@@ -28,62 +28,68 @@ where
 
     for (idx, cmd_str) in commands.iter().enumerate() {
         on_command(cmd_str, idx + 1, total);
-        let _ = writeln!(combined_output, "$ {cmd_str}");
-
-        let cmd_start = Instant::now();
-        let result = run_single_command(repo_root, cmd_str);
+        let result = execute(repo_root, cmd_str);
 
-        #[allow(clippy::cast_possible_truncation)]
-        let cmd_duration = cmd_start.elapsed().as_millis() as u64;
+        let _ = writeln!(combined_output, "$ {cmd_str}");
+        combined_output.push_str(&result.compressed);
+        combined_output.push('\n');
 
-        match result {
-            Ok(cmd_output) => {
-                combined_output.push_str(&cmd_output);
-                combined_output.push('\n');
+        all_passed &= result.passed;
+        results.push(result);
+    }
 
-                let passed = !cmd_output.contains("[exit code:")
-                    || cmd_output.lines().any(|l| l.contains("[exit code: 0]"));
+    #[allow(clippy::cast_possible_truncation)]
+    let total_duration = start.elapsed().as_millis() as u64;
 
-                let exit_code = cmd_output.lines().find_map(|l| {
-                    l.strip_prefix("[exit code: ")
-                        .and_then(|s| s.strip_suffix(']'))
-                        .and_then(|s| s.parse::<i32>().ok())
-                });
+    VerificationReport::new(all_passed, combined_output, results, total_duration)
+}
 
-                let passed = exit_code.map_or(passed, |c| c == 0);
+/// Runs one command and records its raw and compressed output.
+/// Cargo commands are run with JSON messages and parsed into diagnostics.
+fn execute(repo_root: &Path, cmd_str: &str) -> CommandResult {
+    let json_cmd = cargo_json::json_command(cmd_str);
+    let cmd_start = Instant::now();
+    let result = run_single_command(repo_root, json_cmd.as_deref().unwrap_or(cmd_str));
 
-                results.push(CommandResult::new(
-                    cmd_str.clone(),
+    #[allow(clippy::cast_possible_truncation)]
+    let cmd_duration = cmd_start.elapsed().as_millis() as u64;
+
+    match result {
+        Ok(cmd_output) => {
+            let exit_code = cmd_output.lines().find_map(|l| {
+                l.strip_prefix("[exit code: ")
+                    .and_then(|s| s.strip_suffix(']'))
+                    .and_then(|s| s.parse::<i32>().ok())
+            });
+            let passed = exit_code.map_or(!cmd_output.contains("[exit code:"), |c| c == 0);
+
+            if json_cmd.is_none() {
+                return CommandResult::new(
+                    cmd_str.to_string(),
                     passed,
                     exit_code,
-                    cmd_output.clone(),
+                    cmd_output,
                     cmd_duration,
-                ));
-
-                if !passed {
-                    all_passed = false;
-                }
-            }
-            Err(e) => {
-                all_passed = false;
-                let _ = writeln!(combined_output, "ERROR: {e}");
-                combined_output.push('\n');
-
-                results.push(CommandResult::new(
-                    cmd_str.clone(),
-                    false,
-                    None,
-                    format!("ERROR: {e}"),
-                    cmd_duration,
-                ));
+                );
             }
+            let messages = cargo_json::parse(&cmd_output);
+            CommandResult::new(
+                cmd_str.to_string(),
+                passed,
+                exit_code,
+                messages.text,
+                cmd_duration,
+            )
+            .with_diagnostics(messages.diagnostics, &messages.other)
         }
+        Err(e) => CommandResult::new(
+            cmd_str.to_string(),
+            false,
+            None,
+            format!("ERROR: {e}"),
+            cmd_duration,
+        ),
     }
-
-    #[allow(clippy::cast_possible_truncation)]
-    let total_duration = start.elapsed().as_millis() as u64;
-
-    VerificationReport::new(all_passed, combined_output, results, total_duration)
 }
 
 /// Runs a single command string.
@@ -0,0 +1,126 @@
+// src/watch.rs
+//! Change polling and violation deltas for `slopchop watch`.
+//!
+//! The watcher polls the modification times of the discovered files. Once
+//! a poll sees no further changes the tree is considered stable and is
+//! rescanned; unchanged files come from the scan cache, so only changed
+//! files are parsed again. Each stable scan is compared with the previous
+//! one by baseline fingerprint, so violations that merely moved lines are
+//! neither introduced nor fixed.
+
+use std::collections::{BTreeSet, HashMap};
+use std::fs;
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+use crate::baseline::{self, Baseline, BaselineEntry};
+
+/// Modification time of each watched file.
+pub type Mtimes = HashMap<PathBuf, SystemTime>;
+
+/// Reads the modification times of `files`. Files that cannot be read are
+/// left out, so they show up as changed once they can.
+#[must_use]
+pub fn mtimes(files: &[PathBuf]) -> Mtimes {
+    files
+        .iter()
+        .filter_map(|path| {
+            let modified = fs::metadata(path).and_then(|m| m.modified()).ok()?;
+            Some((path.clone(), modified))
+        })
+        .collect()
+}
+
+/// Files added, modified or removed between two polls, sorted.
+#[must_use]
+pub fn changed(before: &Mtimes, after: &Mtimes) -> Vec<PathBuf> {
+    let modified = after
+        .iter()
+        .filter(|(path, time)| before.get(*path) != Some(*time))
+        .map(|(path, _)| path);
+    let removed = before.keys().filter(|path| !after.contains_key(*path));
+    let paths: BTreeSet<&PathBuf> = modified.chain(removed).collect();
+    paths.into_iter().cloned().collect()
+}
+
+/// Violations introduced and fixed between two stable scans.
+#[derive(Debug, Default)]
+pub struct Delta {
+    pub introduced: Vec<BaselineEntry>,
+    pub fixed: Vec<BaselineEntry>,
+}
+
+impl Delta {
+    /// Compares snapshots of the previous and the current scan.
+    #[must_use]
+    pub fn between(before: &Baseline, after: &Baseline) -> Self {
+        Self {
+            introduced: baseline::unmatched(&after.entries, before),
+            fixed: baseline::unmatched(&before.entries, after),
+        }
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.introduced.is_empty() && self.fixed.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn entry(path: &str, law: &str, hash: &str, row: usize) -> BaselineEntry {
+        BaselineEntry {
+            path: path.into(),
+            law: law.into(),
+            hash: hash.into(),
+            row,
+            message: String::new(),
+        }
+    }
+
+    fn snapshot(entries: Vec<BaselineEntry>) -> Baseline {
+        Baseline {
+            version: 1,
+            entries,
+        }
+    }
+
+    #[test]
+    fn test_changed_reports_added_modified_and_removed() {
+        let t0 = SystemTime::UNIX_EPOCH;
+        let t1 = t0 + Duration::from_secs(1);
+        let before: Mtimes = [("a.rs", t0), ("b.rs", t0), ("c.rs", t0)]
+            .into_iter()
+            .map(|(p, t)| (PathBuf::from(p), t))
+            .collect();
+        let after: Mtimes = [("a.rs", t0), ("b.rs", t1), ("d.rs", t0)]
+            .into_iter()
+            .map(|(p, t)| (PathBuf::from(p), t))
+            .collect();
+
+        let names: Vec<PathBuf> = ["b.rs", "c.rs", "d.rs"].map(PathBuf::from).into();
+        assert_eq!(changed(&before, &after), names);
+        assert!(changed(&after, &after).is_empty());
+    }
+
+    #[test]
+    fn test_delta_ignores_moved_violations_and_counts_duplicates() {
+        let before = snapshot(vec![
+            entry("a.rs", "P01", "h1", 3),
+            entry("a.rs", "P01", "h2", 9),
+        ]);
+        let after = snapshot(vec![
+            entry("a.rs", "P01", "h1", 5),
+            entry("a.rs", "P01", "h1", 6),
+        ]);
+
+        let delta = Delta::between(&before, &after);
+        let rows = |entries: &[BaselineEntry]| entries.iter().map(|e| e.row).collect::<Vec<_>>();
+        assert_eq!(rows(&delta.introduced), vec![6]);
+        assert_eq!(rows(&delta.fixed), vec![9]);
+        assert!(Delta::between(&after, &after).is_empty());
+    }
+}
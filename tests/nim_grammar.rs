@@ -262,3 +262,23 @@ fn test_nim_query_compilation_unsafe() {
         }
     }
 }
+
+/// Every query in the Nim `QUERIES` row must compile against the grammar.
+#[test]
+fn test_nim_lang_queries_compile() {
+    use slopchop_core::lang::{Lang, QueryKind};
+
+    let kinds = [
+        QueryKind::Naming,
+        QueryKind::Complexity,
+        QueryKind::Imports,
+        QueryKind::Defs,
+        QueryKind::Exports,
+        QueryKind::Skeleton,
+    ];
+
+    for kind in kinds {
+        let result = Query::new(Lang::Nim.grammar(), Lang::Nim.query(kind));
+        assert!(result.is_ok(), "Nim {kind:?} query failed: {:?}", result.err());
+    }
+}